@@ -0,0 +1,549 @@
+use core::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::common::float::Real;
+
+///Numbers a generic function can be evaluated on, for forward-mode automatic differentiation.
+///
+///A function written once for any `DualNum`, e.g.
+///`fn f<D: DualNum>(x: D) -> D { x.powi(3) - x * 2.0 - D::constant(5.0) }`,
+///can be evaluated on f64, on `Dual` to get its first derivative, and on `Dual2` to get its
+///first and second derivatives, all exact up to rounding. `derivative` and `second_derivative`
+///turn such a function into the closures expected by `Newton::fp`, `Newton::fdp` and `Halley`.
+///The underlying type T is any `Real`, f64 by default; a function written for `DualNum<T>`
+///uses `T::constant` for its constants, e.g. `x * T::constant(2.0)`.
+pub trait DualNum<T = f64>:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Add<T, Output = Self>
+    + Sub<T, Output = Self>
+    + Mul<T, Output = Self>
+    + Div<T, Output = Self>
+{
+    ///A constant, whose derivatives are zero.
+    fn constant(c: T) -> Self;
+    ///The value of the number, without its derivative parts.
+    fn value(&self) -> T;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn atan(self) -> Self;
+    fn abs(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, p: T) -> Self;
+}
+
+impl<T> DualNum<T> for T
+where
+    T: Real,
+{
+    fn constant(c: T) -> Self {
+        c
+    }
+
+    fn value(&self) -> T {
+        *self
+    }
+
+    fn exp(self) -> Self {
+        T::exp(self)
+    }
+
+    fn ln(self) -> Self {
+        T::ln(self)
+    }
+
+    fn sqrt(self) -> Self {
+        T::sqrt(self)
+    }
+
+    fn sin(self) -> Self {
+        T::sin(self)
+    }
+
+    fn cos(self) -> Self {
+        T::cos(self)
+    }
+
+    fn tan(self) -> Self {
+        T::tan(self)
+    }
+
+    fn sinh(self) -> Self {
+        T::sinh(self)
+    }
+
+    fn cosh(self) -> Self {
+        T::cosh(self)
+    }
+
+    fn tanh(self) -> Self {
+        T::tanh(self)
+    }
+
+    fn atan(self) -> Self {
+        T::atan(self)
+    }
+
+    fn abs(self) -> Self {
+        T::abs(self)
+    }
+
+    fn powi(self, n: i32) -> Self {
+        T::powi(self, n)
+    }
+
+    fn powf(self, p: T) -> Self {
+        T::powf(self, p)
+    }
+}
+
+///Value and derivatives of an elementary function g at a point, (g, g', g'').
+type Derivatives<T> = (T, T, T);
+
+fn powi_derivatives<T: Real>(x: T, n: i32) -> Derivatives<T> {
+    let (zero, one, two) = (T::zero(), T::one(), T::constant(2.0));
+    // Written out for small powers so that x = 0 gives 0 instead of 0 * inf
+    match n {
+        0 => (one, zero, zero),
+        1 => (x, one, zero),
+        2 => (x * x, two * x, two),
+        _ => {
+            let n_f = T::constant(n as f64);
+            (
+                x.powi(n),
+                n_f * x.powi(n - 1),
+                n_f * (n_f - one) * x.powi(n - 2),
+            )
+        }
+    }
+}
+
+fn powf_derivatives<T: Real>(x: T, p: T) -> Derivatives<T> {
+    let (one, two) = (T::one(), T::constant(2.0));
+    (
+        x.powf(p),
+        p * x.powf(p - one),
+        p * (p - one) * x.powf(p - two),
+    )
+}
+
+fn tan_derivatives<T: Real>(x: T) -> Derivatives<T> {
+    let t = x.tan();
+    let sec2 = T::one() + t * t;
+    (t, sec2, T::constant(2.0) * t * sec2)
+}
+
+fn tanh_derivatives<T: Real>(x: T) -> Derivatives<T> {
+    let t = x.tanh();
+    let sech2 = T::one() - t * t;
+    (t, sech2, T::constant(-2.0) * t * sech2)
+}
+
+fn atan_derivatives<T: Real>(x: T) -> Derivatives<T> {
+    let d = T::one() / (T::one() + x * x);
+    (x.atan(), d, T::constant(-2.0) * x * d * d)
+}
+
+fn sqrt_derivatives<T: Real>(x: T) -> Derivatives<T> {
+    let s = x.sqrt();
+    (s, T::constant(0.5) / s, T::constant(-0.25) / (s * x))
+}
+
+///First order dual number re + eps ε with ε² = 0, carrying a value and its derivative.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual<T = f64> {
+    pub re: T,
+    pub eps: T,
+}
+
+impl<T> Dual<T>
+where
+    T: Real,
+{
+    pub fn new(re: T, eps: T) -> Self {
+        Self { re, eps }
+    }
+
+    ///The independent variable x, whose derivative is one.
+    pub fn variable(x: T) -> Self {
+        Self {
+            re: x,
+            eps: T::one(),
+        }
+    }
+
+    // Chain rule for g(self), given g and g' at self.re
+    fn chain(self, (g, g1, _): Derivatives<T>) -> Self {
+        Self {
+            re: g,
+            eps: g1 * self.eps,
+        }
+    }
+}
+
+impl<T> fmt::Display for Dual<T>
+where
+    T: Real,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}ε", self.re, self.eps)
+    }
+}
+
+impl<T: Real> Add for Dual<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<T: Real> Sub for Dual<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<T: Real> Mul for Dual<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re, self.eps * rhs.re + self.re * rhs.eps)
+    }
+}
+
+impl<T: Real> Div for Dual<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let q = self.re / rhs.re;
+        Self::new(q, (self.eps - q * rhs.eps) / rhs.re)
+    }
+}
+
+impl<T: Real> Neg for Dual<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+impl<T> DualNum<T> for Dual<T>
+where
+    T: Real,
+{
+    fn constant(c: T) -> Self {
+        Self::new(c, T::zero())
+    }
+
+    fn value(&self) -> T {
+        self.re
+    }
+
+    fn exp(self) -> Self {
+        let e = self.re.exp();
+        self.chain((e, e, e))
+    }
+
+    fn ln(self) -> Self {
+        self.chain((self.re.ln(), self.re.recip(), T::zero()))
+    }
+
+    fn sqrt(self) -> Self {
+        self.chain(sqrt_derivatives(self.re))
+    }
+
+    fn sin(self) -> Self {
+        self.chain((self.re.sin(), self.re.cos(), T::zero()))
+    }
+
+    fn cos(self) -> Self {
+        self.chain((self.re.cos(), -self.re.sin(), T::zero()))
+    }
+
+    fn tan(self) -> Self {
+        self.chain(tan_derivatives(self.re))
+    }
+
+    fn sinh(self) -> Self {
+        self.chain((self.re.sinh(), self.re.cosh(), T::zero()))
+    }
+
+    fn cosh(self) -> Self {
+        self.chain((self.re.cosh(), self.re.sinh(), T::zero()))
+    }
+
+    fn tanh(self) -> Self {
+        self.chain(tanh_derivatives(self.re))
+    }
+
+    fn atan(self) -> Self {
+        self.chain(atan_derivatives(self.re))
+    }
+
+    fn abs(self) -> Self {
+        self.chain((self.re.abs(), self.re.signum(), T::zero()))
+    }
+
+    fn powi(self, n: i32) -> Self {
+        self.chain(powi_derivatives(self.re, n))
+    }
+
+    fn powf(self, p: T) -> Self {
+        self.chain(powf_derivatives(self.re, p))
+    }
+}
+
+///Second order dual number, carrying a value with its first (d1) and second (d2) derivatives.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual2<T = f64> {
+    pub re: T,
+    pub d1: T,
+    pub d2: T,
+}
+
+impl<T> Dual2<T>
+where
+    T: Real,
+{
+    pub fn new(re: T, d1: T, d2: T) -> Self {
+        Self { re, d1, d2 }
+    }
+
+    ///The independent variable x, whose first derivative is one and second derivative zero.
+    pub fn variable(x: T) -> Self {
+        Self::new(x, T::one(), T::zero())
+    }
+
+    // Chain rule for g(self): (g∘u)'' = g''(u) u'^2 + g'(u) u''
+    fn chain(self, (g, g1, g2): Derivatives<T>) -> Self {
+        Self {
+            re: g,
+            d1: g1 * self.d1,
+            d2: g2 * self.d1 * self.d1 + g1 * self.d2,
+        }
+    }
+}
+
+impl<T> fmt::Display for Dual2<T>
+where
+    T: Real,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "re={}, d1={}, d2={}", self.re, self.d1, self.d2)
+    }
+}
+
+impl<T: Real> Add for Dual2<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.d1 + rhs.d1, self.d2 + rhs.d2)
+    }
+}
+
+impl<T: Real> Sub for Dual2<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.d1 - rhs.d1, self.d2 - rhs.d2)
+    }
+}
+
+impl<T: Real> Mul for Dual2<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re,
+            self.d1 * rhs.re + self.re * rhs.d1,
+            self.d2 * rhs.re + T::constant(2.0) * self.d1 * rhs.d1 + self.re * rhs.d2,
+        )
+    }
+}
+
+impl<T: Real> Div for Dual2<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let q = self.re / rhs.re;
+        let q1 = (self.d1 - q * rhs.d1) / rhs.re;
+        let q2 = (self.d2 - T::constant(2.0) * q1 * rhs.d1 - q * rhs.d2) / rhs.re;
+        Self::new(q, q1, q2)
+    }
+}
+
+impl<T: Real> Neg for Dual2<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.d1, -self.d2)
+    }
+}
+
+impl<T> DualNum<T> for Dual2<T>
+where
+    T: Real,
+{
+    fn constant(c: T) -> Self {
+        Self::new(c, T::zero(), T::zero())
+    }
+
+    fn value(&self) -> T {
+        self.re
+    }
+
+    fn exp(self) -> Self {
+        let e = self.re.exp();
+        self.chain((e, e, e))
+    }
+
+    fn ln(self) -> Self {
+        let inv = self.re.recip();
+        self.chain((self.re.ln(), inv, -inv * inv))
+    }
+
+    fn sqrt(self) -> Self {
+        self.chain(sqrt_derivatives(self.re))
+    }
+
+    fn sin(self) -> Self {
+        let (s, c) = self.re.sin_cos();
+        self.chain((s, c, -s))
+    }
+
+    fn cos(self) -> Self {
+        let (s, c) = self.re.sin_cos();
+        self.chain((c, -s, -c))
+    }
+
+    fn tan(self) -> Self {
+        self.chain(tan_derivatives(self.re))
+    }
+
+    fn sinh(self) -> Self {
+        let (sh, ch) = (self.re.sinh(), self.re.cosh());
+        self.chain((sh, ch, sh))
+    }
+
+    fn cosh(self) -> Self {
+        let (sh, ch) = (self.re.sinh(), self.re.cosh());
+        self.chain((ch, sh, ch))
+    }
+
+    fn tanh(self) -> Self {
+        self.chain(tanh_derivatives(self.re))
+    }
+
+    fn atan(self) -> Self {
+        self.chain(atan_derivatives(self.re))
+    }
+
+    fn abs(self) -> Self {
+        self.chain((self.re.abs(), self.re.signum(), T::zero()))
+    }
+
+    fn powi(self, n: i32) -> Self {
+        self.chain(powi_derivatives(self.re, n))
+    }
+
+    fn powf(self, p: T) -> Self {
+        self.chain(powf_derivatives(self.re, p))
+    }
+}
+
+// Mixed arithmetic with constants of the underlying type
+macro_rules! impl_scalar_ops {
+    ($dual:ident) => {
+        impl<T: Real> Add<T> for $dual<T> {
+            type Output = Self;
+            fn add(self, rhs: T) -> Self {
+                self + Self::constant(rhs)
+            }
+        }
+
+        impl<T: Real> Sub<T> for $dual<T> {
+            type Output = Self;
+            fn sub(self, rhs: T) -> Self {
+                self - Self::constant(rhs)
+            }
+        }
+
+        impl<T: Real> Mul<T> for $dual<T> {
+            type Output = Self;
+            fn mul(self, rhs: T) -> Self {
+                self * Self::constant(rhs)
+            }
+        }
+
+        impl<T: Real> Div<T> for $dual<T> {
+            type Output = Self;
+            fn div(self, rhs: T) -> Self {
+                self / Self::constant(rhs)
+            }
+        }
+    };
+}
+
+// Constants on the left side, which can only be implemented for concrete float types
+macro_rules! impl_scalar_lhs_ops {
+    ($dual:ident, $t:ty) => {
+        impl Add<$dual<$t>> for $t {
+            type Output = $dual<$t>;
+            fn add(self, rhs: $dual<$t>) -> $dual<$t> {
+                $dual::constant(self) + rhs
+            }
+        }
+
+        impl Sub<$dual<$t>> for $t {
+            type Output = $dual<$t>;
+            fn sub(self, rhs: $dual<$t>) -> $dual<$t> {
+                $dual::constant(self) - rhs
+            }
+        }
+
+        impl Mul<$dual<$t>> for $t {
+            type Output = $dual<$t>;
+            fn mul(self, rhs: $dual<$t>) -> $dual<$t> {
+                $dual::constant(self) * rhs
+            }
+        }
+
+        impl Div<$dual<$t>> for $t {
+            type Output = $dual<$t>;
+            fn div(self, rhs: $dual<$t>) -> $dual<$t> {
+                $dual::constant(self) / rhs
+            }
+        }
+    };
+}
+
+impl_scalar_ops!(Dual);
+impl_scalar_ops!(Dual2);
+impl_scalar_lhs_ops!(Dual, f32);
+impl_scalar_lhs_ops!(Dual, f64);
+impl_scalar_lhs_ops!(Dual2, f32);
+impl_scalar_lhs_ops!(Dual2, f64);
+
+///The derivative of f, computed by evaluating f on `Dual::variable(x)`.
+///Pass a function that is generic over `DualNum`, which Rust instantiates for `Dual` here.
+pub fn derivative<F, T>(mut f: F) -> impl FnMut(T) -> T
+where
+    F: FnMut(Dual<T>) -> Dual<T>,
+    T: Real,
+{
+    move |x| f(Dual::variable(x)).eps
+}
+
+///The second derivative of f, computed by evaluating f on `Dual2::variable(x)`.
+pub fn second_derivative<F, T>(mut f: F) -> impl FnMut(T) -> T
+where
+    F: FnMut(Dual2<T>) -> Dual2<T>,
+    T: Real,
+{
+    move |x| f(Dual2::variable(x)).d2
+}
@@ -0,0 +1,19 @@
+use std::collections::VecDeque;
+
+use super::float::Real;
+
+///One step of Richardson extrapolation, as used by Romberg integration.
+///`row` holds the latest row of the extrapolation table, from the plain estimate at the front
+///to the most extrapolated one at the back. The new `estimate`, computed with a smaller step,
+///is pushed to the front and the row is updated in place, where column m removes the error
+///term that shrinks by `factor^m` between rows, e.g. factor 4 for halved steps and an error
+///expansion in even powers of the step.
+pub(crate) fn richardson_extend<T>(row: &mut VecDeque<T>, estimate: T, factor: T)
+where
+    T: Real,
+{
+    row.push_front(estimate);
+    for m in 1..row.len() {
+        row[m] = row[m - 1] + (row[m - 1] - row[m]) / (factor.powi(m as i32) - T::one());
+    }
+}
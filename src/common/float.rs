@@ -0,0 +1,27 @@
+use core::fmt;
+
+use num_traits::Float;
+
+///Floating-point types accepted by the generic root finders, integrators and splines.
+///It is implemented for every `num_traits::Float` type, so f32 and f64 work out of the box as
+///well as extended precision types such as double-double implementations of `Float`.
+///Types that are not `Copy`, like `rug::Float`, cannot implement `Float` and are not supported.
+pub trait Real: Float + fmt::Debug + fmt::Display {
+    ///Converts an f64 constant to the type, rounding it to the nearest representable value.
+    fn constant(x: f64) -> Self {
+        num_traits::cast(x).expect("f64 constants are representable in every Float type")
+    }
+
+    ///Smallest relative tolerance the solvers accept, four times the machine epsilon.
+    fn default_rtol() -> Self {
+        Self::constant(4.0) * Self::epsilon()
+    }
+
+    ///Default absolute tolerance of the solvers. It is 1e-8, raised to 100 times the machine
+    ///epsilon for types that cannot resolve that, e.g. 1.2e-5 for f32.
+    fn default_tol() -> Self {
+        Self::constant(1e-8).max(Self::constant(100.0) * Self::epsilon())
+    }
+}
+
+impl<T> Real for T where T: Float + fmt::Debug + fmt::Display {}
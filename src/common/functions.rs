@@ -1,3 +1,5 @@
+use ndarray::Array1;
+
 ///A function that assesses whether two values are equal within a given tolerance.
 ///
 /// ## Parameters
@@ -27,3 +29,19 @@ pub fn precision_equals_vectors(x1: &Vec<f64>, x2: &Vec<f64>, tol: f64, rtol: f6
         .zip(x2.iter())
         .any(|(a, b)| !precision_equals(*a, *b, tol, rtol))
 }
+
+///The maximum norm of a vector, which is NaN if any of its entries is NaN.
+///
+/// ## Parameters
+/// - x: vector
+/// ## Returns
+/// The largest absolute value of the entries of x
+pub fn max_norm(x: &Array1<f64>) -> f64 {
+    x.iter().fold(0.0, |norm: f64, value| {
+        if value.abs() > norm || value.is_nan() {
+            value.abs()
+        } else {
+            norm
+        }
+    })
+}
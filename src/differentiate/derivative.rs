@@ -0,0 +1,349 @@
+use core::fmt;
+use std::collections::VecDeque;
+use std::error::Error;
+
+use num_complex::Complex64;
+
+use crate::common::extrapolation::richardson_extend;
+
+static DEFAULT_RICHARDSON_SIZE: usize = 10;
+
+///Output of the derivative approximations.
+/// ## Attributes
+/// - msg:String
+/// - derivative:f64
+/// - error_estimate:f64, estimate of the absolute error, NaN for methods that do not estimate it
+/// - func_evals:usize
+#[derive(Debug)]
+pub struct DerivativeMetrics {
+    pub msg: String,
+    pub derivative: f64,
+    pub error_estimate: f64,
+    pub func_evals: usize,
+}
+
+impl fmt::Display for DerivativeMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}func_evals={}, derivative={}, error_estimate={:.5e}",
+            self.msg, self.func_evals, self.derivative, self.error_estimate
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum DifferentiationError {
+    UnacceptableStepError,
+    UnacceptableOrderError,
+    DimensionMismatchError,
+}
+
+impl fmt::Display for DifferentiationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DifferentiationError::UnacceptableStepError => {
+                write!(f, "The step h must be positive and finite.")
+            }
+            DifferentiationError::UnacceptableOrderError => {
+                write!(
+                    f,
+                    "The derivative and the accuracy order must be positive, and the accuracy order of central differences even."
+                )
+            }
+            DifferentiationError::DimensionMismatchError => {
+                write!(f, "The function returned vectors of different lengths.")
+            }
+        }
+    }
+}
+
+impl Error for DifferentiationError {}
+
+/// Points of a finite difference formula relative to x.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stencil {
+    Forward,
+    Backward,
+    Central,
+}
+
+/// # Finite Differences
+/// Derivative of order `derivative` (1 by default) at x, with a formula whose truncation error
+/// is O(h^accuracy) (2 by default). The weights are computed with Fornberg's algorithm,
+/// so any combination of orders is supported, e.g. the first and second derivative with
+/// accuracy 2, 4, 6 or 8. If h is not given it is eps^(1 / (derivative + accuracy)) max(|x|, 1),
+/// which balances truncation and rounding errors.
+pub struct FiniteDifference<F> {
+    f: F,
+    x: f64,
+    h: Option<f64>,
+    derivative: usize,
+    accuracy: usize,
+    stencil: Stencil,
+}
+
+impl<F> FiniteDifference<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, x: f64) -> Self {
+        Self {
+            f,
+            x,
+            h: None,
+            derivative: 1,
+            accuracy: 2,
+            stencil: Stencil::Central,
+        }
+    }
+
+    pub fn h(mut self, h: f64) -> Self {
+        self.h = Some(h);
+        self
+    }
+
+    pub fn derivative(mut self, derivative: usize) -> Self {
+        self.derivative = derivative;
+        self
+    }
+
+    pub fn accuracy(mut self, accuracy: usize) -> Self {
+        self.accuracy = accuracy;
+        self
+    }
+
+    pub fn stencil(mut self, stencil: Stencil) -> Self {
+        self.stencil = stencil;
+        self
+    }
+
+    pub fn run(mut self) -> Result<DerivativeMetrics, DifferentiationError> {
+        if self.derivative == 0 || self.accuracy == 0 {
+            return Err(DifferentiationError::UnacceptableOrderError);
+        }
+        if self.stencil == Stencil::Central && !self.accuracy.is_multiple_of(2) {
+            return Err(DifferentiationError::UnacceptableOrderError);
+        }
+
+        let h = match self.h {
+            Some(h) => h,
+            None => {
+                let exponent = 1.0 / (self.derivative + self.accuracy) as f64;
+                f64::EPSILON.powf(exponent) * self.x.abs().max(1.0)
+            }
+        };
+        if !(h > 0.0 && h.is_finite()) {
+            return Err(DifferentiationError::UnacceptableStepError);
+        }
+
+        let offsets = stencil_offsets(self.stencil, self.derivative, self.accuracy);
+        let weights = fornberg_weights(&offsets, self.derivative);
+        let (derivative, func_evals) =
+            apply_stencil(&mut self.f, self.x, h, &offsets, &weights, self.derivative);
+
+        Ok(DerivativeMetrics {
+            msg: String::from("Finite difference with the error of order h^accuracy.\n"),
+            derivative,
+            error_estimate: f64::NAN,
+            func_evals,
+        })
+    }
+}
+
+/// # Richardson Extrapolation
+/// Central differences with steps h, h/2, h/4, ... combined with the same extrapolation table
+/// as Romberg integration, since the error of central differences is a series in even powers
+/// of h. The error estimate is the change of the most extrapolated value between rows, and the
+/// table stops growing once this change increases, i.e. when rounding errors take over.
+/// The initial step h defaults to 0.1 max(|x|, 1) and should be large compared to the scale
+/// at which f is dominated by rounding, but small compared to the scale on which f varies.
+pub struct Richardson<F> {
+    f: F,
+    x: f64,
+    h: f64,
+    derivative: usize,
+    size: usize,
+}
+
+impl<F> Richardson<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, x: f64) -> Self {
+        Self {
+            f,
+            x,
+            h: 0.1 * x.abs().max(1.0),
+            derivative: 1,
+            size: DEFAULT_RICHARDSON_SIZE,
+        }
+    }
+
+    pub fn h(mut self, h: f64) -> Self {
+        self.h = h;
+        self
+    }
+
+    pub fn derivative(mut self, derivative: usize) -> Self {
+        self.derivative = derivative;
+        self
+    }
+
+    ///Maximum number of rows of the extrapolation table.
+    pub fn size(mut self, size: usize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn run(mut self) -> Result<DerivativeMetrics, DifferentiationError> {
+        if self.derivative == 0 || self.size == 0 {
+            return Err(DifferentiationError::UnacceptableOrderError);
+        }
+        if !(self.h > 0.0 && self.h.is_finite()) {
+            return Err(DifferentiationError::UnacceptableStepError);
+        }
+
+        let offsets = stencil_offsets(Stencil::Central, self.derivative, 2);
+        let weights = fornberg_weights(&offsets, self.derivative);
+
+        let mut row: VecDeque<f64> = VecDeque::new();
+        let mut func_evals = 0;
+        let mut best = (f64::NAN, f64::INFINITY);
+        let mut h = self.h;
+
+        for i in 0..self.size {
+            let (estimate, evals) =
+                apply_stencil(&mut self.f, self.x, h, &offsets, &weights, self.derivative);
+            func_evals += evals;
+
+            let previous = row.back().copied();
+            richardson_extend(&mut row, estimate, 4.0);
+            let extrapolated = *row.back().unwrap();
+
+            if let Some(previous) = previous {
+                let error_estimate = (extrapolated - previous).abs();
+                if error_estimate <= best.1 {
+                    best = (extrapolated, error_estimate);
+                } else if i > 1 && error_estimate > 2.0 * best.1 {
+                    break;
+                }
+            } else if self.size == 1 {
+                best = (extrapolated, f64::NAN);
+            }
+            h *= 0.5;
+        }
+
+        Ok(DerivativeMetrics {
+            msg: String::from("Completed Richardson extrapolation.\n"),
+            derivative: best.0,
+            error_estimate: best.1,
+            func_evals,
+        })
+    }
+}
+
+/// # Complex Step
+/// First derivative of a real analytic function from Im f(x + ih) / h, which involves no
+/// subtraction and is therefore exact to machine precision for tiny h (1e-20 by default).
+/// f must be evaluated with complex arithmetic throughout, e.g. `|z: Complex64| z.exp() * z`.
+pub struct ComplexStep<F> {
+    f: F,
+    x: f64,
+    h: f64,
+}
+
+impl<F> ComplexStep<F>
+where
+    F: FnMut(Complex64) -> Complex64,
+{
+    pub fn initialize(f: F, x: f64) -> Self {
+        Self { f, x, h: 1e-20 }
+    }
+
+    pub fn h(mut self, h: f64) -> Self {
+        self.h = h;
+        self
+    }
+
+    pub fn run(mut self) -> Result<DerivativeMetrics, DifferentiationError> {
+        if !(self.h > 0.0 && self.h.is_finite()) {
+            return Err(DifferentiationError::UnacceptableStepError);
+        }
+
+        let f_x = (self.f)(Complex64::new(self.x, self.h));
+        Ok(DerivativeMetrics {
+            msg: String::from("Complex step derivative.\n"),
+            derivative: f_x.im / self.h,
+            error_estimate: f64::NAN,
+            func_evals: 1,
+        })
+    }
+}
+
+// Integer offsets of the points of a formula for the given derivative and accuracy order
+fn stencil_offsets(stencil: Stencil, derivative: usize, accuracy: usize) -> Vec<f64> {
+    match stencil {
+        Stencil::Central => {
+            let half = (derivative.div_ceil(2) + accuracy / 2 - 1) as i64;
+            (-half..=half).map(|k| k as f64).collect()
+        }
+        Stencil::Forward => (0..derivative + accuracy).map(|k| k as f64).collect(),
+        Stencil::Backward => (0..derivative + accuracy).map(|k| -(k as f64)).collect(),
+    }
+}
+
+// Fornberg's algorithm for the weights of the derivative of order m at 0 on the given points
+fn fornberg_weights(z: &[f64], m: usize) -> Vec<f64> {
+    let n = z.len();
+    let mut c = vec![vec![0.0; m + 1]; n];
+    let mut c1 = 1.0;
+    let mut c4 = z[0];
+    c[0][0] = 1.0;
+
+    for i in 1..n {
+        let mn = i.min(m);
+        let mut c2 = 1.0;
+        let c5 = c4;
+        c4 = z[i];
+        for j in 0..i {
+            let c3 = z[i] - z[j];
+            c2 *= c3;
+            if j == i - 1 {
+                for k in (1..=mn).rev() {
+                    c[i][k] = c1 * (k as f64 * c[i - 1][k - 1] - c5 * c[i - 1][k]) / c2;
+                }
+                c[i][0] = -c1 * c5 * c[i - 1][0] / c2;
+            }
+            for k in (1..=mn).rev() {
+                c[j][k] = (c4 * c[j][k] - k as f64 * c[j][k - 1]) / c3;
+            }
+            c[j][0] = c4 * c[j][0] / c3;
+        }
+        c1 = c2;
+    }
+    c.iter().map(|weights| weights[m]).collect()
+}
+
+// Weighted sum of f over the stencil scaled by h, skipping points with a zero weight
+fn apply_stencil<F>(
+    f: &mut F,
+    x: f64,
+    h: f64,
+    offsets: &[f64],
+    weights: &[f64],
+    derivative: usize,
+) -> (f64, usize)
+where
+    F: FnMut(f64) -> f64,
+{
+    let mut sum = 0.0;
+    let mut func_evals = 0;
+    for (offset, weight) in offsets.iter().zip(weights.iter()) {
+        if *weight != 0.0 {
+            sum += weight * f(x + offset * h);
+            func_evals += 1;
+        }
+    }
+    (sum / h.powi(derivative as i32), func_evals)
+}
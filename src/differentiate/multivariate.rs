@@ -0,0 +1,92 @@
+use ndarray::{Array1, Array2};
+
+use super::derivative::DifferentiationError;
+
+// Step of central differences for variable x_k, scaled to its magnitude
+fn step(x_k: f64, exponent: f64) -> f64 {
+    f64::EPSILON.powf(exponent) * x_k.abs().max(1.0)
+}
+
+///Gradient of a scalar function of a vector with central differences,
+///two evaluations per variable and a step of eps^(1/3) max(|x_k|, 1).
+pub fn gradient<F>(mut f: F, x: &Array1<f64>) -> Array1<f64>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+{
+    let mut g = Array1::zeros(x.len());
+    let mut x_h = x.clone();
+    for k in 0..x.len() {
+        let h = step(x[k], 1.0 / 3.0);
+        x_h[k] = x[k] + h;
+        let f_plus = f(&x_h);
+        x_h[k] = x[k] - h;
+        let f_minus = f(&x_h);
+        x_h[k] = x[k];
+        g[k] = (f_plus - f_minus) / (2.0 * h);
+    }
+    g
+}
+
+///Jacobian of a vector function of a vector with central differences, with entry (i, k)
+///the derivative of the i-th component with respect to x_k.
+///Fails if the function returns vectors of different lengths.
+pub fn jacobian<F>(mut f: F, x: &Array1<f64>) -> Result<Array2<f64>, DifferentiationError>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+{
+    let m = f(x).len();
+    let mut j = Array2::zeros((m, x.len()));
+    let mut x_h = x.clone();
+    for k in 0..x.len() {
+        let h = step(x[k], 1.0 / 3.0);
+        x_h[k] = x[k] + h;
+        let f_plus = f(&x_h);
+        x_h[k] = x[k] - h;
+        let f_minus = f(&x_h);
+        x_h[k] = x[k];
+        if f_plus.len() != m || f_minus.len() != m {
+            return Err(DifferentiationError::DimensionMismatchError);
+        }
+        j.column_mut(k).assign(&((f_plus - f_minus) / (2.0 * h)));
+    }
+    Ok(j)
+}
+
+///Hessian of a scalar function of a vector with central second differences and a step of
+///eps^(1/4) max(|x_k|, 1). The result is symmetric by construction.
+pub fn hessian<F>(mut f: F, x: &Array1<f64>) -> Array2<f64>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+{
+    let n = x.len();
+    let h: Vec<f64> = x.iter().map(|x_k| step(*x_k, 0.25)).collect();
+    let f_x = f(x);
+    let mut hess = Array2::zeros((n, n));
+    let mut x_h = x.clone();
+
+    for i in 0..n {
+        x_h[i] = x[i] + h[i];
+        let f_plus = f(&x_h);
+        x_h[i] = x[i] - h[i];
+        let f_minus = f(&x_h);
+        x_h[i] = x[i];
+        hess[[i, i]] = (f_plus - 2.0 * f_x + f_minus) / (h[i] * h[i]);
+
+        for k in 0..i {
+            let mut corner = |s_i: f64, s_k: f64| {
+                x_h[i] = x[i] + s_i * h[i];
+                x_h[k] = x[k] + s_k * h[k];
+                let f_corner = f(&x_h);
+                x_h[i] = x[i];
+                x_h[k] = x[k];
+                f_corner
+            };
+            let value = (corner(1.0, 1.0) - corner(1.0, -1.0) - corner(-1.0, 1.0)
+                + corner(-1.0, -1.0))
+                / (4.0 * h[i] * h[k]);
+            hess[[i, k]] = value;
+            hess[[k, i]] = value;
+        }
+    }
+    hess
+}
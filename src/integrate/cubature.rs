@@ -0,0 +1,732 @@
+use core::fmt;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::error::Error;
+
+use super::quad::{Quad, QuadError};
+use crate::common::float::Real;
+
+static DEFAULT_TOL: f64 = 1e-10;
+static DEFAULT_RTOL: f64 = 1e-8;
+static DEFAULT_MAX_EVALS: usize = 1_000_000;
+
+// Genz-Malik rule of degree 7 with an embedded rule of degree 5
+static LAMBDA2: f64 = 0.358_568_582_800_318_1; // sqrt(9/70)
+static LAMBDA4: f64 = 0.948_683_298_050_513_8; // sqrt(9/10)
+static LAMBDA5: f64 = 0.688_247_201_611_685_3; // sqrt(9/19)
+
+// Ratio (LAMBDA2 / LAMBDA4)^2
+static RATIO: f64 = 1.0 / 7.0;
+
+// Relative difference of the fourth differences below which the widest axis is split
+static SPLIT_TOL: f64 = 1e-5;
+
+///Output characteristics of the multidimensional integrators.
+/// ## Attributes
+/// - msg:String
+/// - integral:T
+/// - error_estimate:T
+/// - func_evals:usize
+#[derive(Debug)]
+pub struct CubatureCharacteristics<T = f64> {
+    pub msg: String,
+    pub integral: T,
+    pub error_estimate: T,
+    pub func_evals: usize,
+}
+
+impl<T> fmt::Display for CubatureCharacteristics<T>
+where
+    T: Real + fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}\nWith {} function evaluations, the result is {} with error {:.5e}",
+            self.msg, self.func_evals, self.integral, self.error_estimate
+        )
+    }
+}
+
+///Errors of the multidimensional integrators.
+///## Types
+/// - Invalid Input
+/// - Dimension not supported or bounds of different lengths
+/// - Region with infinite or NaN bounds, or a degenerate simplex
+/// - Divergence Occured
+/// - Error of an inner one dimensional integral
+/// - Tolerance Requirments Not Met
+#[derive(Debug)]
+pub enum CubatureError<T = f64> {
+    InvalidInput(String),
+    DimensionError,
+    RegionError,
+    Divergence,
+    Quad(QuadError<T>),
+    UnacceptableTolearanceError(CubatureCharacteristics<T>),
+}
+
+impl<T> fmt::Display for CubatureError<T>
+where
+    T: Real + fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CubatureError::InvalidInput(message) => {
+                write!(f, "The algorithm could not start due to {}", message)
+            }
+            CubatureError::DimensionError => {
+                write!(
+                    f,
+                    "The dimension is not supported by the method or the bounds have different lengths."
+                )
+            }
+            CubatureError::RegionError => write!(f, "The integration region is not valid."),
+            CubatureError::Divergence => write!(f, "The integral is probably divergent."),
+            CubatureError::Quad(error) => write!(f, "An inner integral failed: {}", error),
+            CubatureError::UnacceptableTolearanceError(char) => {
+                write!(
+                    f,
+                    "The algorithm has terminated without meeting the tolerance requirements.\n{}",
+                    char
+                )
+            }
+        }
+    }
+}
+
+impl<T> Error for CubatureError<T>
+where
+    T: Real + fmt::LowerExp + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CubatureError::Quad(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<QuadError<T>> for CubatureError<T> {
+    fn from(error: QuadError<T>) -> Self {
+        CubatureError::Quad(error)
+    }
+}
+
+// Finite bounds of a box, ordered, with the sign of the orientation of the original bounds
+pub(crate) fn ordered_box<T: Real>(
+    lower: &[T],
+    upper: &[T],
+) -> Result<(Vec<T>, Vec<T>, T), CubatureError<T>> {
+    if lower.len() != upper.len() || lower.is_empty() {
+        return Err(CubatureError::DimensionError);
+    }
+    if lower.iter().chain(upper).any(|bound| !bound.is_finite()) {
+        return Err(CubatureError::RegionError);
+    }
+    let mut sign = T::one();
+    let (mut a, mut b) = (lower.to_vec(), upper.to_vec());
+    for i in 0..a.len() {
+        if a[i] > b[i] {
+            std::mem::swap(&mut a[i], &mut b[i]);
+            sign = -sign;
+        }
+    }
+    Ok((a, b, sign))
+}
+
+// Region of integration of the Genz-Malik cubature
+enum Domain<T> {
+    Box { lower: Vec<T>, upper: Vec<T> },
+    // Vertex v0 and the edges v_k - v0 of a simplex
+    Simplex { origin: Vec<T>, edges: Vec<Vec<T>> },
+}
+
+/// # Genz-Malik Cubature
+/// Adaptive cubature over hyperrectangles and simplices of dimension 2 or more.
+/// Every region is integrated with the Genz-Malik rule of degree 7, whose difference from the
+/// embedded rule of degree 5 is the error estimate, and the region with the largest error is
+/// bisected along the axis where the fourth differences of f are largest, until the sum of the
+/// errors is below max(tolerance, relative_tolerance |integral|) or the evaluation limit is reached.
+/// A rule takes 2^n + 2n^2 + 2n + 1 evaluations, so the method is best suited to dimensions
+/// up to about 10; `QuasiMonteCarlo` scales to higher dimensions.
+/// A simplex is mapped to the unit cube with collapsed coordinates.
+/// The default tolerances are raised to 100 times the machine epsilon for types that cannot
+/// resolve them, e.g. f32.
+///
+/// ## Methods
+/// - Initialize the struct on a box or a simplex
+/// - Change Tolerance Parameters
+/// - Change the limit of function evaluations
+/// - Run and Compute the integrals
+pub struct Cubature<F, T = f64> {
+    f: F,
+    domain: Domain<T>,
+    tolerance: T,
+    relative_tolerance: T,
+    max_evals: usize,
+
+    error_type: Option<CubatureError<T>>,
+}
+
+impl<F> Cubature<F>
+where
+    F: FnMut(&[f64]) -> f64,
+{
+    ///Integration over the box with the given lower and upper bounds of every variable
+    pub fn initialize(function: F, lower: &[f64], upper: &[f64]) -> Self {
+        Self::new(function, lower, upper)
+    }
+
+    ///Integration over the simplex with the given n + 1 vertices in n dimensions
+    pub fn simplex(function: F, vertices: &[Vec<f64>]) -> Self {
+        Self::new_simplex(function, vertices)
+    }
+}
+
+impl<F, T> Cubature<F, T>
+where
+    F: FnMut(&[T]) -> T,
+    T: Real,
+{
+    ///Same as `initialize` for any floating-point type, which is inferred from the bounds
+    pub fn new(function: F, lower: &[T], upper: &[T]) -> Self {
+        Self::with_domain(
+            function,
+            Domain::Box {
+                lower: lower.to_vec(),
+                upper: upper.to_vec(),
+            },
+        )
+    }
+
+    ///Same as `simplex` for any floating-point type, which is inferred from the vertices
+    pub fn new_simplex(function: F, vertices: &[Vec<T>]) -> Self {
+        let origin = vertices.first().cloned().unwrap_or_default();
+        let edges = vertices
+            .iter()
+            .skip(1)
+            .map(|v| {
+                v.iter()
+                    .zip(&origin)
+                    .map(|(v_i, o_i)| *v_i - *o_i)
+                    .collect()
+            })
+            .collect();
+        Self::with_domain(function, Domain::Simplex { origin, edges })
+    }
+
+    fn with_domain(function: F, domain: Domain<T>) -> Self {
+        let floor = T::constant(100.0) * T::epsilon();
+        Self {
+            f: function,
+            domain,
+            tolerance: T::constant(DEFAULT_TOL).max(floor),
+            relative_tolerance: T::constant(DEFAULT_RTOL).max(floor),
+            max_evals: DEFAULT_MAX_EVALS,
+
+            error_type: None,
+        }
+    }
+
+    ///A method that changes the tolerance of the computation
+    pub fn change_tolerance(mut self, tol: T) -> Self {
+        if tol < T::default_rtol() {
+            self.error_type = Some(CubatureError::InvalidInput(
+                "invalid tolerance.".to_string(),
+            ));
+        }
+        self.tolerance = tol;
+        self
+    }
+
+    ///A method that changes the relative tolerance of the computation
+    pub fn change_relative_tolerance(mut self, rtol: T) -> Self {
+        if rtol < T::default_rtol() {
+            self.error_type = Some(CubatureError::InvalidInput(
+                "invalid relative tolerance.".to_string(),
+            ));
+        }
+        self.relative_tolerance = rtol;
+        self
+    }
+
+    ///A method that changes the maximum number of function evaluations
+    pub fn change_max_evals(mut self, max_evals: usize) -> Self {
+        self.max_evals = max_evals;
+        self
+    }
+
+    pub fn run(mut self) -> Result<CubatureCharacteristics<T>, CubatureError<T>> {
+        if let Some(error) = self.error_type.take() {
+            return Err(error);
+        }
+        let (tol, rtol, max_evals) = (self.tolerance, self.relative_tolerance, self.max_evals);
+        let f = &mut self.f;
+
+        match &self.domain {
+            Domain::Box { lower, upper } => {
+                let (a, b, sign) = ordered_box(lower, upper)?;
+                let mut result = adaptive(f, &a, &b, tol, rtol, max_evals)?;
+                result.integral = sign * result.integral;
+                Ok(result)
+            }
+            Domain::Simplex { origin, edges } => {
+                let n = origin.len();
+                if n < 2 || edges.len() != n || edges.iter().any(|edge| edge.len() != n) {
+                    return Err(CubatureError::DimensionError);
+                }
+                let volume_factor = determinant(edges.clone()).abs();
+                if !(volume_factor > T::zero() && volume_factor.is_finite()) {
+                    return Err(CubatureError::RegionError);
+                }
+
+                let mut y = vec![T::zero(); n];
+                let mut mapped = |u: &[T]| {
+                    // Collapsed coordinates x_k = u_k (1 - u_0) ... (1 - u_(k-1))
+                    let mut scale = T::one();
+                    let mut jacobian = volume_factor;
+                    y.copy_from_slice(origin);
+                    for (k, &u_k) in u.iter().enumerate() {
+                        let x_k = scale * u_k;
+                        jacobian = jacobian * scale;
+                        for (y_i, &edge_i) in y.iter_mut().zip(&edges[k]) {
+                            *y_i = *y_i + x_k * edge_i;
+                        }
+                        scale = scale * (T::one() - u_k);
+                    }
+                    jacobian * f(&y)
+                };
+                adaptive(
+                    &mut mapped,
+                    &vec![T::zero(); n],
+                    &vec![T::one(); n],
+                    tol,
+                    rtol,
+                    max_evals,
+                )
+            }
+        }
+    }
+}
+
+// Subregion with the results of the rule, ordered by error estimate
+struct Region<T> {
+    center: Vec<T>,
+    half_width: Vec<T>,
+    integral: T,
+    error: T,
+    split: usize,
+}
+
+impl<T: Real> PartialEq for Region<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Real> Eq for Region<T> {}
+
+impl<T: Real> PartialOrd for Region<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Real> Ord for Region<T> {
+    // Regions whose rule is not finite are reported as divergence and never enter the heap
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.error
+            .partial_cmp(&other.error)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+fn rule_points(n: usize) -> usize {
+    (1usize << n) + 2 * n * n + 2 * n + 1
+}
+
+fn adaptive<F, T>(
+    f: &mut F,
+    a: &[T],
+    b: &[T],
+    tol: T,
+    rtol: T,
+    max_evals: usize,
+) -> Result<CubatureCharacteristics<T>, CubatureError<T>>
+where
+    F: FnMut(&[T]) -> T,
+    T: Real,
+{
+    let n = a.len();
+    if !(2..usize::BITS as usize).contains(&n) || rule_points(n) > max_evals {
+        return Err(CubatureError::DimensionError);
+    }
+    let points = rule_points(n);
+    let half = T::constant(0.5);
+
+    let center = a
+        .iter()
+        .zip(b)
+        .map(|(&a_i, &b_i)| half * (a_i + b_i))
+        .collect();
+    let half_width = a
+        .iter()
+        .zip(b)
+        .map(|(&a_i, &b_i)| half * (b_i - a_i))
+        .collect();
+    let first = genz_malik(f, center, half_width)?;
+    let mut func_evals = points;
+    let mut integral = first.integral;
+    let mut error = first.error;
+    let mut heap = BinaryHeap::from([first]);
+
+    let converged = loop {
+        if error <= tol.max(rtol * integral.abs()) {
+            break true;
+        }
+        if func_evals + 2 * points > max_evals {
+            break false;
+        }
+
+        let worst = heap.pop().unwrap();
+        let s = worst.split;
+        let mut half_width = worst.half_width.clone();
+        half_width[s] = half * half_width[s];
+        let mut left = worst.center.clone();
+        left[s] = left[s] - half_width[s];
+        let mut right = worst.center;
+        right[s] = right[s] + half_width[s];
+
+        let left = genz_malik(f, left, half_width.clone())?;
+        let right = genz_malik(f, right, half_width)?;
+        func_evals += 2 * points;
+        integral = integral + left.integral + right.integral - worst.integral;
+        error = error + left.error + right.error - worst.error;
+        heap.push(left);
+        heap.push(right);
+    };
+
+    // Sum again to remove the rounding accumulated by the updates
+    let integral = heap
+        .iter()
+        .fold(T::zero(), |sum, region| sum + region.integral);
+    let error_estimate = heap
+        .iter()
+        .fold(T::zero(), |sum, region| sum + region.error);
+    let cubchar = CubatureCharacteristics {
+        msg: "Completed Integration".to_string(),
+        integral,
+        error_estimate,
+        func_evals,
+    };
+    if converged {
+        Ok(cubchar)
+    } else {
+        Err(CubatureError::UnacceptableTolearanceError(
+            CubatureCharacteristics {
+                msg: "Unacceptable Tolerance due to meeting the function evaluations limit\n"
+                    .to_string(),
+                ..cubchar
+            },
+        ))
+    }
+}
+
+// The weights are computed in f64 and rounded to T
+fn genz_malik<F, T>(
+    f: &mut F,
+    center: Vec<T>,
+    half_width: Vec<T>,
+) -> Result<Region<T>, CubatureError<T>>
+where
+    F: FnMut(&[T]) -> T,
+    T: Real,
+{
+    let n = center.len();
+    let c = &center;
+    let h = &half_width;
+    let mut p = center.clone();
+    let (lambda2, lambda4, lambda5) = (
+        T::constant(LAMBDA2),
+        T::constant(LAMBDA4),
+        T::constant(LAMBDA5),
+    );
+    let (one, two) = (T::one(), T::constant(2.0));
+
+    let f0 = f(&p);
+    let (mut sum2, mut sum3, mut sum4, mut sum5) = (T::zero(), T::zero(), T::zero(), T::zero());
+    let mut fourth_differences = vec![T::zero(); n];
+
+    for i in 0..n {
+        let mut at = |offset: T| {
+            p[i] = c[i] + offset * h[i];
+            f(&p)
+        };
+        let (f1, f2) = (at(-lambda2), at(lambda2));
+        let (f3, f4) = (at(-lambda4), at(lambda4));
+        p[i] = c[i];
+        sum2 = sum2 + f1 + f2;
+        sum3 = sum3 + f3 + f4;
+        fourth_differences[i] =
+            (f1 + f2 - two * f0 - T::constant(RATIO) * (f3 + f4 - two * f0)).abs();
+    }
+
+    for i in 0..n {
+        for j in i + 1..n {
+            for (s_i, s_j) in [(-one, -one), (-one, one), (one, -one), (one, one)] {
+                p[i] = c[i] + s_i * lambda4 * h[i];
+                p[j] = c[j] + s_j * lambda4 * h[j];
+                sum4 = sum4 + f(&p);
+            }
+            p[i] = c[i];
+            p[j] = c[j];
+        }
+    }
+
+    for corner in 0..(1usize << n) {
+        for i in 0..n {
+            let sign = if (corner >> i) & 1 == 1 { one } else { -one };
+            p[i] = c[i] + sign * lambda5 * h[i];
+        }
+        sum5 = sum5 + f(&p);
+    }
+
+    let n_f = n as f64;
+    let volume = h.iter().fold(one, |volume, &h_i| volume * (two * h_i));
+    let w1 = T::constant((12824.0 - 9120.0 * n_f + 400.0 * n_f * n_f) / 19683.0);
+    let w2 = T::constant(980.0 / 6561.0);
+    let w3 = T::constant((1820.0 - 400.0 * n_f) / 19683.0);
+    let w4 = T::constant(200.0 / 19683.0);
+    let w5 = T::constant(6859.0 / 19683.0 / 2f64.powi(n as i32));
+    let degree7 = volume * (w1 * f0 + w2 * sum2 + w3 * sum3 + w4 * sum4 + w5 * sum5);
+
+    let e1 = T::constant((729.0 - 950.0 * n_f + 50.0 * n_f * n_f) / 729.0);
+    let e2 = T::constant(245.0 / 486.0);
+    let e3 = T::constant((265.0 - 100.0 * n_f) / 1458.0);
+    let e4 = T::constant(25.0 / 729.0);
+    let degree5 = volume * (e1 * f0 + e2 * sum2 + e3 * sum3 + e4 * sum4);
+
+    if !degree7.is_finite() {
+        return Err(CubatureError::Divergence);
+    }
+
+    // Bisect where the fourth differences are largest, or along the widest axis on a tie
+    let mut split = 0;
+    for i in 1..n {
+        let delta = fourth_differences[i] - fourth_differences[split];
+        let tie = delta.abs() <= T::constant(SPLIT_TOL) * fourth_differences[split];
+        if (!tie && delta > T::zero()) || (tie && h[i] > h[split]) {
+            split = i;
+        }
+    }
+
+    Ok(Region {
+        center,
+        half_width,
+        integral: degree7,
+        error: (degree7 - degree5).abs(),
+        split,
+    })
+}
+
+// Determinant with Gaussian elimination and partial pivoting
+fn determinant<T: Real>(mut m: Vec<Vec<T>>) -> T {
+    let n = m.len();
+    let mut det = T::one();
+    for k in 0..n {
+        let pivot = (k..n)
+            .max_by(|&i, &j| {
+                m[i][k]
+                    .abs()
+                    .partial_cmp(&m[j][k].abs())
+                    .unwrap_or(Ordering::Equal)
+            })
+            .unwrap();
+        if m[pivot][k] == T::zero() {
+            return T::zero();
+        }
+        if pivot != k {
+            m.swap(pivot, k);
+            det = -det;
+        }
+        det = det * m[k][k];
+        let (upper, lower) = m.split_at_mut(k + 1);
+        let pivot_row = &upper[k];
+        for row in lower.iter_mut() {
+            let factor = row[k] / pivot_row[k];
+            for (entry, &pivot_entry) in row[k..].iter_mut().zip(&pivot_row[k..]) {
+                *entry = *entry - factor * pivot_entry;
+            }
+        }
+    }
+    det
+}
+
+/// # Nested Quadrature
+/// Integration over a box as iterated one dimensional integrals, each computed with the
+/// adaptive `Quad`, from the last variable (innermost) to the first (outermost).
+/// It is accurate for integrands that are smooth in some variables and irregular in others,
+/// but the number of evaluations grows exponentially with the dimension.
+/// The error estimate of every level is that of its `Quad` plus the error estimates of the inner
+/// integrals, integrated with the trapezoid rule over the nodes where they were computed.
+/// The default tolerances are raised to 100 times the machine epsilon for types that cannot
+/// resolve them, e.g. f32.
+///
+/// ## Methods
+/// - Initialize the struct
+/// - Change Tolerance Parameters, used for every one dimensional integral
+/// - Run and Compute the integrals
+pub struct NestedQuad<F, T = f64> {
+    f: F,
+    lower: Vec<T>,
+    upper: Vec<T>,
+    tolerance: T,
+    relative_tolerance: T,
+}
+
+impl<F> NestedQuad<F>
+where
+    F: FnMut(&[f64]) -> f64,
+{
+    pub fn initialize(function: F, lower: &[f64], upper: &[f64]) -> Self {
+        Self::new(function, lower, upper)
+    }
+}
+
+impl<F, T> NestedQuad<F, T>
+where
+    F: FnMut(&[T]) -> T,
+    T: Real,
+{
+    ///Same as `initialize` for any floating-point type, which is inferred from the bounds
+    pub fn new(function: F, lower: &[T], upper: &[T]) -> Self {
+        let floor = T::constant(100.0) * T::epsilon();
+        Self {
+            f: function,
+            lower: lower.to_vec(),
+            upper: upper.to_vec(),
+            tolerance: T::constant(DEFAULT_TOL).max(floor),
+            relative_tolerance: T::constant(DEFAULT_RTOL).max(floor),
+        }
+    }
+
+    ///A method that changes the tolerance of the computation
+    pub fn change_tolerance(mut self, tol: T) -> Self {
+        self.tolerance = tol;
+        self
+    }
+
+    ///A method that changes the relative tolerance of the computation
+    pub fn change_relative_tolerance(mut self, rtol: T) -> Self {
+        self.relative_tolerance = rtol;
+        self
+    }
+
+    pub fn run(mut self) -> Result<CubatureCharacteristics<T>, CubatureError<T>> {
+        // Quad takes care of the orientation of every interval
+        ordered_box(&self.lower, &self.upper)?;
+
+        let mut point = self.lower.clone();
+        let mut func_evals = 0;
+        let mut counted_function = |x: &[T]| {
+            func_evals += 1;
+            (self.f)(x)
+        };
+        let (integral, error_estimate) = nested(
+            &mut counted_function,
+            &self.lower,
+            &self.upper,
+            &mut point,
+            0,
+            (self.tolerance, self.relative_tolerance),
+        )?;
+
+        Ok(CubatureCharacteristics {
+            msg: "Completed Integration".to_string(),
+            integral,
+            error_estimate,
+            func_evals,
+        })
+    }
+}
+
+// Integral over the variables from depth on, with the previous ones fixed in point
+fn nested<F, T>(
+    f: &mut F,
+    lower: &[T],
+    upper: &[T],
+    point: &mut [T],
+    depth: usize,
+    tolerances: (T, T),
+) -> Result<(T, T), CubatureError<T>>
+where
+    F: FnMut(&[T]) -> T,
+    T: Real,
+{
+    let (a, b) = (lower[depth], upper[depth]);
+    let (tol, rtol) = tolerances;
+
+    if depth == point.len() - 1 {
+        let quadchar = Quad::new(
+            |x| {
+                point[depth] = x;
+                f(point)
+            },
+            a,
+            b,
+        )
+        .change_tolerance(tol)
+        .change_relative_tolerance(rtol)
+        .run()?;
+        return Ok((quadchar.integral, quadchar.error_estimate));
+    }
+
+    let mut inner_error = None;
+    let mut inner_estimates = Vec::new();
+    let outer = Quad::new(
+        |x| {
+            point[depth] = x;
+            match nested(f, lower, upper, point, depth + 1, tolerances) {
+                Ok((integral, error_estimate)) => {
+                    inner_estimates.push((x, error_estimate));
+                    integral
+                }
+                Err(error) => {
+                    // Stops the outer integral, the inner error is returned instead
+                    inner_error.get_or_insert(error);
+                    T::nan()
+                }
+            }
+        },
+        a,
+        b,
+    )
+    .change_tolerance(tol)
+    .change_relative_tolerance(rtol)
+    .run();
+
+    if let Some(error) = inner_error {
+        return Err(error);
+    }
+    let outer = outer?;
+    Ok((
+        outer.integral,
+        outer.error_estimate + integrate_samples(&mut inner_estimates, a.min(b), a.max(b)),
+    ))
+}
+
+// Trapezoid rule over unordered samples in [a, b], extended by constants to the endpoints
+fn integrate_samples<T: Real>(samples: &mut [(T, T)], a: T, b: T) -> T {
+    // The nodes of Quad are finite
+    samples.sort_by(|p, q| p.0.partial_cmp(&q.0).unwrap_or(Ordering::Equal));
+    match (samples.first(), samples.last()) {
+        (Some(first), Some(last)) => {
+            let inner = samples.windows(2).fold(T::zero(), |sum, pair| {
+                sum + T::constant(0.5) * (pair[1].0 - pair[0].0) * (pair[0].1 + pair[1].1)
+            });
+            inner + (first.0 - a) * first.1 + (b - last.0) * last.1
+        }
+        _ => T::zero(),
+    }
+}
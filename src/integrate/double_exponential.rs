@@ -0,0 +1,366 @@
+use std::f64::consts::FRAC_PI_2;
+
+use super::integrator::{IntegrationError, IntegrationMetrics, Integrator};
+use super::quad::{QuadCharacteristics, QuadError};
+use crate::common::float::Real;
+
+static DEFAULT_TOL: f64 = 1e-11;
+static DEFAULT_RTOL: f64 = 1e-10;
+static DEFAULT_MAX_LEVEL: usize = 12;
+// Levels computed before the error estimate is trusted
+static MIN_LEVEL: usize = 2;
+// Beyond this t the nodes of every transformation have collided with the endpoints or overflowed
+static T_MAX: f64 = 6.5;
+
+// Change of variables x(t), with the weight dx/dt, on the interval of the integral
+#[derive(Debug, Clone, Copy)]
+enum Transform<T> {
+    // Tanh-sinh, x = c + l tanh(pi/2 sinh t) on [c - l, c + l]
+    Finite { a: T, b: T },
+    // Exp-sinh, x = a + exp(pi/2 sinh t) on [a, inf)
+    HalfInfinite { a: T },
+    // Sinh-sinh, x = sinh(pi/2 sinh t) on (-inf, inf)
+    Infinite,
+}
+
+impl<T: Real> Transform<T> {
+    // Node and weight at t, None once the node has collided with an endpoint of a finite interval,
+    // overflowed or its weight has underflowed, which happens for every larger |t| as well.
+    // Exp-sinh nodes that round onto a are kept, since a large a can absorb a sizeable part
+    // of the offsets.
+    fn node(&self, t: T) -> Option<(T, T)> {
+        let frac_pi_2 = T::constant(FRAC_PI_2);
+        let u = frac_pi_2 * t.sinh();
+        let du = frac_pi_2 * t.cosh();
+        let (x, w) = match *self {
+            Transform::Finite { a, b } => {
+                let half_length = T::constant(0.5) * (b - a);
+                let cosh_u = u.cosh();
+                // 1 - tanh|u|, computed without cancellation so that nodes approach the endpoints
+                let distance = half_length * (-u.abs()).exp() / cosh_u;
+                let x = if t < T::zero() {
+                    a + distance
+                } else {
+                    b - distance
+                };
+                if x <= a || x >= b {
+                    return None;
+                }
+                (x, half_length * du / (cosh_u * cosh_u))
+            }
+            Transform::HalfInfinite { a } => {
+                let offset = u.exp();
+                (a + offset, du * offset)
+            }
+            Transform::Infinite => (u.sinh(), du * u.cosh()),
+        };
+        if x.is_finite() && w.is_finite() && w > T::zero() {
+            Some((x, w))
+        } else {
+            None
+        }
+    }
+
+    // Whether an exp-sinh node has rounded onto its finite endpoint
+    fn on_endpoint(&self, x: T) -> bool {
+        matches!(*self, Transform::HalfInfinite { a } if x <= a)
+    }
+}
+
+/// # Double Exponential Integration
+/// Trapezoid rule after a change of variables whose weights decay double exponentially,
+/// which makes it robust to integrable singularities at the endpoints, e.g. 1/sqrt(x) or ln(x)
+/// on [0, 1], and to infinite intervals.
+/// - Finite intervals use tanh-sinh, x = c + l tanh(pi/2 sinh t)
+/// - Half infinite intervals use exp-sinh, x = a + exp(pi/2 sinh t)
+/// - The whole real line uses sinh-sinh, x = sinh(pi/2 sinh t)
+///
+/// The step in t is halved on every level, reusing the previous nodes, until the difference
+/// between two levels is below max(tolerance, relative_tolerance |integral|). This difference is
+/// the error estimate and is usually pessimistic, since the number of correct digits roughly
+/// doubles with each level. The number of intervals in the output is that of the last trapezoid
+/// rule. Close to a nonzero endpoint the nodes can only approach it up to rounding, so
+/// singularities are best moved to 0 before integrating. Exp-sinh nodes that round onto a are
+/// evaluated there and left out if f is not finite at a.
+/// The default tolerances are raised to 100 times the machine epsilon for types that cannot
+/// resolve them, e.g. f32.
+///
+/// ## Methods
+/// - Initialize the struct
+/// - Change Tolerance Parameters
+/// - Change the maximum level
+/// - Run and Compute the integrals
+pub struct DoubleExponential<F, T = f64> {
+    f: F,
+    a: T,
+    b: T,
+    tolerance: T,
+    relative_tolerance: T,
+    max_level: usize,
+    func_evals: usize,
+
+    error_type: Option<QuadError<T>>,
+}
+
+impl<F> DoubleExponential<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    /// Initialize a new DoubleExponential instance with the given function,
+    /// lower and upper bounds, which may be infinite.
+    pub fn initialize(function: F, a: f64, b: f64) -> Self {
+        Self::new(function, a, b)
+    }
+}
+
+impl<F, T> DoubleExponential<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    ///Same as `initialize` for any floating-point type, which is inferred from a and b
+    pub fn new(function: F, a: T, b: T) -> Self {
+        let floor = T::constant(100.0) * T::epsilon();
+        Self {
+            f: function,
+            a,
+            b,
+            tolerance: T::constant(DEFAULT_TOL).max(floor),
+            relative_tolerance: T::constant(DEFAULT_RTOL).max(floor),
+            max_level: DEFAULT_MAX_LEVEL,
+            func_evals: 0,
+
+            error_type: None,
+        }
+    }
+
+    ///A method that changes the tolerance of the computation
+    pub fn change_tolerance(mut self, tol: T) -> Self {
+        if tol < T::default_rtol() {
+            self.error_type = Some(QuadError::InvalidInput("invalid tolerance.".to_string()));
+        }
+        self.tolerance = tol;
+        self
+    }
+
+    ///A method that changes the relative tolerance of the computation
+    pub fn change_relative_tolerance(mut self, rtol: T) -> Self {
+        if rtol < T::default_rtol() {
+            self.error_type = Some(QuadError::InvalidInput(
+                "invalid relative tolerance.".to_string(),
+            ));
+        }
+        self.relative_tolerance = rtol;
+        self
+    }
+
+    ///A method that changes the number of times the step is halved, 2^level intervals per unit of t
+    pub fn change_max_level(mut self, level: usize) -> Self {
+        if level < MIN_LEVEL {
+            self.error_type = Some(QuadError::InvalidInput(
+                "invalid maximum level.".to_string(),
+            ));
+        }
+        self.max_level = level;
+        self
+    }
+
+    /// A method that runs the numerical integration and return the result.
+    pub fn run(mut self) -> Result<QuadCharacteristics<T>, QuadError<T>> {
+        self.compute()
+    }
+
+    ///Runs the computation without consuming the struct and records the number of function evaluations.
+    fn compute(&mut self) -> Result<QuadCharacteristics<T>, QuadError<T>> {
+        if let Some(QuadError::InvalidInput(message)) = &self.error_type {
+            return Err(QuadError::InvalidInput(message.clone()));
+        }
+        if self.a.is_nan() || self.b.is_nan() {
+            return Err(QuadError::IntervalError);
+        }
+
+        // Integrate from the smaller to the larger endpoint and restore the sign afterwards
+        let (a, b, sign) = if self.a <= self.b {
+            (self.a, self.b, T::one())
+        } else {
+            (self.b, self.a, -T::one())
+        };
+        if a == b {
+            return Ok(QuadCharacteristics {
+                msg: "Completed Integration".to_string(),
+                number_of_intervals: 0,
+                error_estimate: T::zero(),
+                integral: T::zero(),
+            });
+        }
+
+        let function = &mut self.f;
+        let mut func_evals = 0;
+        let mut counted_function = |x: T| {
+            func_evals += 1;
+            function(x)
+        };
+
+        let outcome = if a.is_finite() && b.is_finite() {
+            levels(
+                &mut counted_function,
+                Transform::Finite { a, b },
+                self.tolerance,
+                self.relative_tolerance,
+                self.max_level,
+            )
+        } else if a.is_finite() {
+            levels(
+                &mut counted_function,
+                Transform::HalfInfinite { a },
+                self.tolerance,
+                self.relative_tolerance,
+                self.max_level,
+            )
+        } else if b.is_finite() {
+            // x -> -x maps (-inf, b] to [-b, inf)
+            let mut reflected = |x: T| counted_function(-x);
+            levels(
+                &mut reflected,
+                Transform::HalfInfinite { a: -b },
+                self.tolerance,
+                self.relative_tolerance,
+                self.max_level,
+            )
+        } else {
+            levels(
+                &mut counted_function,
+                Transform::Infinite,
+                self.tolerance,
+                self.relative_tolerance,
+                self.max_level,
+            )
+        };
+        self.func_evals = func_evals;
+
+        let (integral, error_estimate, number_of_intervals, converged) = outcome?;
+        let quadchar = QuadCharacteristics {
+            msg: "Completed Integration".to_string(),
+            number_of_intervals,
+            error_estimate,
+            integral: sign * integral,
+        };
+        if converged {
+            Ok(quadchar)
+        } else {
+            Err(QuadError::UnacceptableTolearanceError(
+                QuadCharacteristics {
+                    msg: "Unacceptable Tolerance due to meeting the maximum level\n".to_string(),
+                    ..quadchar
+                },
+            ))
+        }
+    }
+}
+
+// Refines the trapezoid rule in t until the tolerance is met or the maximum level is reached.
+// Returns the integral, the error estimate, the number of intervals and whether it converged.
+fn levels<F, T>(
+    f: &mut F,
+    transform: Transform<T>,
+    tol: T,
+    rtol: T,
+    max_level: usize,
+) -> Result<(T, T, usize, bool), QuadError<T>>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    let mut h = T::one();
+    let (mut sum, mut nodes) = level_sum(f, transform, h, 1)?;
+    if let Some((x, w)) = transform.node(T::zero()) {
+        if let Some(term) = weighted(f, transform, x, w)? {
+            sum = sum + term;
+            nodes += 1;
+        }
+    }
+    let mut integral = h * sum;
+    let mut error = T::infinity();
+
+    for level in 1..=max_level {
+        h = h * T::constant(0.5);
+        // Only the odd multiples of the new step are new nodes
+        let (new_sum, new_nodes) = level_sum(f, transform, h, 2)?;
+        sum = sum + new_sum;
+        nodes += new_nodes;
+
+        let refined = h * sum;
+        error = (refined - integral).abs();
+        integral = refined;
+        if level >= MIN_LEVEL && error <= tol.max(rtol * integral.abs()) {
+            return Ok((integral, error, nodes.saturating_sub(1), true));
+        }
+    }
+    Ok((integral, error, nodes.saturating_sub(1), false))
+}
+
+// Sum of w f(x) over t = k h and t = -k h for k = 1, 1 + stride, 1 + 2 stride, ...
+// stopping on each side at the first node outside the interval.
+// Returns the sum and the number of nodes in it.
+fn level_sum<F, T>(
+    f: &mut F,
+    transform: Transform<T>,
+    h: T,
+    stride: usize,
+) -> Result<(T, usize), QuadError<T>>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    let mut sum = T::zero();
+    let mut nodes = 0;
+    for direction in [T::one(), -T::one()] {
+        let mut k = 1;
+        while T::constant(k as f64) * h <= T::constant(T_MAX) {
+            match transform.node(direction * T::constant(k as f64) * h) {
+                Some((x, w)) => {
+                    if let Some(term) = weighted(f, transform, x, w)? {
+                        sum = sum + term;
+                        nodes += 1;
+                    }
+                }
+                None => break,
+            }
+            k += stride;
+        }
+    }
+    Ok((sum, nodes))
+}
+
+// w f(x), or None for a node that has rounded onto an endpoint where f is not finite,
+// which is left out of the sum
+fn weighted<F, T>(f: &mut F, transform: Transform<T>, x: T, w: T) -> Result<Option<T>, QuadError<T>>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    let term = w * f(x);
+    if term.is_finite() {
+        Ok(Some(term))
+    } else if transform.on_endpoint(x) {
+        Ok(None)
+    } else {
+        Err(QuadError::Divergence)
+    }
+}
+
+impl<F, T> Integrator<T> for DoubleExponential<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn integrate(&mut self) -> Result<IntegrationMetrics<T>, IntegrationError<T>> {
+        let quadchar = self.compute()?;
+        Ok(IntegrationMetrics {
+            msg: quadchar.msg,
+            integral: quadchar.integral,
+            error_estimate: quadchar.error_estimate,
+            func_evals: self.func_evals,
+        })
+    }
+}
@@ -0,0 +1,406 @@
+use core::fmt;
+use std::error::Error;
+use std::f64::consts::PI;
+
+use super::integrator::{IntegralChar, IntegrationError, IntegrationMetrics, Integrator};
+use crate::common::float::Real;
+
+static DEFAULT_ORDER: usize = 20;
+static QL_MAX_ITER: usize = 60;
+
+// Lanczos approximation of the gamma function with g = 7
+static LANCZOS_G: f64 = 7.0;
+static LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Weight functions of the Gaussian rules, on their canonical intervals.
+/// - Legendre: 1 on [-1, 1]
+/// - Laguerre(alpha): x^alpha e^(-x) on [0, inf), alpha > -1
+/// - Hermite: e^(-x^2) on (-inf, inf)
+/// - ChebyshevFirstKind: 1 / sqrt(1 - x^2) on [-1, 1]
+/// - ChebyshevSecondKind: sqrt(1 - x^2) on [-1, 1]
+/// - Jacobi(alpha, beta): (1 - x)^alpha (1 + x)^beta on [-1, 1], alpha, beta > -1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GaussFamily {
+    Legendre,
+    Laguerre(f64),
+    Hermite,
+    ChebyshevFirstKind,
+    ChebyshevSecondKind,
+    Jacobi(f64, f64),
+}
+
+#[derive(Debug)]
+pub enum GaussError {
+    ZeroOrderError,
+    InvalidParameterError,
+    IntervalError,
+    NonConvergenceError,
+}
+
+impl fmt::Display for GaussError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GaussError::ZeroOrderError => write!(f, "The order of the rule must be positive."),
+            GaussError::InvalidParameterError => {
+                write!(f, "The parameters alpha and beta must be greater than -1.")
+            }
+            GaussError::IntervalError => write!(f, "The interval must be finite."),
+            GaussError::NonConvergenceError => {
+                write!(f, "The eigenvalues of the Jacobi matrix did not converge.")
+            }
+        }
+    }
+}
+
+impl Error for GaussError {}
+
+///Nodes in increasing order and weights of a Gaussian rule, which integrates w(x) p(x) exactly
+///for polynomials p of degree up to 2n - 1, with w the weight function of the family.
+/// ## Attributes
+/// - nodes:Vec<f64>
+/// - weights:Vec<f64>
+#[derive(Debug, Clone)]
+pub struct GaussRule {
+    pub nodes: Vec<f64>,
+    pub weights: Vec<f64>,
+}
+
+impl GaussRule {
+    ///Sum of the weights times the values of f at the nodes.
+    pub fn apply<F>(&self, mut f: F) -> f64
+    where
+        F: FnMut(f64) -> f64,
+    {
+        self.nodes
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(x, w)| w * f(*x))
+            .sum()
+    }
+}
+
+///Nodes and weights of the Gaussian rule of order n for the given family.
+///Chebyshev rules are known in closed form, while the others are computed with the
+///Golub-Welsch algorithm: the nodes are the eigenvalues of the symmetric tridiagonal Jacobi matrix
+///of the three term recurrence of the orthogonal polynomials, and the weights follow from the
+///first components of its eigenvectors.
+pub fn gauss_rule(family: GaussFamily, n: usize) -> Result<GaussRule, GaussError> {
+    if n == 0 {
+        return Err(GaussError::ZeroOrderError);
+    }
+
+    // Diagonal, squared off-diagonal of the Jacobi matrix and integral of the weight function
+    let (diagonal, off_diagonal_sq, mu0): (Vec<f64>, Vec<f64>, f64) = match family {
+        GaussFamily::Legendre => (
+            vec![0.0; n],
+            (1..n)
+                .map(|k| {
+                    let k = k as f64;
+                    k * k / (4.0 * k * k - 1.0)
+                })
+                .collect(),
+            2.0,
+        ),
+        GaussFamily::Laguerre(alpha) => {
+            if alpha <= -1.0 {
+                return Err(GaussError::InvalidParameterError);
+            }
+            (
+                (0..n).map(|k| 2.0 * k as f64 + alpha + 1.0).collect(),
+                (1..n).map(|k| k as f64 * (k as f64 + alpha)).collect(),
+                gamma(alpha + 1.0),
+            )
+        }
+        GaussFamily::Hermite => (
+            vec![0.0; n],
+            (1..n).map(|k| 0.5 * k as f64).collect(),
+            PI.sqrt(),
+        ),
+        GaussFamily::ChebyshevFirstKind => {
+            let nodes = (1..=n)
+                .rev()
+                .map(|k| ((2 * k - 1) as f64 * PI / (2 * n) as f64).cos())
+                .collect();
+            return Ok(GaussRule {
+                nodes,
+                weights: vec![PI / n as f64; n],
+            });
+        }
+        GaussFamily::ChebyshevSecondKind => {
+            let theta = |k: usize| k as f64 * PI / (n + 1) as f64;
+            return Ok(GaussRule {
+                nodes: (1..=n).rev().map(|k| theta(k).cos()).collect(),
+                weights: (1..=n)
+                    .rev()
+                    .map(|k| PI / (n + 1) as f64 * theta(k).sin().powi(2))
+                    .collect(),
+            });
+        }
+        GaussFamily::Jacobi(alpha, beta) => {
+            if alpha <= -1.0 || beta <= -1.0 {
+                return Err(GaussError::InvalidParameterError);
+            }
+            jacobi_recurrence(alpha, beta, n)
+        }
+    };
+
+    golub_welsch(diagonal, off_diagonal_sq, mu0)
+}
+
+// Recurrence coefficients of the monic Jacobi polynomials, with the first terms written out
+// where the general formulas become 0 / 0
+fn jacobi_recurrence(alpha: f64, beta: f64, n: usize) -> (Vec<f64>, Vec<f64>, f64) {
+    let ab = alpha + beta;
+    let diagonal = (0..n)
+        .map(|k| {
+            if k == 0 {
+                (beta - alpha) / (ab + 2.0)
+            } else {
+                let s = 2.0 * k as f64 + ab;
+                (beta * beta - alpha * alpha) / (s * (s + 2.0))
+            }
+        })
+        .collect();
+    let off_diagonal_sq = (1..n)
+        .map(|k| {
+            let k_f = k as f64;
+            let s = 2.0 * k_f + ab;
+            if k == 1 {
+                4.0 * (1.0 + alpha) * (1.0 + beta) / ((2.0 + ab).powi(2) * (3.0 + ab))
+            } else {
+                4.0 * k_f * (k_f + alpha) * (k_f + beta) * (k_f + ab)
+                    / (s * s * (s + 1.0) * (s - 1.0))
+            }
+        })
+        .collect();
+    let mu0 = 2f64.powf(ab + 1.0) * gamma(alpha + 1.0) * gamma(beta + 1.0) / gamma(ab + 2.0);
+    (diagonal, off_diagonal_sq, mu0)
+}
+
+// Implicit QL iterations on the symmetric tridiagonal matrix, which only keep track of the
+// first components of the eigenvectors since these give the weights
+fn golub_welsch(
+    diagonal: Vec<f64>,
+    off_diagonal_sq: Vec<f64>,
+    mu0: f64,
+) -> Result<GaussRule, GaussError> {
+    let n = diagonal.len();
+    let mut d = diagonal;
+    // e[i] couples rows i and i + 1
+    let mut e: Vec<f64> = off_diagonal_sq.iter().map(|b| b.sqrt()).collect();
+    e.push(0.0);
+    let mut z = vec![0.0; n];
+    z[0] = 1.0;
+
+    for l in 0..n {
+        let mut iter = 0;
+        loop {
+            let mut m = l;
+            while m < n - 1 {
+                let dd = d[m].abs() + d[m + 1].abs();
+                if e[m].abs() <= f64::EPSILON * dd {
+                    break;
+                }
+                m += 1;
+            }
+            if m == l {
+                break;
+            }
+            iter += 1;
+            if iter > QL_MAX_ITER {
+                return Err(GaussError::NonConvergenceError);
+            }
+
+            let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+            let mut r = g.hypot(1.0);
+            g = d[m] - d[l] + e[l] / (g + r.copysign(g));
+            let (mut s, mut c, mut p) = (1.0, 1.0, 0.0);
+            let mut underflow = false;
+
+            for i in (l..m).rev() {
+                let f = s * e[i];
+                let b = c * e[i];
+                r = f.hypot(g);
+                e[i + 1] = r;
+                if r == 0.0 {
+                    d[i + 1] -= p;
+                    e[m] = 0.0;
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = d[i + 1] - p;
+                r = (d[i] - g) * s + 2.0 * c * b;
+                p = s * r;
+                d[i + 1] = g + p;
+                g = c * r - b;
+
+                let z_next = z[i + 1];
+                z[i + 1] = s * z[i] + c * z_next;
+                z[i] = c * z[i] - s * z_next;
+            }
+            if underflow {
+                continue;
+            }
+            d[l] -= p;
+            e[l] = g;
+            e[m] = 0.0;
+        }
+    }
+
+    let mut pairs: Vec<(f64, f64)> = d
+        .into_iter()
+        .zip(z)
+        .map(|(x, z_0)| (x, mu0 * z_0 * z_0))
+        .collect();
+    pairs.sort_by(|p, q| p.0.total_cmp(&q.0));
+    Ok(GaussRule {
+        nodes: pairs.iter().map(|p| p.0).collect(),
+        weights: pairs.iter().map(|p| p.1).collect(),
+    })
+}
+
+fn gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        // Reflection formula
+        PI / ((PI * x).sin() * gamma(1.0 - x))
+    } else {
+        let x = x - 1.0;
+        let mut a = LANCZOS[0];
+        for (i, coefficient) in LANCZOS.iter().enumerate().skip(1) {
+            a += coefficient / (x + i as f64);
+        }
+        let t = x + LANCZOS_G + 0.5;
+        (2.0 * PI).sqrt() * t.powf(x + 0.5) * (-t).exp() * a
+    }
+}
+
+/// # Gaussian Quadrature
+/// Fixed order Gaussian rule, either Gauss-Legendre on an interval [a, b] or a weighted rule
+/// on the canonical interval of its family, where f is the integrand without the weight function.
+/// The error estimate of the `Integrator` implementation is the difference from the rule of
+/// order n - 1, which costs n - 1 more evaluations and usually overestimates the error.
+/// The nodes and weights are computed in f64 and rounded to the floating-point type of the integral.
+///
+/// ## Methods
+/// - Initialize the struct, on [a, b] or for a weight function
+/// - Change the Order
+/// - Run and Compute the integrals
+pub struct Gauss<F, T = f64> {
+    f: F,
+    family: GaussFamily,
+    a: T,
+    b: T,
+    order: usize,
+}
+
+impl<F> Gauss<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    ///Gauss-Legendre integration of f on [a, b]
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self::new(f, a, b)
+    }
+
+    ///Integration of w(x) f(x) on the canonical interval of the family, see `GaussFamily`
+    pub fn weighted(f: F, family: GaussFamily) -> Self {
+        Self::new_weighted(f, family)
+    }
+}
+
+impl<F, T> Gauss<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    ///Same as `initialize` for any floating-point type, which is inferred from a and b
+    pub fn new(f: F, a: T, b: T) -> Self {
+        Self {
+            f,
+            family: GaussFamily::Legendre,
+            a,
+            b,
+            order: DEFAULT_ORDER,
+        }
+    }
+
+    ///Same as `weighted` for any floating-point type, which is inferred from f
+    pub fn new_weighted(f: F, family: GaussFamily) -> Self {
+        Self {
+            f,
+            family,
+            a: -T::one(),
+            b: T::one(),
+            order: DEFAULT_ORDER,
+        }
+    }
+
+    ///A method that changes the number of nodes of the rule
+    pub fn order(mut self, order: usize) -> Self {
+        self.order = order;
+        self
+    }
+
+    pub fn run(mut self) -> Result<IntegralChar<T>, GaussError> {
+        let integral = self.compute(self.order)?;
+        Ok(IntegralChar {
+            msg: "Integration Completed".to_string(),
+            nodes: self.order,
+            integral,
+        })
+    }
+
+    fn compute(&mut self, order: usize) -> Result<T, GaussError> {
+        if !(self.a.is_finite() && self.b.is_finite()) {
+            return Err(GaussError::IntervalError);
+        }
+        let rule = gauss_rule(self.family, order)?;
+
+        // Affine map from [-1, 1] to [a, b], the identity for weighted rules
+        let half_length = T::constant(0.5) * (self.b - self.a);
+        let center = T::constant(0.5) * (self.a + self.b);
+        let sum = rule
+            .nodes
+            .iter()
+            .zip(rule.weights.iter())
+            .fold(T::zero(), |sum, (x, w)| {
+                sum + T::constant(*w) * (self.f)(center + half_length * T::constant(*x))
+            });
+        Ok(half_length * sum)
+    }
+}
+
+impl<F, T> Integrator<T> for Gauss<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn integrate(&mut self) -> Result<IntegrationMetrics<T>, IntegrationError<T>> {
+        let integral = self.compute(self.order)?;
+        let (error_estimate, func_evals) = if self.order > 1 {
+            let lower = self.compute(self.order - 1)?;
+            ((integral - lower).abs(), 2 * self.order - 1)
+        } else {
+            (T::nan(), 1)
+        };
+
+        Ok(IntegrationMetrics {
+            msg: "Integration Completed".to_string(),
+            integral,
+            error_estimate,
+            func_evals,
+        })
+    }
+}
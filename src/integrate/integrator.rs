@@ -1,224 +1,456 @@
 use core::fmt;
 use std::collections::VecDeque;
+use std::error::Error;
 
-static DEFAULT_NODES:usize=10000;
-static DEFAULT_TOL:f64=1e-11;
+use super::double_exponential::DoubleExponential;
+use super::gauss::GaussError;
+use super::quad::{Quad, QuadError};
+use crate::common::extrapolation::richardson_extend;
+use crate::common::float::Real;
+
+static DEFAULT_NODES: usize = 10000;
+static DEFAULT_TOL: f64 = 1e-11;
+static DEFAULT_ROMBERG_SIZE: u32 = 12;
 
 ///Output characteristics for evaluating an one dimensional integral .
-///Consists of an output message , the number of nodes evaluated and the result in f64
+///Consists of an output message , the number of nodes evaluated and the result in T
 /// ## Attributes
 /// - msg:String
 /// - nodes:usize
-/// - integral:f64
-pub struct IntegralChar{
-    pub msg:String,
-    pub nodes:usize,
-    pub integral:f64
-
+/// - integral:T
+#[derive(Debug)]
+pub struct IntegralChar<T = f64> {
+    pub msg: String,
+    pub nodes: usize,
+    pub integral: T,
 }
-impl fmt::Display for IntegralChar {
+impl<T> fmt::Display for IntegralChar<T>
+where
+    T: Real,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-
-        writeln!(f,"{}\n Nodes={} Result={}\n",
-            self.msg, self.nodes,self.integral
+        writeln!(
+            f,
+            "{}\n Nodes={} Result={}\n",
+            self.msg, self.nodes, self.integral
         )
     }
 }
 
 /// Possible errors that will occured in the integration process.
-pub enum IntegralError{
-    None,
+#[derive(Debug)]
+pub enum IntegralError<T = f64> {
     IntervalError,
-    UnacceptableTolearanceError(IntegralChar),
-    IterationLimitExceededError(IntegralChar),
+    UnacceptableTolearanceError(IntegralChar<T>),
+    IterationLimitExceededError(IntegralChar<T>),
 }
 
-impl fmt::Display for IntegralError {
+impl<T> fmt::Display for IntegralError<T>
+where
+    T: Real,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            IntegralError::None=>{
-                write!(f, "The parameters are valid\n")}
             IntegralError::IntervalError => {
-                write!(f, "The interval is infinite\n")
+                write!(
+                    f,
+                    "The interval is infinite or too small for the given number of nodes."
+                )
             }
             IntegralError::UnacceptableTolearanceError(integral_char) => {
-                
-                write!(f,"Derivative became zero during computation.\n{}",
+                write!(
+                    f,
+                    "The requested tolerance could not be achieved.\n{}",
                     integral_char
                 )
             }
             IntegralError::IterationLimitExceededError(integral_char) => {
-                write!(f, "Initial guesses x0 and x1 cannot be identical.\n{}",integral_char)
+                write!(
+                    f,
+                    "Maximum number of iterations reached.\n{}",
+                    integral_char
+                )
             }
         }
     }
 }
 
+impl<T> Error for IntegralError<T> where T: Real {}
+
+///Output characteristics shared by every method that implements the `Integrator` trait.
+/// ## Attributes
+/// - msg:String
+/// - integral:T
+/// - error_estimate:T : estimate of the absolute error of the result, NaN when the method has
+///   no estimate, e.g. the trapezoid rule on a single interval
+/// - func_evals:usize : number of evaluations of the integrand
+#[derive(Debug)]
+pub struct IntegrationMetrics<T = f64> {
+    pub msg: String,
+    pub integral: T,
+    pub error_estimate: T,
+    pub func_evals: usize,
+}
+impl<T> fmt::Display for IntegrationMetrics<T>
+where
+    T: Real + fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}\nfunc_evals={}, integral={}, error_estimate={:.5e}",
+            self.msg, self.func_evals, self.integral, self.error_estimate
+        )
+    }
+}
+
+///Errors returned through the `Integrator` trait, wrapping the error of the underlying method
+///which is available through `source`.
+#[derive(Debug)]
+pub enum IntegrationError<T = f64> {
+    Integral(IntegralError<T>),
+    Quad(QuadError<T>),
+    Gauss(GaussError),
+}
+
+impl<T> fmt::Display for IntegrationError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrationError::Integral(_) => write!(f, "The fixed node integration failed."),
+            IntegrationError::Quad(_) => write!(f, "The adaptive quadrature integration failed."),
+            IntegrationError::Gauss(_) => write!(f, "The Gaussian quadrature integration failed."),
+        }
+    }
+}
+
+impl<T> Error for IntegrationError<T>
+where
+    T: Real + fmt::LowerExp + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IntegrationError::Integral(error) => Some(error),
+            IntegrationError::Quad(error) => Some(error),
+            IntegrationError::Gauss(error) => Some(error),
+        }
+    }
+}
+
+impl<T> From<IntegralError<T>> for IntegrationError<T> {
+    fn from(error: IntegralError<T>) -> Self {
+        IntegrationError::Integral(error)
+    }
+}
+
+impl<T> From<QuadError<T>> for IntegrationError<T> {
+    fn from(error: QuadError<T>) -> Self {
+        IntegrationError::Quad(error)
+    }
+}
+
+impl<T> From<GaussError> for IntegrationError<T> {
+    fn from(error: GaussError) -> Self {
+        IntegrationError::Gauss(error)
+    }
+}
+
+/// # Integrator
+/// Common interface of the one dimensional integration methods,
+/// so that the method can be switched without changing the surrounding code.
+pub trait Integrator<T = f64> {
+    ///Computes the integral and reports it together with an error estimate
+    fn integrate(&mut self) -> Result<IntegrationMetrics<T>, IntegrationError<T>>;
+}
+
+///The integration methods that can be selected in the `integrate` function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrationMethod {
+    Trapezoid,
+    Simpson,
+    Romberg,
+    Quad,
+    DoubleExponential,
+}
+
+/// A function that integrates f on [a, b] with the selected method and its default parameters.
+/// ## Parameters
+/// - f: FnMut(f64)->f64
+/// - a: f64, b: f64 : interval endpoints
+/// - method: IntegrationMethod
+///
+/// ## Returns
+/// - Result<IntegrationMetrics, IntegrationError>
+pub fn integrate<F>(
+    f: F,
+    a: f64,
+    b: f64,
+    method: IntegrationMethod,
+) -> Result<IntegrationMetrics, IntegrationError>
+where
+    F: FnMut(f64) -> f64,
+{
+    match method {
+        IntegrationMethod::Trapezoid => CompositeTrapezoid::initialize(f, a, b).integrate(),
+        IntegrationMethod::Simpson => Simpson::initialize(f, a, b).integrate(),
+        IntegrationMethod::Romberg => Romberg::initialize(f, a, b)
+            .extend(DEFAULT_ROMBERG_SIZE)
+            .integrate(),
+        IntegrationMethod::Quad => Quad::initialize(f, a, b).integrate(),
+        IntegrationMethod::DoubleExponential => DoubleExponential::initialize(f, a, b).integrate(),
+    }
+}
+
 /// # Composite Trapezoid Integration
 /// Structure that handles input parameters and runs the composite trapezoid method
 /// for a number of nodes.
-
+/// The error estimate of the `Integrator` implementation compares the result with the rule on
+/// every other node, with the last interval kept whole for an odd number of nodes. A single
+/// interval gives no estimate, i.e. NaN.
+///
 /// ## Methods
 /// - Initialize the struct
-/// - Change the Nodes 
+/// - Change the Nodes
 /// - Run and Compute the integrals
-/// 
-pub struct CompositeTrapezoid{
-    f:fn(f64)->f64,
-    a: f64,
-    b: f64,
-    nodes: usize
-
+///
+pub struct CompositeTrapezoid<F, T = f64> {
+    f: F,
+    a: T,
+    b: T,
+    nodes: usize,
 }
-impl CompositeTrapezoid{
-
+impl<F> CompositeTrapezoid<F>
+where
+    F: FnMut(f64) -> f64,
+{
     ///A method that will initialize the integration struct
-    pub fn initialize(f:fn(f64)->f64,a:f64,b:f64)-> Self {
-        
-        Self { 
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self::new(f, a, b)
+    }
+}
 
+impl<F, T> CompositeTrapezoid<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    ///Same as `initialize` for any floating-point type, which is inferred from a and b
+    pub fn new(f: F, a: T, b: T) -> Self {
+        Self {
             f,
             a,
             b,
-            nodes:DEFAULT_NODES  
-
+            nodes: DEFAULT_NODES,
         }
     }
 
     ///A method that changes the number of nodes that will be evaluated in the interval
-    pub fn nodes (mut self, nodes:usize) -> Self {
-
-        self.nodes=nodes;
-        self 
-
+    pub fn nodes(mut self, nodes: usize) -> Self {
+        self.nodes = nodes;
+        self
     }
 
-    pub fn run (self)->Result<IntegralChar,IntegralError> {
-        
-
-        let mut integral_char=IntegralChar{
-            
+    pub fn run(mut self) -> Result<IntegralChar<T>, IntegralError<T>> {
+        let mut integral_char = IntegralChar {
             msg: String::from(""),
             nodes: self.nodes,
-            integral:f64::NAN,
+            integral: T::nan(),
         };
 
+        let (result, _) = self.compute()?;
 
-        if !(self.a.is_finite() || self.b.is_finite()) {
+        integral_char.msg = "Integration Completed".to_string();
+        integral_char.integral = result;
 
-            return Err(IntegralError::IntervalError)
-        }
+        Ok(integral_char)
+    }
 
-        let h = (self.b - self.a) / self.nodes as f64;
+    ///Computes the composite rule and an error estimate obtained by comparing it
+    ///with the rule on every other node, which reuses the same evaluations.
+    fn compute(&mut self) -> Result<(T, T), IntegralError<T>> {
+        if !(self.a.is_finite() || self.b.is_finite()) {
+            return Err(IntegralError::IntervalError);
+        }
 
-        if h <=DEFAULT_TOL{
+        let h = (self.b - self.a) / T::constant(self.nodes as f64);
 
+        if h <= T::constant(DEFAULT_TOL) {
             return Err(IntegralError::IntervalError);
         }
 
-        let mut result = 0.5 * (self.f)(self.a) + (self.f)(self.b);
+        let two = T::constant(2.0);
+        let half = T::constant(0.5);
+        let (f_a, f_b) = ((self.f)(self.a), (self.f)(self.b));
+        let endpoints = half * (f_a + f_b);
+        let mut even_sum = T::zero();
+        let mut odd_sum = T::zero();
+        let mut f_last = f_a;
 
         for i in 1..self.nodes {
-            let x = self.a + i as f64 * h;
-            result += (self.f)(x);
+            let x = self.a + T::constant(i as f64) * h;
+            f_last = (self.f)(x);
+            if i % 2 == 0 {
+                even_sum = even_sum + f_last;
+            } else {
+                odd_sum = odd_sum + f_last;
+            }
         }
 
-        result *= h;
-
-        integral_char.msg="Integration Completed".to_string();
-        integral_char.integral=result;
+        let result = h * (endpoints + even_sum + odd_sum);
+        let error_estimate = if self.nodes.is_multiple_of(2) {
+            (result - two * h * (endpoints + even_sum)).abs() / T::constant(3.0)
+        } else if self.nodes > 1 {
+            // Both rules share the last interval, so their difference measures the error
+            // of the others, which is scaled up to all the intervals
+            let fine = result - h * half * (f_last + f_b);
+            let coarse = two * h * (half * f_a + even_sum - half * f_last);
+            let nodes = T::constant(self.nodes as f64);
+            (fine - coarse).abs() / T::constant(3.0) * nodes / (nodes - T::one())
+        } else {
+            T::nan()
+        };
 
-        return  Ok(integral_char)
+        Ok((result, error_estimate))
     }
-   
+}
 
+impl<F, T> Integrator<T> for CompositeTrapezoid<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn integrate(&mut self) -> Result<IntegrationMetrics<T>, IntegrationError<T>> {
+        let (integral, error_estimate) = self.compute()?;
+        Ok(IntegrationMetrics {
+            msg: "Integration Completed".to_string(),
+            integral,
+            error_estimate,
+            func_evals: self.nodes + 1,
+        })
+    }
 }
 
 /// # Simpson Rule Integration
-/// Structure that handles input parameters and runs the simpson 1/3 rule 
+/// Structure that handles input parameters and runs the simpson 1/3 rule
 /// for a number of nodes.
-
+///
 /// ## Methods
 /// - Initialize the struct
-/// - Change the Nodes 
+/// - Change the Nodes
 /// - Run and Compute the integrals
 ///
-pub struct Simpson{
-
-    f:fn(f64)->f64,
-    a: f64,
-    b: f64,
-    nodes: usize
+pub struct Simpson<F, T = f64> {
+    f: F,
+    a: T,
+    b: T,
+    nodes: usize,
 }
 
-impl Simpson{
-
+impl<F> Simpson<F>
+where
+    F: FnMut(f64) -> f64,
+{
     ///A method that will initialize the integration struct
-    pub fn initialize(f:fn(f64)->f64,a:f64,b:f64)-> Self {
-        
-        Self { 
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self::new(f, a, b)
+    }
+}
 
+impl<F, T> Simpson<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    ///Same as `initialize` for any floating-point type, which is inferred from a and b
+    pub fn new(f: F, a: T, b: T) -> Self {
+        Self {
             f,
             a,
             b,
-            nodes:DEFAULT_NODES  
-
+            nodes: DEFAULT_NODES,
         }
     }
 
     ///A method that changes the number of nodes that will be evaluated in the interval
-    pub fn nodes (mut self, nodes:usize) -> Self {
-
-        self.nodes=nodes;
-        self 
-
+    pub fn nodes(mut self, nodes: usize) -> Self {
+        self.nodes = nodes;
+        self
     }
 
     /// A method that runs the numerical integration and returns the result.
-    pub fn run (self)->Result<IntegralChar,IntegralError> {
-
-        let mut integral_char=IntegralChar{
-            
+    pub fn run(mut self) -> Result<IntegralChar<T>, IntegralError<T>> {
+        let mut integral_char = IntegralChar {
             msg: String::from(""),
             nodes: self.nodes,
-            integral:f64::NAN,
+            integral: T::nan(),
         };
 
+        let (result, _) = self.compute()?;
 
-        if !(self.a.is_finite() || self.b.is_finite()) {
+        integral_char.integral = result;
 
-            return Err(IntegralError::IntervalError)
-        }
+        Ok(integral_char)
+    }
 
-        let h = (self.b - self.a) / self.nodes as f64;
+    ///Computes the simpson rule and an error estimate from the evaluations already made.
+    ///When the number of nodes is divisible by four the result is compared with the simpson rule
+    ///on every other node, otherwise with the trapezoid rule on the same nodes.
+    fn compute(&mut self) -> Result<(T, T), IntegralError<T>> {
+        if !(self.a.is_finite() || self.b.is_finite()) {
+            return Err(IntegralError::IntervalError);
+        }
 
-        if h <=DEFAULT_TOL{
+        let h = (self.b - self.a) / T::constant(self.nodes as f64);
 
+        if h <= T::constant(DEFAULT_TOL) {
             return Err(IntegralError::IntervalError);
         }
 
-        let mut result = (self.f)(self.a) + (self.f)(self.b);
+        let two = T::constant(2.0);
+        let three = T::constant(3.0);
+        let four = T::constant(4.0);
+        let endpoints = (self.f)(self.a) + (self.f)(self.b);
+        // Sums of the interior values, split by index modulo four
+        let mut sums = [T::zero(); 4];
 
         for i in 1..self.nodes {
-            let x = self.a + i as f64 * h;
-            result += if i % 2 == 0 { 2.0 * (self.f)(x) } else { 4.0 * (self.f)(x) };
+            let x = self.a + T::constant(i as f64) * h;
+            sums[i % 4] = sums[i % 4] + (self.f)(x);
         }
 
-        result *= h/ 3.0;
+        let even_sum = sums[0] + sums[2];
+        let odd_sum = sums[1] + sums[3];
+        let result = h / three * (endpoints + two * even_sum + four * odd_sum);
 
-        integral_char.integral=result;
+        let error_estimate = if self.nodes.is_multiple_of(4) {
+            let coarse = two * h / three * (endpoints + two * sums[0] + four * sums[2]);
+            (result - coarse).abs() / T::constant(15.0)
+        } else {
+            let trapezoid = h * (T::constant(0.5) * endpoints + even_sum + odd_sum);
+            (result - trapezoid).abs()
+        };
 
-        return  Ok(integral_char)
+        Ok((result, error_estimate))
     }
-
-
 }
 
-
-
+impl<F, T> Integrator<T> for Simpson<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn integrate(&mut self) -> Result<IntegrationMetrics<T>, IntegrationError<T>> {
+        let (integral, error_estimate) = self.compute()?;
+        Ok(IntegrationMetrics {
+            msg: "Integration Completed".to_string(),
+            integral,
+            error_estimate,
+            func_evals: self.nodes + 1,
+        })
+    }
+}
 
 /// # Romberg Integration
-/// Structure that handles input parameters and runs the romberg integration 
+/// Structure that handles input parameters and runs the romberg integration
 /// for a number of nodes what is a power of two.
 /// The estimated values of the integral are stored in the struct and can be extended
 /// for more precision.
@@ -227,107 +459,121 @@ impl Simpson{
 /// - Extend function that computes the integral
 /// - Run function that returns the integral
 ///
-pub struct Romberg<F>{
-
-    function:F,
-    a:f64,
-    h:f64,
-    r:VecDeque<f64>,
-    size:u32,
-    error_type:IntegralError
-
+pub struct Romberg<F, T = f64> {
+    function: F,
+    a: T,
+    h: T,
+    r: VecDeque<T>,
+    size: u32,
+    func_evals: usize,
+    error_estimate: T,
+    infinite_interval: bool,
 }
 impl<F> Romberg<F>
-
 where
-    F:Fn(f64)->f64,
+    F: FnMut(f64) -> f64,
 {
     ///A method that will initialize the integration struct
-    pub fn initialize(function:F,a:f64,b:f64)-> Self {
+    pub fn initialize(function: F, a: f64, b: f64) -> Self {
+        Self::new(function, a, b)
+    }
+}
 
-        let mut rcopy:VecDeque<f64>=VecDeque::new();
+impl<F, T> Romberg<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    ///Same as `initialize` for any floating-point type, which is inferred from a and b
+    pub fn new(mut function: F, a: T, b: T) -> Self {
+        let mut rcopy: VecDeque<T> = VecDeque::new();
 
         if !(a.is_finite() || b.is_finite()) {
-            rcopy.push_front(0.0);
-            Self{
-
-                function:function,
-                a:a,
-                h:b-a,
-                r:rcopy,
-                size:1,
-                error_type:IntegralError::IntervalError
-            } 
-        }
-        else{
-
+            rcopy.push_front(T::zero());
+            Self {
+                function,
+                a,
+                h: b - a,
+                r: rcopy,
+                size: 1,
+                func_evals: 0,
+                error_estimate: T::nan(),
+                infinite_interval: true,
+            }
+        } else {
             //first evalutation
-            rcopy.push_front( 0.5 * (b-a) *  ((function)(a) + (function)(b)));
-
-            Self{
-
-                function:function,
-                a:a,
-                h:b-a,
-                r:rcopy,
-                size:1,
-                error_type:IntegralError::None
-            } 
+            rcopy.push_front(T::constant(0.5) * (b - a) * ((function)(a) + (function)(b)));
+
+            Self {
+                function,
+                a,
+                h: b - a,
+                r: rcopy,
+                size: 1,
+                func_evals: 2,
+                error_estimate: T::nan(),
+                infinite_interval: false,
             }
-    }
-
-    /// A method that takes as input the struct parameters and a degree that determines how many times the interval points are sub divided. 
-    pub fn extend(mut self,size:u32)->Self{
-
-
-    let extention_size=size+1;
-    
-
-
-    for n in self.size..self.size+extention_size{
-
-        let hn: f64 =self.h/(2_i32.pow(n)  as f64);
-        let mut sum =0.0;
-        for k in 1..(2_i32.pow(n-1)+1){
-            sum += (self.function)(self.a + (2.0 * k as f64 - 1.0) * hn);
-            
         }
+    }
 
-        
-        self.r.push_front( 0.5 * self.r[0] + hn * sum);
+    /// A method that takes as input the struct parameters and a degree that determines how many times the interval points are sub divided.
+    pub fn extend(mut self, size: u32) -> Self {
+        let extention_size = size + 1;
+        let one = T::one();
+        let two = T::constant(2.0);
+        let four = T::constant(4.0);
+
+        for n in self.size..self.size + extention_size {
+            let hn: T = self.h / T::constant(2_i32.pow(n) as f64);
+            let mut sum = T::zero();
+            for k in 1..(2_i32.pow(n - 1) + 1) {
+                sum = sum + (self.function)(self.a + (two * T::constant(k as f64) - one) * hn);
+            }
+            self.func_evals += 2_usize.pow(n - 1);
 
-        for m in 1..=n as usize {
-            self.r[m]= self.r[m-1]+ (self.r[m-1]-self.r[m])/(4.0_f64.powi(m as i32)-1.0);
-            
+            let previous_estimate = *self.r.back().unwrap();
+            let estimate = T::constant(0.5) * self.r[0] + hn * sum;
+            richardson_extend(&mut self.r, estimate, four);
+            self.error_estimate = (*self.r.back().unwrap() - previous_estimate).abs();
         }
-    }
-    self.size+=extention_size;
-    self
-    
+        self.size += extention_size;
+        self
     }
 
     /// A method that returns the result.
-    pub fn run(self)->Result<IntegralChar,IntegralError>{
-        let mut int_char=IntegralChar{
-            msg:"".to_string(),
-            nodes:self.size.pow(2) as usize,
-            integral:f64::NAN
-
+    pub fn run(self) -> Result<IntegralChar<T>, IntegralError<T>> {
+        let mut int_char = IntegralChar {
+            msg: "".to_string(),
+            nodes: self.size.pow(2) as usize,
+            integral: T::nan(),
         };
 
-        match self.error_type{
-            IntegralError::IntervalError=>{
-                return Err(IntegralError::IntervalError)
-            }
-            _=>{
-                int_char.msg="Completed Integration".to_string();
-                int_char.integral=*self.r.back().unwrap();
-                return Ok(int_char)
-            }
-
+        if self.infinite_interval {
+            return Err(IntegralError::IntervalError);
         }
-   
+
+        int_char.msg = "Completed Integration".to_string();
+        int_char.integral = *self.r.back().unwrap();
+        Ok(int_char)
     }
 }
 
+impl<F, T> Integrator<T> for Romberg<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn integrate(&mut self) -> Result<IntegrationMetrics<T>, IntegrationError<T>> {
+        if self.infinite_interval {
+            return Err(IntegralError::IntervalError.into());
+        }
 
+        Ok(IntegrationMetrics {
+            msg: "Completed Integration".to_string(),
+            integral: *self.r.back().unwrap(),
+            error_estimate: self.error_estimate,
+            func_evals: self.func_evals,
+        })
+    }
+}
@@ -0,0 +1,521 @@
+use core::fmt;
+
+use super::cubature::{ordered_box, CubatureError};
+use crate::common::random::SplitMix64;
+
+static DEFAULT_SAMPLES: usize = 100_000;
+static DEFAULT_VEGAS_SAMPLES: usize = 10_000;
+static DEFAULT_ITERATIONS: usize = 10;
+static DEFAULT_BINS: usize = 50;
+static DEFAULT_ALPHA: f64 = 1.5;
+
+///Output characteristics of the Monte Carlo integrators.
+/// ## Attributes
+/// - msg:String
+/// - mean:f64, the estimate of the integral, i.e. the mean of the weighted samples
+/// - standard_error:f64, the estimated standard deviation of the mean
+/// - samples:usize, the number of function evaluations
+#[derive(Debug)]
+pub struct MonteCarloChar {
+    pub msg: String,
+    pub mean: f64,
+    pub standard_error: f64,
+    pub samples: usize,
+}
+
+impl fmt::Display for MonteCarloChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}\nWith {} samples, the result is {} with standard error {:.5e}",
+            self.msg, self.samples, self.mean, self.standard_error
+        )
+    }
+}
+
+// Running mean and variance of the samples with Welford's algorithm
+#[derive(Default)]
+struct Moments {
+    count: usize,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    // Variance of the mean
+    fn variance_of_mean(&self) -> f64 {
+        self.m2 / ((self.count - 1) * self.count) as f64
+    }
+}
+
+fn too_few_samples() -> CubatureError {
+    CubatureError::InvalidInput("too few samples, at least 2 are needed.".to_string())
+}
+
+fn check_finite(value: f64) -> Result<f64, CubatureError> {
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err(CubatureError::Divergence)
+    }
+}
+
+/// # Monte Carlo Integration
+/// Plain Monte Carlo over a box, with uniformly distributed samples from a generator seeded by
+/// `seed`, so that results are reproducible. The standard error decreases as 1/sqrt(samples)
+/// in any dimension.
+///
+/// ## Methods
+/// - Initialize the struct
+/// - Change the number of samples and the seed
+/// - Run and Compute the integrals
+pub struct MonteCarlo<F> {
+    f: F,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    samples: usize,
+    seed: u64,
+}
+
+impl<F> MonteCarlo<F>
+where
+    F: FnMut(&[f64]) -> f64,
+{
+    pub fn initialize(function: F, lower: &[f64], upper: &[f64]) -> Self {
+        Self {
+            f: function,
+            lower: lower.to_vec(),
+            upper: upper.to_vec(),
+            samples: DEFAULT_SAMPLES,
+            seed: 0,
+        }
+    }
+
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn run(mut self) -> Result<MonteCarloChar, CubatureError> {
+        if self.samples < 2 {
+            return Err(too_few_samples());
+        }
+        let (a, b, sign) = ordered_box(&self.lower, &self.upper)?;
+        let volume: f64 = a.iter().zip(&b).map(|(a_i, b_i)| b_i - a_i).product();
+
+        let mut rng = SplitMix64::new(self.seed);
+        let mut x = vec![0.0; a.len()];
+        let mut moments = Moments::default();
+        for _ in 0..self.samples {
+            for i in 0..x.len() {
+                x[i] = a[i] + rng.next_f64() * (b[i] - a[i]);
+            }
+            moments.push(check_finite(volume * (self.f)(&x))?);
+        }
+
+        Ok(MonteCarloChar {
+            msg: "Completed Monte Carlo integration".to_string(),
+            mean: sign * moments.mean,
+            standard_error: moments.variance_of_mean().sqrt(),
+            samples: self.samples,
+        })
+    }
+}
+
+/// # Stratified Monte Carlo Integration
+/// The box is divided into `strata` equal parts along every axis and each of the strata^n cells
+/// receives the same share of the samples, which removes the variance due to the variation of
+/// f between cells. The number of cells grows exponentially with the dimension, and every cell
+/// needs at least 2 samples for the error estimate.
+///
+/// ## Methods
+/// - Initialize the struct
+/// - Change the number of samples, strata and the seed
+/// - Run and Compute the integrals
+pub struct StratifiedMonteCarlo<F> {
+    f: F,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    samples: usize,
+    strata: usize,
+    seed: u64,
+}
+
+impl<F> StratifiedMonteCarlo<F>
+where
+    F: FnMut(&[f64]) -> f64,
+{
+    pub fn initialize(function: F, lower: &[f64], upper: &[f64]) -> Self {
+        Self {
+            f: function,
+            lower: lower.to_vec(),
+            upper: upper.to_vec(),
+            samples: DEFAULT_SAMPLES,
+            strata: 4,
+            seed: 0,
+        }
+    }
+
+    ///Total number of samples, split evenly between the cells
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    ///Number of strata along every axis
+    pub fn strata(mut self, strata: usize) -> Self {
+        self.strata = strata;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn run(mut self) -> Result<MonteCarloChar, CubatureError> {
+        let (a, b, sign) = ordered_box(&self.lower, &self.upper)?;
+        let n = a.len();
+        let cells = u32::try_from(n)
+            .ok()
+            .and_then(|n| self.strata.checked_pow(n))
+            .filter(|cells| *cells > 0)
+            .ok_or_else(|| CubatureError::InvalidInput("invalid number of strata.".to_string()))?;
+        let per_cell = self.samples / cells;
+        if per_cell < 2 {
+            return Err(too_few_samples());
+        }
+
+        let strata = self.strata as f64;
+        let cell_width: Vec<f64> = a
+            .iter()
+            .zip(&b)
+            .map(|(a_i, b_i)| (b_i - a_i) / strata)
+            .collect();
+        let cell_volume: f64 = cell_width.iter().product();
+
+        let mut rng = SplitMix64::new(self.seed);
+        let mut x = vec![0.0; n];
+        let mut corner = vec![0.0; n];
+        let (mut mean, mut variance) = (0.0, 0.0);
+        for cell in 0..cells {
+            // Digits of the cell index in base strata give its position along every axis
+            let mut index = cell;
+            for i in 0..n {
+                corner[i] = a[i] + (index % self.strata) as f64 * cell_width[i];
+                index /= self.strata;
+            }
+
+            let mut moments = Moments::default();
+            for _ in 0..per_cell {
+                for i in 0..n {
+                    x[i] = corner[i] + rng.next_f64() * cell_width[i];
+                }
+                moments.push(check_finite(cell_volume * (self.f)(&x))?);
+            }
+            mean += moments.mean;
+            variance += moments.variance_of_mean();
+        }
+
+        Ok(MonteCarloChar {
+            msg: "Completed stratified Monte Carlo integration".to_string(),
+            mean: sign * mean,
+            standard_error: variance.sqrt(),
+            samples: per_cell * cells,
+        })
+    }
+}
+
+/// # Importance Sampling
+/// Integral of f over the support of a probability density p, as the mean of f(x) / p(x)
+/// over samples x drawn from p. The variance is small when p is nearly proportional to |f|,
+/// and the support may be unbounded.
+/// The samples are drawn with `transform`, which maps a uniform point of [0, 1)^n to a sample
+/// of p, e.g. with the inverse of its cumulative distribution function, and `density`
+/// evaluates p. Both must describe the same distribution for the result to be correct.
+///
+/// ## Methods
+/// - Initialize the struct
+/// - Change the number of samples and the seed
+/// - Run and Compute the integrals
+pub struct ImportanceSampling<F, S, P> {
+    f: F,
+    dimension: usize,
+    transform: S,
+    density: P,
+    samples: usize,
+    seed: u64,
+}
+
+impl<F, S, P> ImportanceSampling<F, S, P>
+where
+    F: FnMut(&[f64]) -> f64,
+    S: FnMut(&[f64]) -> Vec<f64>,
+    P: FnMut(&[f64]) -> f64,
+{
+    pub fn initialize(function: F, dimension: usize, transform: S, density: P) -> Self {
+        Self {
+            f: function,
+            dimension,
+            transform,
+            density,
+            samples: DEFAULT_SAMPLES,
+            seed: 0,
+        }
+    }
+
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn run(mut self) -> Result<MonteCarloChar, CubatureError> {
+        if self.dimension == 0 {
+            return Err(CubatureError::DimensionError);
+        }
+        if self.samples < 2 {
+            return Err(too_few_samples());
+        }
+
+        let mut rng = SplitMix64::new(self.seed);
+        let mut u = vec![0.0; self.dimension];
+        let mut moments = Moments::default();
+        for _ in 0..self.samples {
+            u.iter_mut().for_each(|u_i| *u_i = rng.next_f64());
+            let x = (self.transform)(&u);
+            if x.len() != self.dimension {
+                return Err(CubatureError::DimensionError);
+            }
+            moments.push(check_finite((self.f)(&x) / (self.density)(&x))?);
+        }
+
+        Ok(MonteCarloChar {
+            msg: "Completed importance sampling".to_string(),
+            mean: moments.mean,
+            standard_error: moments.variance_of_mean().sqrt(),
+            samples: self.samples,
+        })
+    }
+}
+
+/// # VEGAS
+/// Adaptive importance sampling over a box with Lepage's VEGAS algorithm. The sampling density
+/// is a product of piecewise constant densities along the axes, given by grids of `bins` bins
+/// which are refined after every iteration so that each bin holds the same share of the
+/// integral of f^2, damped by the exponent `alpha`. The iterations are combined with weights
+/// inversely proportional to their variances, which assumes the estimates of the first
+/// iterations are not biased by a poor grid; with `skip` the first iterations only train the grid.
+/// Iterations with zero variance have no finite weight and are left out of the average.
+/// The method works best when the peaks of f are aligned with the axes.
+///
+/// ## Methods
+/// - Initialize the struct
+/// - Change the number of samples per iteration, iterations, bins and the damping
+/// - Change the number of training iterations and the seed
+/// - Run and Compute the integrals
+pub struct Vegas<F> {
+    f: F,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    samples: usize,
+    iterations: usize,
+    skip: usize,
+    bins: usize,
+    alpha: f64,
+    seed: u64,
+}
+
+impl<F> Vegas<F>
+where
+    F: FnMut(&[f64]) -> f64,
+{
+    pub fn initialize(function: F, lower: &[f64], upper: &[f64]) -> Self {
+        Self {
+            f: function,
+            lower: lower.to_vec(),
+            upper: upper.to_vec(),
+            samples: DEFAULT_VEGAS_SAMPLES,
+            iterations: DEFAULT_ITERATIONS,
+            skip: 0,
+            bins: DEFAULT_BINS,
+            alpha: DEFAULT_ALPHA,
+            seed: 0,
+        }
+    }
+
+    ///Number of samples of every iteration
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    ///Number of first iterations that only refine the grid and are left out of the result
+    pub fn skip(mut self, skip: usize) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn bins(mut self, bins: usize) -> Self {
+        self.bins = bins;
+        self
+    }
+
+    ///Damping of the grid refinement, 0 keeps the grid uniform and larger values adapt faster
+    pub fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn run(mut self) -> Result<MonteCarloChar, CubatureError> {
+        if self.samples < 2 {
+            return Err(too_few_samples());
+        }
+        if self.bins == 0 || self.iterations <= self.skip || self.alpha.is_nan() || self.alpha < 0.0
+        {
+            return Err(CubatureError::InvalidInput(
+                "invalid bins, iterations or alpha.".to_string(),
+            ));
+        }
+        let (a, b, sign) = ordered_box(&self.lower, &self.upper)?;
+        let n = a.len();
+        let bins = self.bins;
+        let volume: f64 = a.iter().zip(&b).map(|(a_i, b_i)| b_i - a_i).product();
+
+        // Bin edges in [0, 1] of every axis, initially uniform
+        let mut grid: Vec<Vec<f64>> = vec![(0..=bins).map(|k| k as f64 / bins as f64).collect(); n];
+        let mut rng = SplitMix64::new(self.seed);
+        let mut x = vec![0.0; n];
+        let mut bin_of = vec![0; n];
+        let (mut weighted_sum, mut weight_sum) = (0.0, 0.0);
+        let (mut exact_sum, mut exact_iterations) = (0.0, 0);
+
+        for iteration in 0..self.iterations {
+            let mut moments = Moments::default();
+            let mut squares = vec![vec![0.0; bins]; n];
+
+            for _ in 0..self.samples {
+                let mut jacobian = volume;
+                for i in 0..n {
+                    let y = rng.next_f64() * bins as f64;
+                    let k = (y as usize).min(bins - 1);
+                    let width = grid[i][k + 1] - grid[i][k];
+                    x[i] = a[i] + (grid[i][k] + (y - k as f64) * width) * (b[i] - a[i]);
+                    jacobian *= bins as f64 * width;
+                    bin_of[i] = k;
+                }
+                let value = check_finite(jacobian * (self.f)(&x))?;
+                moments.push(value);
+                for i in 0..n {
+                    squares[i][bin_of[i]] += value * value;
+                }
+            }
+
+            if iteration >= self.skip {
+                let variance = moments.variance_of_mean();
+                if variance > 0.0 {
+                    weighted_sum += moments.mean / variance;
+                    weight_sum += 1.0 / variance;
+                } else {
+                    // Exact for this grid, e.g. a constant integrand, which has no weight
+                    exact_sum += moments.mean;
+                    exact_iterations += 1;
+                }
+            }
+            for (edges, squares) in grid.iter_mut().zip(&squares) {
+                refine(edges, squares, self.alpha);
+            }
+        }
+
+        // The iterations with zero variance only give the result when all of them are exact
+        let (mean, standard_error) = if weight_sum > 0.0 {
+            (weighted_sum / weight_sum, (1.0 / weight_sum).sqrt())
+        } else {
+            (exact_sum / exact_iterations as f64, 0.0)
+        };
+        Ok(MonteCarloChar {
+            msg: "Completed VEGAS integration".to_string(),
+            mean: sign * mean,
+            standard_error,
+            samples: self.iterations * self.samples,
+        })
+    }
+}
+
+// Moves the edges of one axis so that every bin holds the same share of the damped weights
+fn refine(edges: &mut [f64], squares: &[f64], alpha: f64) {
+    let bins = squares.len();
+    // Smoothing with the neighbouring bins
+    let smoothed: Vec<f64> = (0..bins)
+        .map(|k| {
+            let lo = k.saturating_sub(1);
+            let hi = (k + 1).min(bins - 1);
+            squares[lo..=hi].iter().sum::<f64>() / (hi - lo + 1) as f64
+        })
+        .collect();
+    let total: f64 = smoothed.iter().sum();
+    if !(total > 0.0 && total.is_finite()) {
+        return;
+    }
+
+    let weights: Vec<f64> = smoothed
+        .iter()
+        .map(|d| {
+            let r = d / total;
+            if r <= 0.0 {
+                0.0
+            } else if r >= 1.0 {
+                1.0
+            } else {
+                ((r - 1.0) / r.ln()).powf(alpha)
+            }
+        })
+        .collect();
+    let average = weights.iter().sum::<f64>() / bins as f64;
+    if average <= 0.0 {
+        return;
+    }
+
+    let old = edges.to_vec();
+    let mut accumulated = 0.0;
+    let mut k = 0;
+    for (j, edge) in edges.iter_mut().enumerate().take(bins).skip(1) {
+        let target = j as f64 * average;
+        while accumulated + weights[k] < target && k + 1 < bins {
+            accumulated += weights[k];
+            k += 1;
+        }
+        let fraction = if weights[k] > 0.0 {
+            ((target - accumulated) / weights[k]).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        *edge = old[k] + fraction * (old[k + 1] - old[k]);
+    }
+}
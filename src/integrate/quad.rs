@@ -1,355 +1,577 @@
 use core::fmt;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::error::Error;
 
-//Default Values and Mathmatical Parameters
-static DEFAULT_TOL:f64=1e-11;
-static DEFAULT_RTOL:f64=0.001;
-static LIMIT_TOL:f64=4.0*f64::EPSILON;
-static DEFAULT_SUBINTERVAL_LIMIT:usize=10000;
-
-static  W:[f64;5]=[0.5688888888888889,0.4786286704993665,0.4786286704993665,0.2369268850561891,0.2369268850561891];
-static  X:[f64;5]=[0.0,-0.5384693101056831,0.5384693101056831, 0.9061798459386639,-0.9061798459386639];
+use super::integrator::{IntegrationError, IntegrationMetrics, Integrator};
+use crate::common::float::Real;
 
+//Default Values and Mathmatical Parameters
+static DEFAULT_TOL: f64 = 1e-11;
+static DEFAULT_RTOL: f64 = 1e-10;
+static DEFAULT_SUBINTERVAL_LIMIT: usize = 10000;
+
+// Kronrod nodes in decreasing order down to the center, with the Kronrod weights and the weights
+// of the embedded Gauss rule, whose nodes are every other Kronrod node (QUADPACK qk15 and qk21)
+static XGK15: [f64; 8] = [
+    0.9914553711208126,
+    0.9491079123427585,
+    0.8648644233597691,
+    0.7415311855993945,
+    0.5860872354676911,
+    0.4058451513773972,
+    0.20778495500789848,
+    0.0,
+];
+static WGK15: [f64; 8] = [
+    0.022935322010529224,
+    0.06309209262997856,
+    0.10479001032225019,
+    0.14065325971552592,
+    0.1690047266392679,
+    0.19035057806478542,
+    0.20443294007529889,
+    0.20948214108472782,
+];
+static WG7: [f64; 4] = [
+    0.1294849661688697,
+    0.27970539148927664,
+    0.3818300505051189,
+    0.4179591836734694,
+];
+
+static XGK21: [f64; 11] = [
+    0.9956571630258081,
+    0.9739065285171717,
+    0.9301574913557082,
+    0.8650633666889845,
+    0.7808177265864169,
+    0.6794095682990244,
+    0.5627571346686047,
+    0.4333953941292472,
+    0.2943928627014602,
+    0.14887433898163122,
+    0.0,
+];
+static WGK21: [f64; 11] = [
+    0.011694638867371874,
+    0.032558162307964725,
+    0.054755896574351995,
+    0.07503967481091996,
+    0.0931254545836976,
+    0.10938715880229764,
+    0.12349197626206584,
+    0.13470921731147334,
+    0.14277593857706009,
+    0.14773910490133849,
+    0.1494455540029169,
+];
+static WG10: [f64; 5] = [
+    0.06667134430868814,
+    0.1494513491505806,
+    0.21908636251598204,
+    0.26926671930999635,
+    0.29552422471475287,
+];
 
 ///Output characteristics for evaluating an one dimensional integral using Adaptive Gauss Quadrature.
 ///Consists of an output message , the number of intervals needed for the result was to be computed,
-///an error estimate given by the sum of errors and the result in f64
+///an error estimate given by the sum of errors and the result in T
 /// ## Attributes
 /// - msg:String
 /// - number_of_intervals:usize
-/// - error_estimate:f64
-/// - integral:f64
-
-pub struct QuadCharacteristics{
-    pub msg:String,
-    pub number_of_intervals:usize,
-    pub error_estimate:f64,
-    pub integral:f64
-
+/// - error_estimate:T
+/// - integral:T
+#[derive(Debug)]
+pub struct QuadCharacteristics<T = f64> {
+    pub msg: String,
+    pub number_of_intervals: usize,
+    pub error_estimate: T,
+    pub integral: T,
 }
-impl fmt::Display for QuadCharacteristics {
+impl<T> fmt::Display for QuadCharacteristics<T>
+where
+    T: Real + fmt::LowerExp,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-
-        writeln!(f,"{}\nWith {} subintervals, the result is {} with error {}",
-            self.msg, self.number_of_intervals,self.integral ,format!("{:.5e}",self.error_estimate)
+        writeln!(
+            f,
+            "{}\nWith {} subintervals, the result is {} with error {:.5e}",
+            self.msg, self.number_of_intervals, self.integral, self.error_estimate
         )
     }
 }
 
-
-
-///Consists of error that happen during exclusively in the run routine.
-enum QuadProcessError{
-    None,
-    SubintervalLimitExceededError,
-    Divergence
-
-}
 ///Errors during integration that occur within every method in the Quad struct.
 ///## Types
 /// - Invalid Input
-/// - Interval is NaN 
+/// - Interval is NaN
 /// - Divergence Occured
 /// - Tolerance Requirments Not Met (Various Issues)
-pub enum QuadError {
-    None,
+#[derive(Debug)]
+pub enum QuadError<T = f64> {
     InvalidInput(String),
     IntervalError,
     Divergence,
-    UnacceptableTolearanceError(QuadCharacteristics),
-
+    UnacceptableTolearanceError(QuadCharacteristics<T>),
 }
-impl fmt::Display for QuadError{
-
+impl<T> fmt::Display for QuadError<T>
+where
+    T: Real + fmt::LowerExp,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            QuadError::None=>{
-                write!(f,"\n")
-            }
-            QuadError::InvalidInput(message)=>{
-                write!(f,"The algorithm could not start due to {}\n",message)
+            QuadError::InvalidInput(message) => {
+                write!(f, "The algorithm could not start due to {}", message)
             }
-            QuadError::IntervalError=>{
-                write!(f,"The interval is not valid\n")
+            QuadError::IntervalError => {
+                write!(f, "The interval is not valid.")
             }
-            QuadError::UnacceptableTolearanceError(char)=>{
-                write!(f,"The algorithm has terminated without meeting the tolerance requirements.
-                    The integral may differge or be irregular on some points\n,{}",char)
-                
+            QuadError::UnacceptableTolearanceError(char) => {
+                write!(
+                    f,
+                    "The algorithm has terminated without meeting the tolerance requirements. \
+                    The integral may diverge or be irregular on some points.\n{}",
+                    char
+                )
             }
-            QuadError::Divergence=>{
-                write!(f,"The integral is guarented to diverge\n")
+            QuadError::Divergence => {
+                write!(f, "The integral is probably divergent.")
             }
+        }
+    }
+}
+
+impl<T> Error for QuadError<T> where T: Real + fmt::LowerExp {}
 
+/// Gauss-Kronrod pair used on every subinterval. The difference between the Kronrod rule and
+/// the embedded Gauss rule gives the error estimate of the subinterval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuadRule {
+    ///7 point Gauss and 15 point Kronrod rule
+    G7K15,
+    ///10 point Gauss and 21 point Kronrod rule
+    G10K21,
+}
 
+impl QuadRule {
+    fn tables(&self) -> (&'static [f64], &'static [f64], &'static [f64]) {
+        match self {
+            QuadRule::G7K15 => (&XGK15, &WGK15, &WG7),
+            QuadRule::G10K21 => (&XGK21, &WGK21, &WG10),
         }
     }
+}
 
+// A subinterval with its integral and error estimates, ordered by the error estimate
+#[derive(Debug, Clone, Copy)]
+struct Subinterval<T> {
+    a: T,
+    b: T,
+    integral: T,
+    error: T,
+}
 
+impl<T: Real> PartialEq for Subinterval<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Real> Eq for Subinterval<T> {}
+
+impl<T: Real> PartialOrd for Subinterval<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Real> Ord for Subinterval<T> {
+    // A NaN estimate is reported as divergence before the heap is popped again
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.error
+            .partial_cmp(&other.error)
+            .unwrap_or(Ordering::Equal)
+    }
 }
 
 /// # Quadrature Integration
-/// Structure that handles input parameters and runs the appropriate
-/// quadrature type on specific interval and function type.
-/// Stores the reference to the function, the interval endpoints, the tolerance attributes, the error type if occured,
-/// the limit of the subintervals to be created and the final result. 
-/// 
+/// Adaptive Gauss-Kronrod quadrature in the style of QUADPACK's QAG and QAGI.
+/// The subinterval with the largest error estimate is bisected until the sum of the error
+/// estimates is below max(tolerance, relative_tolerance |integral|), the subinterval limit
+/// is reached, or a subinterval becomes too small to be bisected.
+/// Infinite intervals are mapped to (0, 1] with x = a + (1 - t) / t.
+/// The default tolerances are raised to 100 times the machine epsilon for types that cannot
+/// resolve them, e.g. f32.
+///
 /// ## Methods
 /// - Initialize the struct
 /// - Change Tolerance Parameters
-/// - Change in Integral Type
+/// - Change the Gauss-Kronrod rule and the subinterval limit
 /// - Run and Compute the integrals
-pub struct Quad{
-
-    f:fn(f64)->f64,
-    a: f64,
-    b: f64,
+pub struct Quad<F, T = f64> {
+    f: F,
+    a: T,
+    b: T,
     limit_subintevals: usize,
-    tolerance:f64,
-    relative_tolerance:f64,
-
-    error_type:QuadError
-    
-    
+    tolerance: T,
+    relative_tolerance: T,
+    rule: QuadRule,
+    func_evals: usize,
 
+    error_type: Option<QuadError<T>>,
 }
-impl Quad{
-
-/// Initialize a new GaussQuad instance with the given function,
-/// lower and upper bounds.
-/// ## Parameters
-/// 
-/// - function: fn(f64)->f64
-/// - a: f64, b: f64 : interval endpoints
-/// 
-/// ## Returns
-/// - Initialized Quad Struct
-pub fn initialize(function:fn(f64)->f64,a:f64,b:f64)->Self{
-
-    Self{
-
-        f:function,
-        a: a,
-        b: b,
-        limit_subintevals: DEFAULT_SUBINTERVAL_LIMIT,
-        
-        tolerance:DEFAULT_TOL,
-        relative_tolerance:DEFAULT_RTOL,
-        
-        error_type:QuadError::None
-        
-
+impl<F> Quad<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    /// Initialize a new GaussQuad instance with the given function,
+    /// lower and upper bounds.
+    /// ## Parameters
+    ///
+    /// - function: FnMut(f64)->f64
+    /// - a: f64, b: f64 : interval endpoints
+    ///
+    /// ## Returns
+    /// - Initialized Quad Struct
+    pub fn initialize(function: F, a: f64, b: f64) -> Self {
+        Self::new(function, a, b)
     }
 }
 
-///A method that changes the tolerance of the computation
-pub fn change_tolerance(mut self, tol:f64)->Self{
-    if tol<LIMIT_TOL {
-        self.error_type=QuadError::InvalidInput("Invalid Tolerance\n".to_string());
+impl<F, T> Quad<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    ///Same as `initialize` for any floating-point type, which is inferred from a and b
+    pub fn new(function: F, a: T, b: T) -> Self {
+        let floor = T::constant(100.0) * T::epsilon();
+        Self {
+            f: function,
+            a,
+            b,
+            limit_subintevals: DEFAULT_SUBINTERVAL_LIMIT,
+
+            tolerance: T::constant(DEFAULT_TOL).max(floor),
+            relative_tolerance: T::constant(DEFAULT_RTOL).max(floor),
+            rule: QuadRule::G7K15,
+            func_evals: 0,
+
+            error_type: None,
+        }
     }
-    self.tolerance=tol;
-    self
-}
 
-///A method that changes the relative tolerance of the computation
-pub fn change_relative_tolerance(mut self, rtol:f64)->Self{
-
-    if rtol<LIMIT_TOL {
-        self.error_type=QuadError::InvalidInput("Invalid Relative Tolerance\n".to_string());
+    ///A method that changes the tolerance of the computation
+    pub fn change_tolerance(mut self, tol: T) -> Self {
+        if tol < T::default_rtol() {
+            self.error_type = Some(QuadError::InvalidInput("invalid tolerance.".to_string()));
+        }
+        self.tolerance = tol;
+        self
     }
 
-    self.relative_tolerance=rtol;
-    self
-}
-
-
-/// A method that runs the numerical integration and return the result.
-/// Firstly check for accumulated errors in the input, then decides 
-/// which function to call and with which parameters to start the computation.
-/// ## Underlying Computing Functions
-/// - quad_finite
-/// - quad_intinite
-pub fn run(self)->Result<QuadCharacteristics,QuadError> {
-    
-    let mut quadchar=QuadCharacteristics { 
-        msg:"".to_string(),
-        number_of_intervals:1,
-        error_estimate:0.0,
-        integral:f64::NAN 
-    };
+    ///A method that changes the relative tolerance of the computation
+    pub fn change_relative_tolerance(mut self, rtol: T) -> Self {
+        if rtol < T::default_rtol() {
+            self.error_type = Some(QuadError::InvalidInput(
+                "invalid relative tolerance.".to_string(),
+            ));
+        }
 
+        self.relative_tolerance = rtol;
+        self
+    }
 
-    //Handles the errors accumulated before run method
-    match self.error_type {
-        QuadError::None=>{}
-        _=>{
-            return Err(self.error_type)
+    ///A method that changes the maximum number of subintervals
+    pub fn change_subinterval_limit(mut self, limit: usize) -> Self {
+        if limit == 0 {
+            self.error_type = Some(QuadError::InvalidInput(
+                "invalid subinterval limit.".to_string(),
+            ));
         }
+        self.limit_subintevals = limit;
+        self
     }
-    let solution:f64;
-    let mut error_type:QuadProcessError=QuadProcessError::None;
-    
-    
-    //Splits the interval cases
-    if self.a.is_finite() && self.b.is_finite(){
-
-        solution=Self::quad_finite(&self.f,0.0,self.a,self.b, self.tolerance,self.relative_tolerance,
-            self.limit_subintevals,&mut quadchar.number_of_intervals,&mut error_type,&mut quadchar.error_estimate)
-    }
-    else if self.a.is_finite() && self.b.is_infinite(){
 
-        solution=Self::quad_infinite(self.f,self.a,1,self.tolerance,self.relative_tolerance,
-            self.limit_subintevals,&mut quadchar.number_of_intervals,&mut error_type,&mut quadchar.error_estimate)
+    ///A method that changes the Gauss-Kronrod rule applied on the subintervals
+    pub fn rule(mut self, rule: QuadRule) -> Self {
+        self.rule = rule;
+        self
     }
-    else if self.a.is_infinite() && self.b.is_finite(){
-        solution=Self::quad_infinite(self.f,self.b,-1,self.tolerance,self.relative_tolerance,
-            self.limit_subintevals,&mut quadchar.number_of_intervals,&mut error_type,&mut quadchar.error_estimate)
 
+    /// A method that runs the numerical integration and return the result.
+    /// Firstly check for accumulated errors in the input, then maps an infinite interval
+    /// to a finite one and runs the adaptive integration.
+    pub fn run(mut self) -> Result<QuadCharacteristics<T>, QuadError<T>> {
+        self.compute()
     }
-    else if self.a.is_infinite() && self.b.is_infinite() {
-        
-        solution=Self::quad_infinite(self.f,0.0,-1,self.tolerance,self.relative_tolerance,
-            self.limit_subintevals,&mut quadchar.number_of_intervals,&mut error_type,&mut quadchar.error_estimate)+
-            Self::quad_infinite(self.f,0.0,1,self.tolerance,self.relative_tolerance,
-                self.limit_subintevals,&mut quadchar.number_of_intervals,&mut error_type,&mut quadchar.error_estimate)
-
-    }   
-    else {
-        return Err(QuadError::IntervalError);
-    }
-    
-    
-    match error_type {
-        QuadProcessError::SubintervalLimitExceededError=>{
-            quadchar.msg="Unacceptable Tolerance due to meating subintervals number limit\n".to_string();
-            quadchar.integral=solution;
-            return  Err(QuadError::UnacceptableTolearanceError(quadchar));
+
+    ///Runs the computation without consuming the struct and records the number of function evaluations.
+    fn compute(&mut self) -> Result<QuadCharacteristics<T>, QuadError<T>> {
+        //Handles the errors accumulated before run method
+        if let Some(QuadError::InvalidInput(message)) = &self.error_type {
+            return Err(QuadError::InvalidInput(message.clone()));
         }
-        _=>{ 
-            quadchar.msg="Completed Integration".to_string();   
-            quadchar.integral=solution;
-            return  Ok(quadchar)
+        if self.a.is_nan() || self.b.is_nan() {
+            return Err(QuadError::IntervalError);
         }
 
-        
+        // Integrate from the smaller to the larger endpoint and restore the sign afterwards
+        let (a, b, sign) = if self.a <= self.b {
+            (self.a, self.b, T::one())
+        } else {
+            (self.b, self.a, -T::one())
+        };
+
+        let function = &mut self.f;
+        let mut func_evals = 0;
+        let mut counted_function = |x: T| {
+            func_evals += 1;
+            function(x)
+        };
+        let tables = self.rule.tables();
+        let (tol, rtol, limit) = (
+            self.tolerance,
+            self.relative_tolerance,
+            self.limit_subintevals,
+        );
+
+        let (zero, one) = (T::zero(), T::one());
+
+        //Splits the interval cases
+        let outcome = if a == b {
+            Ok(Adaptive::empty())
+        } else if a.is_finite() && b.is_finite() {
+            adaptive(&mut counted_function, a, b, tables, tol, rtol, limit)
+        } else if a.is_finite() {
+            let mut mapped = |t: T| counted_function(a + (one - t) / t) / (t * t);
+            adaptive(&mut mapped, zero, one, tables, tol, rtol, limit)
+        } else if b.is_finite() {
+            let mut mapped = |t: T| counted_function(b - (one - t) / t) / (t * t);
+            adaptive(&mut mapped, zero, one, tables, tol, rtol, limit)
+        } else {
+            let mut mapped = |t: T| {
+                let x = (one - t) / t;
+                (counted_function(x) + counted_function(-x)) / (t * t)
+            };
+            adaptive(&mut mapped, zero, one, tables, tol, rtol, limit)
+        };
+        self.func_evals = func_evals;
+
+        let adaptive = outcome?;
+        let quadchar = QuadCharacteristics {
+            msg: String::new(),
+            number_of_intervals: adaptive.intervals,
+            error_estimate: adaptive.error,
+            integral: sign * adaptive.integral,
+        };
+        match adaptive.stop {
+            Stop::Converged => Ok(QuadCharacteristics {
+                msg: "Completed Integration".to_string(),
+                ..quadchar
+            }),
+            Stop::SubintervalLimit => Err(QuadError::UnacceptableTolearanceError(
+                QuadCharacteristics {
+                    msg: "Unacceptable Tolerance due to meating subintervals number limit\n"
+                        .to_string(),
+                    ..quadchar
+                },
+            )),
+            Stop::TooSmallInterval => Err(QuadError::UnacceptableTolearanceError(
+                QuadCharacteristics {
+                    msg: "Unacceptable Tolerance due to a subinterval too small to be bisected\n"
+                        .to_string(),
+                    ..quadchar
+                },
+            )),
+        }
     }
+}
 
+// Reason the adaptive integration stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stop {
+    Converged,
+    SubintervalLimit,
+    TooSmallInterval,
+}
+
+// State of the adaptive integration when it stopped
+struct Adaptive<T> {
+    integral: T,
+    error: T,
+    intervals: usize,
+    stop: Stop,
+}
 
+impl<T: Real> Adaptive<T> {
+    fn empty() -> Self {
+        Self {
+            integral: T::zero(),
+            error: T::zero(),
+            intervals: 1,
+            stop: Stop::Converged,
+        }
+    }
 }
 
+// Applies the Gauss-Kronrod pair on [a, b] and estimates the error of the Kronrod result
+// as QUADPACK does, scaling the raw difference to avoid overly pessimistic estimates for smooth
+// integrands and bounding it from below by the rounding error of the sum.
+// The f64 tables are rounded to T.
+fn gauss_kronrod<G, T>(
+    function: &mut G,
+    a: T,
+    b: T,
+    (xgk, wgk, wg): (&[f64], &[f64], &[f64]),
+) -> Subinterval<T>
+where
+    G: FnMut(T) -> T,
+    T: Real,
+{
+    let half = T::constant(0.5);
+    let center = half * (a + b);
+    let half_length = half * (b - a);
+    let last = xgk.len() - 1;
+    let gauss_center = 2 * wg.len() == xgk.len();
+
+    let mut values = vec![T::zero(); 2 * last + 1];
+    let f_center = function(center);
+    values[2 * last] = f_center;
+    let mut result_kronrod = T::constant(wgk[last]) * f_center;
+    let mut result_gauss = if gauss_center {
+        T::constant(wg[wg.len() - 1]) * f_center
+    } else {
+        T::zero()
+    };
+    let mut result_abs = result_kronrod.abs();
+
+    for j in 0..last {
+        let dx = half_length * T::constant(xgk[j]);
+        let f1 = function(center - dx);
+        let f2 = function(center + dx);
+        values[2 * j] = f1;
+        values[2 * j + 1] = f2;
+        let w = T::constant(wgk[j]);
+        result_kronrod = result_kronrod + w * (f1 + f2);
+        result_abs = result_abs + w * (f1.abs() + f2.abs());
+        if j % 2 == 1 {
+            result_gauss = result_gauss + T::constant(wg[j / 2]) * (f1 + f2);
+        }
+    }
 
-///Computes the integral in an finite interval
-///implemented with an adaptive 5 point gauss-legendre quadrature.
-/// ## Parameters
-/// - function: &Fn(f64)->f64 (reference)
-/// - approx: f64 
-/// - a: f64, b: f64 : integral endpoints 
-/// - tolerance: f64, rtolerance:f64 : tolerance attributes
-/// - limit_iter:usize : Limit of subintervals created
-/// - iter: &mut usize : Starting with zero , passed as reference
-/// - error_type: &mut QuadProcessError : Starting with None type
-/// - error_estimate:&mut f64 : Sum of errors passed as reference
-/// 
-/// ## Returns
-/// - solution:f64
-fn quad_finite<F:Fn(f64)->f64>(function:&F,approx:f64 ,a: f64, b: f64, tolerance: f64,rtolerance:f64,limit_iter:usize,iter: &mut usize,error_type: &mut QuadProcessError, error_estimate:&mut f64)->f64{
-    
-
-    *iter+=1;
-
-    let midpoint:f64 = a + (b-a) / 2.0;
-    let center: f64=(midpoint-a)/2.0;
-    
-
-    let mut left_area:f64=0.0;
-    let mut right_area:f64=0.0;
-
-    for i in 0..W.len() {
-        
-        left_area += ((*function)((X[i]+1.0)*center+a))* W[i];
-        right_area += ((*function)((X[i]+1.0)*center+midpoint))* W[i];
+    // Integral of |f - mean| to scale the error estimate
+    let mean = half * result_kronrod;
+    let mut result_asc = T::constant(wgk[last]) * (f_center - mean).abs();
+    for j in 0..last {
+        result_asc = result_asc
+            + T::constant(wgk[j])
+                * ((values[2 * j] - mean).abs() + (values[2 * j + 1] - mean).abs());
     }
-    
-    left_area= left_area * center;
-    right_area= right_area * center;
 
+    let scale = half_length.abs();
+    let integral = result_kronrod * half_length;
+    result_abs = result_abs * scale;
+    result_asc = result_asc * scale;
+    let mut error = ((result_kronrod - result_gauss) * half_length).abs();
 
-    //Next Iteration Desicion Tree
-    match *error_type {
-        QuadProcessError::None=>{}
-        _=>{
-            return left_area+right_area;
-        }
+    if result_asc != T::zero() && error != T::zero() {
+        let ratio = T::constant(200.0) * error / result_asc;
+        error = result_asc * ratio.powf(T::constant(1.5)).min(T::one());
     }
-    if  *iter >= limit_iter {
-
-        *error_type=QuadProcessError::SubintervalLimitExceededError;
-        return left_area + right_area;
-    } 
-    else if (approx - (left_area + right_area)).abs() <= tolerance {
-
-        
-        *error_estimate+=(approx - (left_area + right_area)).abs();
-        return left_area + right_area;
-
-    }else if (approx - (left_area + right_area)).abs()<1.0 {
-        *error_type=QuadProcessError::Divergence;
-        return left_area+right_area;
-    }else {
-        
-        let left_result = Self::quad_finite(function, left_area, a, midpoint, tolerance / 2.0,rtolerance,limit_iter,iter,error_type,error_estimate);
-        let right_result = Self::quad_finite(function,right_area, midpoint, b, tolerance / 2.0,rtolerance,limit_iter,iter,error_type,error_estimate);
-        return left_result + right_result;
+    let rounding = T::constant(50.0) * T::epsilon();
+    if result_abs > T::min_positive_value() / rounding {
+        error = error.max(rounding * result_abs);
     }
 
+    Subinterval {
+        a,
+        b,
+        integral,
+        error,
+    }
 }
 
-///Computes the integral in an infinite interval by changing the variable
-///and calling the quad_finite function for a finite interval.
-/// ## Parameters
-/// - function: fn(f64)->f64
-/// - a: f64, b: f64 : integral endpoints 
-/// - inf:i32 : Type of infinity (1 or -1)
-/// - tolerance: f64, rtolerance:f64 : tolerance attributes
-/// - limit_iter:usize : Limit of subintervals created
-/// - iter: &mut usize : Starting with zero , passed as reference
-/// - error_type: &mut QuadProcessError : Starting with None type
-/// - error_estimate:&mut f64 : Sum of errors passed as reference
-/// 
-/// ## Returns
-/// - solution:f64
-fn quad_infinite(function:fn(f64)->f64 ,a: f64, inf: i32, tolerance: f64,rtolerance:f64,limit_iter:usize,iter: &mut usize,error_type: &mut QuadProcessError, error_estimate:&mut f64)->f64{
-
-    let adjusted_function=|x:f64|->f64 {
-        let result=(function)(1.0/x)/(x).powi(2);
-        result
-    };
-
-    if inf==1{
-        if a < 1.0{
-            
-            return Self::quad_finite(&function, 0.0, a,1.0 , tolerance,rtolerance,limit_iter,iter,error_type,error_estimate)
-                -Self::quad_finite(&adjusted_function, 0.0, 1.0,0.0 , tolerance,rtolerance,limit_iter,iter,error_type,error_estimate);
+// Bisects the subinterval with the largest error estimate until the requested accuracy is met.
+// Non-finite estimates are reported as divergence, which is how a non-integrable singularity
+// ends once its values overflow on the shrinking subintervals around it.
+fn adaptive<G, T>(
+    function: &mut G,
+    a: T,
+    b: T,
+    tables: (&[f64], &[f64], &[f64]),
+    tol: T,
+    rtol: T,
+    limit: usize,
+) -> Result<Adaptive<T>, QuadError<T>>
+where
+    G: FnMut(T) -> T,
+    T: Real,
+{
+    let first = gauss_kronrod(function, a, b, tables);
+    let mut integral = first.integral;
+    let mut error = first.error;
+    let mut heap = BinaryHeap::new();
+    heap.push(first);
+
+    let mut stop = Stop::Converged;
+    while error > tol.max(rtol * integral.abs()) {
+        if !integral.is_finite() || !error.is_finite() {
+            return Err(QuadError::Divergence);
         }
-        else {
-            return Self::quad_finite(&adjusted_function, 0.0, 0.0,1.0 /a, tolerance,rtolerance,limit_iter,iter,error_type,error_estimate);
+        if heap.len() >= limit {
+            stop = Stop::SubintervalLimit;
+            break;
         }
-    }
-    else {
 
-        if a >-1.0{
-            
-            return Self::quad_finite(&function, 0.0, -1.0,a , tolerance,rtolerance,limit_iter,iter,error_type,error_estimate)
-                +Self::quad_finite(&adjusted_function, 0.0, -1.0,0.0 , tolerance,rtolerance,limit_iter,iter,error_type,error_estimate);
-        }
-        else {
-            return Self::quad_finite(&adjusted_function, 0.0, 0.0,1.0 /a, tolerance,rtolerance,limit_iter,iter,error_type,error_estimate);
+        let worst = heap.pop().unwrap();
+        let midpoint = T::constant(0.5) * (worst.a + worst.b);
+
+        // The interval can no longer be split into distinct floating-point numbers
+        if midpoint <= worst.a || midpoint >= worst.b {
+            heap.push(worst);
+            stop = Stop::TooSmallInterval;
+            break;
         }
-        
+
+        let left = gauss_kronrod(function, worst.a, midpoint, tables);
+        let right = gauss_kronrod(function, midpoint, worst.b, tables);
+
+        integral = integral + left.integral + right.integral - worst.integral;
+        error = error + left.error + right.error - worst.error;
+        heap.push(left);
+        heap.push(right);
     }
-    
 
-}
+    // Sum again to remove the rounding errors of the running updates
+    let intervals = heap.len();
+    let integral_sum = heap.iter().fold(T::zero(), |sum, s| sum + s.integral);
+    let error_sum = heap.iter().fold(T::zero(), |sum, s| sum + s.error);
+    if !integral_sum.is_finite() || !error_sum.is_finite() {
+        return Err(QuadError::Divergence);
+    }
 
+    Ok(Adaptive {
+        integral: integral_sum,
+        error: error_sum,
+        intervals,
+        stop,
+    })
+}
 
-    
+impl<F, T> Integrator<T> for Quad<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn integrate(&mut self) -> Result<IntegrationMetrics<T>, IntegrationError<T>> {
+        let quadchar = self.compute()?;
+        Ok(IntegrationMetrics {
+            msg: quadchar.msg,
+            integral: quadchar.integral,
+            error_estimate: quadchar.error_estimate,
+            func_evals: self.func_evals,
+        })
+    }
 }
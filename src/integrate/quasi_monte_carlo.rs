@@ -0,0 +1,266 @@
+use super::cubature::{ordered_box, CubatureCharacteristics, CubatureError};
+use crate::common::random::SplitMix64;
+
+static DEFAULT_POINTS: usize = 4096;
+static DEFAULT_RANDOMIZATIONS: usize = 8;
+static SOBOL_BITS: usize = 32;
+
+// Degree s, coefficients a and initial direction numbers m of the primitive polynomials for
+// the dimensions after the first, from the tables of Joe and Kuo
+static SOBOL_TABLE: [(u32, u32, &[u32]); 20] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+///Maximum dimension of the Sobol sequence
+pub const SOBOL_MAX_DIMENSION: usize = SOBOL_TABLE.len() + 1;
+
+/// Low discrepancy sequences of the quasi-Monte Carlo integration.
+/// - Sobol: base 2 digital sequence, up to `SOBOL_MAX_DIMENSION` dimensions
+/// - Halton: radical inverses in the first n primes, any dimension, though the
+///   uniformity of its projections degrades in high dimensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LowDiscrepancy {
+    Sobol,
+    Halton,
+}
+
+/// # Sobol Sequence
+/// Points of the Sobol sequence in [0, 1)^n in Gray code order, starting after the origin.
+pub struct SobolSequence {
+    directions: Vec<[u32; SOBOL_BITS]>,
+    state: Vec<u32>,
+    index: u64,
+}
+
+impl SobolSequence {
+    pub fn new(dimension: usize) -> Result<Self, CubatureError> {
+        if dimension == 0 || dimension > SOBOL_MAX_DIMENSION {
+            return Err(CubatureError::DimensionError);
+        }
+        let mut directions = vec![[0u32; SOBOL_BITS]; dimension];
+        for (j, v) in directions[0].iter_mut().enumerate() {
+            *v = 1 << (SOBOL_BITS - 1 - j);
+        }
+        for (d, (s, a, m)) in SOBOL_TABLE.iter().take(dimension - 1).enumerate() {
+            let s = *s as usize;
+            let v = &mut directions[d + 1];
+            for j in 0..SOBOL_BITS {
+                v[j] = if j < s {
+                    m[j] << (SOBOL_BITS - 1 - j)
+                } else {
+                    let mut value = v[j - s] ^ (v[j - s] >> s);
+                    for k in 1..s {
+                        if (a >> (s - 1 - k)) & 1 == 1 {
+                            value ^= v[j - k];
+                        }
+                    }
+                    value
+                };
+            }
+        }
+        Ok(Self {
+            directions,
+            state: vec![0; dimension],
+            index: 0,
+        })
+    }
+}
+
+impl Iterator for SobolSequence {
+    type Item = Vec<f64>;
+
+    fn next(&mut self) -> Option<Vec<f64>> {
+        // The lowest zero bit of the index selects the direction number to flip
+        let bit = (!self.index).trailing_zeros() as usize;
+        if bit >= SOBOL_BITS {
+            return None;
+        }
+        self.index += 1;
+        let scale = 1.0 / (1u64 << SOBOL_BITS) as f64;
+        Some(
+            self.state
+                .iter_mut()
+                .zip(&self.directions)
+                .map(|(x, v)| {
+                    *x ^= v[bit];
+                    *x as f64 * scale
+                })
+                .collect(),
+        )
+    }
+}
+
+/// # Halton Sequence
+/// Points of the Halton sequence in [0, 1)^n, starting after the origin.
+pub struct HaltonSequence {
+    bases: Vec<u64>,
+    index: u64,
+}
+
+impl HaltonSequence {
+    pub fn new(dimension: usize) -> Result<Self, CubatureError> {
+        if dimension == 0 {
+            return Err(CubatureError::DimensionError);
+        }
+        let mut bases: Vec<u64> = Vec::with_capacity(dimension);
+        let mut candidate = 2;
+        while bases.len() < dimension {
+            if bases.iter().all(|p| candidate % p != 0) {
+                bases.push(candidate);
+            }
+            candidate += 1;
+        }
+        Ok(Self { bases, index: 0 })
+    }
+}
+
+impl Iterator for HaltonSequence {
+    type Item = Vec<f64>;
+
+    fn next(&mut self) -> Option<Vec<f64>> {
+        self.index += 1;
+        Some(
+            self.bases
+                .iter()
+                .map(|&base| radical_inverse(self.index, base))
+                .collect(),
+        )
+    }
+}
+
+fn radical_inverse(mut index: u64, base: u64) -> f64 {
+    let inverse_base = 1.0 / base as f64;
+    let mut scale = inverse_base;
+    let mut result = 0.0;
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale *= inverse_base;
+    }
+    result
+}
+
+/// # Quasi-Monte Carlo Integration
+/// Integration over a box with randomized low discrepancy points, whose error decreases
+/// almost as 1/N instead of the 1/sqrt(N) of Monte Carlo, for any number of dimensions.
+/// Each randomization shifts all points by the same uniform random vector modulo 1,
+/// the integral is the mean over the randomizations and the error estimate is its standard error.
+/// The shifts are generated from the seed, so results are reproducible.
+///
+/// ## Methods
+/// - Initialize the struct
+/// - Change the sequence, the number of points and randomizations and the seed
+/// - Run and Compute the integrals
+pub struct QuasiMonteCarlo<F> {
+    f: F,
+    lower: Vec<f64>,
+    upper: Vec<f64>,
+    sequence: LowDiscrepancy,
+    points: usize,
+    randomizations: usize,
+    seed: u64,
+}
+
+impl<F> QuasiMonteCarlo<F>
+where
+    F: FnMut(&[f64]) -> f64,
+{
+    pub fn initialize(function: F, lower: &[f64], upper: &[f64]) -> Self {
+        Self {
+            f: function,
+            lower: lower.to_vec(),
+            upper: upper.to_vec(),
+            sequence: LowDiscrepancy::Sobol,
+            points: DEFAULT_POINTS,
+            randomizations: DEFAULT_RANDOMIZATIONS,
+            seed: 0,
+        }
+    }
+
+    pub fn sequence(mut self, sequence: LowDiscrepancy) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
+    ///Number of points of every randomization, preferably a power of 2 for the Sobol sequence
+    pub fn points(mut self, points: usize) -> Self {
+        self.points = points;
+        self
+    }
+
+    ///Number of random shifts, at least 2 for an error estimate
+    pub fn randomizations(mut self, randomizations: usize) -> Self {
+        self.randomizations = randomizations;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn run(mut self) -> Result<CubatureCharacteristics, CubatureError> {
+        if self.points == 0 || self.randomizations < 2 {
+            return Err(CubatureError::InvalidInput(
+                "too few points or randomizations.".to_string(),
+            ));
+        }
+        let (a, b, sign) = ordered_box(&self.lower, &self.upper)?;
+        let n = a.len();
+        let volume: f64 = a.iter().zip(&b).map(|(a_i, b_i)| b_i - a_i).product();
+
+        let mut rng = SplitMix64::new(self.seed);
+        let mut estimates = Vec::with_capacity(self.randomizations);
+        let mut x = vec![0.0; n];
+        for _ in 0..self.randomizations {
+            let shift: Vec<f64> = (0..n).map(|_| rng.next_f64()).collect();
+            let sequence: Box<dyn Iterator<Item = Vec<f64>>> = match self.sequence {
+                LowDiscrepancy::Sobol => Box::new(SobolSequence::new(n)?),
+                LowDiscrepancy::Halton => Box::new(HaltonSequence::new(n)?),
+            };
+
+            let mut sum = 0.0;
+            for u in sequence.take(self.points) {
+                for i in 0..n {
+                    let shifted = (u[i] + shift[i]).fract();
+                    x[i] = a[i] + shifted * (b[i] - a[i]);
+                }
+                sum += (self.f)(&x);
+            }
+            estimates.push(volume * sum / self.points as f64);
+        }
+
+        let m = estimates.len() as f64;
+        let mean = estimates.iter().sum::<f64>() / m;
+        let variance = estimates.iter().map(|e| (e - mean).powi(2)).sum::<f64>() / (m - 1.0);
+        if !mean.is_finite() {
+            return Err(CubatureError::Divergence);
+        }
+
+        Ok(CubatureCharacteristics {
+            msg: "Completed Integration".to_string(),
+            integral: sign * mean,
+            error_estimate: (variance / m).sqrt(),
+            func_evals: self.points * self.randomizations,
+        })
+    }
+}
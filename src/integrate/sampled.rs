@@ -0,0 +1,159 @@
+use core::fmt;
+use std::error::Error;
+
+use ndarray::{Array1, ArrayView1};
+
+#[derive(Debug)]
+pub enum SampledDataError {
+    DimensionMismatchError,
+    TooFewSamplesError,
+    SpacingError,
+}
+
+impl fmt::Display for SampledDataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SampledDataError::DimensionMismatchError => {
+                write!(f, "The samples y and x have different lengths.")
+            }
+            SampledDataError::TooFewSamplesError => {
+                write!(f, "At least two samples are needed.")
+            }
+            SampledDataError::SpacingError => {
+                write!(
+                    f,
+                    "The sample points x must be strictly increasing or strictly decreasing."
+                )
+            }
+        }
+    }
+}
+
+impl Error for SampledDataError {}
+
+// Checks the lengths and returns the views of the samples
+fn views<'a, Y, X>(
+    y: Y,
+    x: X,
+) -> Result<(ArrayView1<'a, f64>, ArrayView1<'a, f64>), SampledDataError>
+where
+    Y: Into<ArrayView1<'a, f64>>,
+    X: Into<ArrayView1<'a, f64>>,
+{
+    let (y, x) = (y.into(), x.into());
+    if y.len() != x.len() {
+        return Err(SampledDataError::DimensionMismatchError);
+    }
+    if y.len() < 2 {
+        return Err(SampledDataError::TooFewSamplesError);
+    }
+    Ok((y, x))
+}
+
+// Spacings of strictly monotonic sample points
+fn spacings(x: &ArrayView1<f64>) -> Result<Vec<f64>, SampledDataError> {
+    let h: Vec<f64> = x.windows(2).into_iter().map(|w| w[1] - w[0]).collect();
+    let direction = h[0].signum();
+    if h.iter().all(|h_k| h_k * direction > 0.0) {
+        Ok(h)
+    } else {
+        Err(SampledDataError::SpacingError)
+    }
+}
+
+///Integral of the piecewise linear interpolant of the samples y at the points x.
+///Accepts slices, vectors and `Array1`, e.g. `trapezoid(&y, &x)`.
+///Decreasing points give the integral with the opposite sign.
+pub fn trapezoid<'a, Y, X>(y: Y, x: X) -> Result<f64, SampledDataError>
+where
+    Y: Into<ArrayView1<'a, f64>>,
+    X: Into<ArrayView1<'a, f64>>,
+{
+    let (y, x) = views(y, x)?;
+    Ok((0..y.len() - 1)
+        .map(|k| 0.5 * (x[k + 1] - x[k]) * (y[k] + y[k + 1]))
+        .sum())
+}
+
+///Running integrals of the trapezoid rule from x[0] to every x[k], starting with 0.
+pub fn cumulative_trapezoid<'a, Y, X>(y: Y, x: X) -> Result<Array1<f64>, SampledDataError>
+where
+    Y: Into<ArrayView1<'a, f64>>,
+    X: Into<ArrayView1<'a, f64>>,
+{
+    let (y, x) = views(y, x)?;
+    let mut integral = Array1::zeros(y.len());
+    for k in 1..y.len() {
+        integral[k] = integral[k - 1] + 0.5 * (x[k] - x[k - 1]) * (y[k - 1] + y[k]);
+    }
+    Ok(integral)
+}
+
+// Integral over [x0, x1] of the parabola through (x0, y0), (x1, y1), (x2, y2),
+// with h0 = x1 - x0 and h1 = x2 - x1
+fn parabola_first_interval(h0: f64, h1: f64, y0: f64, y1: f64, y2: f64) -> f64 {
+    let h = h0 + h1;
+    let w0 = (2.0 * h0 * h0 + 3.0 * h0 * h1) / (6.0 * h);
+    let w1 = (h0 * h0 + 3.0 * h0 * h1) / (6.0 * h1);
+    let w2 = h0 * h0 * h0 / (6.0 * h1 * h);
+    w0 * y0 + w1 * y1 - w2 * y2
+}
+
+// Integrals over every interval of the parabolas through consecutive pairs of intervals,
+// the last interval of an odd number of intervals using the parabola through the last 3 points
+fn simpson_intervals(y: &ArrayView1<f64>, h: &[f64]) -> Vec<f64> {
+    let intervals = h.len();
+    if intervals == 1 {
+        return vec![0.5 * h[0] * (y[0] + y[1])];
+    }
+    let mut parts = Vec::with_capacity(intervals);
+    for k in (0..intervals - 1).step_by(2) {
+        let (h0, h1) = (h[k], h[k + 1]);
+        let (y0, y1, y2) = (y[k], y[k + 1], y[k + 2]);
+        parts.push(parabola_first_interval(h0, h1, y0, y1, y2));
+        parts.push(parabola_first_interval(h1, h0, y2, y1, y0));
+    }
+    if !intervals.is_multiple_of(2) {
+        let k = intervals - 2;
+        parts.push(parabola_first_interval(
+            h[k + 1],
+            h[k],
+            y[k + 2],
+            y[k + 1],
+            y[k],
+        ));
+    }
+    parts
+}
+
+///Composite Simpson's rule for samples y at strictly monotonic, possibly non-uniform, points x.
+///Every pair of intervals is integrated with the parabola through its three points. For an even
+///number of samples, i.e. an odd number of intervals, the last interval is integrated with the
+///parabola through the last three points, which keeps the rule exact for quadratics.
+///Two samples give the trapezoid rule.
+pub fn simpson<'a, Y, X>(y: Y, x: X) -> Result<f64, SampledDataError>
+where
+    Y: Into<ArrayView1<'a, f64>>,
+    X: Into<ArrayView1<'a, f64>>,
+{
+    let (y, x) = views(y, x)?;
+    let h = spacings(&x)?;
+    Ok(simpson_intervals(&y, &h).iter().sum())
+}
+
+///Running integrals of Simpson's rule from x[0] to every x[k], starting with 0.
+///The intervals are integrated with the same parabolas as in `simpson`,
+///so the last value equals `simpson(y, x)`.
+pub fn cumulative_simpson<'a, Y, X>(y: Y, x: X) -> Result<Array1<f64>, SampledDataError>
+where
+    Y: Into<ArrayView1<'a, f64>>,
+    X: Into<ArrayView1<'a, f64>>,
+{
+    let (y, x) = views(y, x)?;
+    let h = spacings(&x)?;
+    let mut integral = Array1::zeros(y.len());
+    for (k, part) in simpson_intervals(&y, &h).into_iter().enumerate() {
+        integral[k + 1] = integral[k] + part;
+    }
+    Ok(integral)
+}
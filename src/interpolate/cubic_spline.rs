@@ -1,15 +1,17 @@
-use ndarray::{Array1, Array2};
-use ndarray_linalg::SolveTridiagonal;
 use std::cmp::Ordering;
 
 use super::error_utils::InterpolationError;
+use crate::common::float::Real;
 
-pub struct CubicSpline {
-    segments: Vec<(f64, f64, f64, f64)>,
-    coefficients: Vec<(f64, f64, f64, f64)>,
+pub struct CubicSpline<T = f64> {
+    segments: Vec<(T, T, T, T)>,
+    coefficients: Vec<(T, T, T, T)>,
 }
 
-impl CubicSpline {
+impl<T> CubicSpline<T>
+where
+    T: Real,
+{
     /// # Natural Cubic Spline Constructor
     ///
     /// Constructs a natural cubic spline based on the given set of points.
@@ -29,16 +31,18 @@ impl CubicSpline {
     ///
     /// * `InterpolationError::DuplicateXValuesError` - Thrown when two points have the same x-value.
     ///
-    pub(crate) fn new(pts: &mut Vec<(f64, f64)>) -> Result<Self, InterpolationError> {
+    pub(crate) fn new(pts: &mut Vec<(T, T)>) -> Result<Self, InterpolationError> {
         pts.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
 
         let mut segments = Vec::new();
         let mut coefficients = Vec::new();
         let mut h = Vec::new();
         let n = pts.len();
+        let two = T::constant(2.0);
+        let six = T::constant(6.0);
 
         // Initialize m vector with zeros, including endpoints
-        let mut m = Array1::<f64>::zeros(n);
+        let mut m = vec![T::zero(); n];
 
         for i in 0..(n - 1) {
             let (x1, y1) = pts[i];
@@ -52,31 +56,26 @@ impl CubicSpline {
             segments.push((x1, y1, x2, y2));
         }
 
-        let mut b = Array1::<f64>::zeros(n - 2);
+        let mut b = vec![T::zero(); n - 2];
         for i in 0..(n - 2) {
             let (_, y0) = pts[i];
             let (_, y1) = pts[i + 1];
             let (_, y2) = pts[i + 2];
-            b[i] = 6.0 * ((y2 - y1) / h[i + 1] - (y1 - y0) / h[i]);
+            b[i] = six * ((y2 - y1) / h[i + 1] - (y1 - y0) / h[i]);
         }
 
-        let mut a = Array2::<f64>::zeros((n - 2, n - 2));
+        let mut diagonal = vec![T::zero(); n - 2];
         for i in 0..(n - 2) {
-            a[[i, i]] = 2.0 * (h[i] + h[i + 1]);
+            diagonal[i] = two * (h[i] + h[i + 1]);
         }
 
-        for i in 0..(n - 3) {
-            a[[i, i + 1]] = h[i + 1];
-            a[[i + 1, i]] = h[i + 1];
-        }
+        let off_diagonal = h[1..(n - 2)].to_vec();
 
         // Solve the tridiagonal system
-        let m_inner = a.solve_tridiagonal_into(b).unwrap();
+        let m_inner = solve_tridiagonal(&diagonal, &off_diagonal, b);
 
         // Fill in the inner m values
-        for i in 1..(n - 1) {
-            m[i] = m_inner[i - 1];
-        }
+        m[1..(n - 1)].copy_from_slice(&m_inner);
 
         for i in 0..(n - 1) {
             let (_x_i, y_i) = pts[i];
@@ -86,9 +85,9 @@ impl CubicSpline {
             let m_ipp = m[i + 1];
 
             let a_i = y_i;
-            let b_i = (y_ipp - y_i) / h_i - h_i * (m_ipp + 2.0 * m_i) / 6.0;
-            let c_i = m_i / 2.0;
-            let d_i = (m_ipp - m_i) / (6.0 * h_i);
+            let b_i = (y_ipp - y_i) / h_i - h_i * (m_ipp + two * m_i) / six;
+            let c_i = m_i / two;
+            let d_i = (m_ipp - m_i) / (six * h_i);
 
             coefficients.push((a_i, b_i, c_i, d_i));
         }
@@ -110,10 +109,10 @@ impl CubicSpline {
     ///
     /// # Returns
     ///
-    /// * `Option<f64>` - Returns the y-coordinate corresponding to `x` if `x` is within the domain of the spline.
+    /// * `Option<T>` - Returns the y-coordinate corresponding to `x` if `x` is within the domain of the spline.
     ///   Returns `None` if `x` is outside the domain.
     ///
-    pub fn eval(&self, x: f64) -> Option<f64> {
+    pub fn eval(&self, x: T) -> Option<T> {
         let idx = self.segments.binary_search_by(|&(x1, _, x2, _)| {
             if x < x1 {
                 Ordering::Greater
@@ -135,3 +134,27 @@ impl CubicSpline {
         }
     }
 }
+
+// Solves the symmetric tridiagonal system with the given diagonal and off-diagonal by the Thomas
+// algorithm. The system of the spline is diagonally dominant, so no pivoting is needed.
+fn solve_tridiagonal<T>(diagonal: &[T], off_diagonal: &[T], mut rhs: Vec<T>) -> Vec<T>
+where
+    T: Real,
+{
+    let n = diagonal.len();
+    let mut pivots = diagonal.to_vec();
+
+    for i in 1..n {
+        let factor = off_diagonal[i - 1] / pivots[i - 1];
+        pivots[i] = pivots[i] - factor * off_diagonal[i - 1];
+        rhs[i] = rhs[i] - factor * rhs[i - 1];
+    }
+
+    for i in (0..n).rev() {
+        if i + 1 < n {
+            rhs[i] = rhs[i] - off_diagonal[i] * rhs[i + 1];
+        }
+        rhs[i] = rhs[i] / pivots[i];
+    }
+    rhs
+}
@@ -1,4 +1,5 @@
 use super::{cubic_spline::CubicSpline, linear_spline::LinearSpline};
+use crate::common::float::Real;
 
 /// # Linear Spline Interpolation
 ///
@@ -19,9 +20,12 @@ use super::{cubic_spline::CubicSpline, linear_spline::LinearSpline};
 /// # Returns
 ///
 /// * `LinearSpline` - A `LinearSpline` object that can be used for interpolation.
-pub fn linear_spline(
-    pts: &[(f64, f64)],
-) -> Result<LinearSpline, super::error_utils::InterpolationError> {
+pub fn linear_spline<T>(
+    pts: &[(T, T)],
+) -> Result<LinearSpline<T>, super::error_utils::InterpolationError>
+where
+    T: Real,
+{
     let mut pts_clone = pts.to_owned();
     LinearSpline::new(&mut pts_clone)
 }
@@ -44,9 +48,12 @@ pub fn linear_spline(
 /// # Returns
 ///
 /// * `LinearSpline` - A `LinearSpline` object that can be used for interpolation.
-pub fn linear_spline_in_place(
-    pts: &mut Vec<(f64, f64)>,
-) -> Result<LinearSpline, super::error_utils::InterpolationError> {
+pub fn linear_spline_in_place<T>(
+    pts: &mut Vec<(T, T)>,
+) -> Result<LinearSpline<T>, super::error_utils::InterpolationError>
+where
+    T: Real,
+{
     LinearSpline::new(pts)
 }
 
@@ -69,9 +76,12 @@ pub fn linear_spline_in_place(
 ///
 /// * `CubicSpline` - A `CubicSpline` object that can be used for interpolation.
 ///
-pub fn cubic_spline(
-    pts: &[(f64, f64)],
-) -> Result<CubicSpline, super::error_utils::InterpolationError> {
+pub fn cubic_spline<T>(
+    pts: &[(T, T)],
+) -> Result<CubicSpline<T>, super::error_utils::InterpolationError>
+where
+    T: Real,
+{
     let mut pts_clone = pts.to_owned();
     CubicSpline::new(&mut pts_clone)
 }
@@ -1,10 +1,14 @@
 use super::error_utils::InterpolationError;
+use crate::common::float::Real;
 use std::cmp::Ordering;
-pub struct LinearSpline {
-    segments: Vec<(f64, f64, f64, f64)>, // (x1, y1, x2, y2) for each segment
+pub struct LinearSpline<T = f64> {
+    segments: Vec<(T, T, T, T)>, // (x1, y1, x2, y2) for each segment
 }
 
-impl LinearSpline {
+impl<T> LinearSpline<T>
+where
+    T: Real,
+{
     /// # Constructor for `LinearSpline`
     ///
     /// Initializes a `LinearSpline` object by sorting the input points and creating segments.
@@ -20,7 +24,7 @@ impl LinearSpline {
     /// # Errors
     ///
     /// * `InterpolationError::DuplicateXValuesError` - Thrown when two points have the same x-value.
-    pub(crate) fn new(pts: &mut Vec<(f64, f64)>) -> Result<Self, InterpolationError> {
+    pub(crate) fn new(pts: &mut Vec<(T, T)>) -> Result<Self, InterpolationError> {
         pts.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
 
         let mut segments = Vec::new();
@@ -47,7 +51,7 @@ impl LinearSpline {
     ///
     /// # Returns
     ///
-    /// * `Some(f64)` - The value of the spline at `x` if `x` is within the domain of the spline.
+    /// * `Some(T)` - The value of the spline at `x` if `x` is within the domain of the spline.
     /// * `None` - If `x` is outside the domain of the spline.
     ///
     ///
@@ -61,7 +65,7 @@ impl LinearSpline {
     ///
     /// * The function does NOT panic but returns `None` if `x` is outside the domain.
     ///
-    pub fn eval(&self, x: f64) -> Option<f64> {
+    pub fn eval(&self, x: T) -> Option<T> {
         // Assuming self.segments is sorted by x1
         let idx = self.segments.binary_search_by(|&(x1, _, x2, _)| {
             if x < x1 {
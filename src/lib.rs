@@ -1,4 +1,13 @@
 pub mod optimize {
+    pub mod batch;
+    pub mod bracket;
+    pub mod complex_roots;
+    pub mod constrained;
+    pub mod least_squares;
+    pub mod minimize;
+    pub mod minimize_scalar;
+    pub mod nonlinear_system;
+    pub mod polynomial_roots;
     pub mod root_finding;
 }
 
@@ -14,8 +23,21 @@ pub mod interpolate {
 }
 
 pub mod integrate {
+    pub mod cubature;
+    pub mod double_exponential;
+    pub mod gauss;
     pub mod integrator;
+    pub mod monte_carlo;
     pub mod quad;
+    pub mod quasi_monte_carlo;
+    pub mod sampled;
+
+    pub use integrator::{integrate, IntegrationMethod, Integrator};
+}
+
+pub mod differentiate {
+    pub mod derivative;
+    pub mod multivariate;
 }
 
 pub mod arithmetic {
@@ -27,5 +49,9 @@ pub mod special {
 }
 
 pub mod common {
+    pub mod dual;
+    pub(crate) mod extrapolation;
+    pub mod float;
     pub mod functions;
+    pub(crate) mod random;
 }
\ No newline at end of file
@@ -0,0 +1,353 @@
+use core::fmt;
+use std::error::Error;
+
+use ndarray::Array1;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use super::root_finding::{AlgoMetrics, Brent, Newton, RootFindingError, Secant, DEFAULT_RTOL};
+
+/// Type of the derivative of a batched Newton solver that has not been given one.
+pub type NoParamDerivative = fn(f64, f64) -> f64;
+
+/// Outcome of the solve of a single element of a batch, one per variant of `RootFindingError`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootStatus {
+    Converged,
+    SignAgreement,
+    NonConvergence,
+    ZeroDerivative,
+    IdenticalInitialGuesses,
+    MissingDerivative,
+    UnacceptableTolerance,
+    IterationLimitExceeded,
+    EarlyTermination,
+}
+
+impl RootStatus {
+    pub fn is_converged(&self) -> bool {
+        *self == RootStatus::Converged
+    }
+}
+
+///Results of a batched solve, indexed like the parameters.
+/// ## Attributes
+/// - roots:Array1<f64>, the root of every element, or the last approximation made for elements
+///   that failed (NaN if the solver never started)
+/// - status:Array1<RootStatus>
+/// - func_evals:Array1<u32>
+/// - iter:Array1<usize>
+#[derive(Debug)]
+pub struct BatchMetrics {
+    pub roots: Array1<f64>,
+    pub status: Array1<RootStatus>,
+    pub func_evals: Array1<u32>,
+    pub iter: Array1<usize>,
+}
+
+impl BatchMetrics {
+    pub fn all_converged(&self) -> bool {
+        self.status.iter().all(RootStatus::is_converged)
+    }
+
+    pub fn converged_count(&self) -> usize {
+        self.status.iter().filter(|s| s.is_converged()).count()
+    }
+
+    fn from_results(results: Vec<Result<AlgoMetrics, RootFindingError>>) -> Self {
+        let n = results.len();
+        let mut batch_metrics = BatchMetrics {
+            roots: Array1::from_elem(n, f64::NAN),
+            status: Array1::from_elem(n, RootStatus::Converged),
+            func_evals: Array1::zeros(n),
+            iter: Array1::zeros(n),
+        };
+
+        for (i, result) in results.into_iter().enumerate() {
+            let (status, algo_metrics) = match result {
+                Ok(algo_metrics) => (RootStatus::Converged, Some(algo_metrics)),
+                Err(RootFindingError::SignAgreementError) => (RootStatus::SignAgreement, None),
+                Err(RootFindingError::IdenticalInitialGuessesError) => {
+                    (RootStatus::IdenticalInitialGuesses, None)
+                }
+                Err(RootFindingError::MissingDerivativeError) => {
+                    (RootStatus::MissingDerivative, None)
+                }
+                Err(RootFindingError::NonConvergenceError(m)) => {
+                    (RootStatus::NonConvergence, Some(m))
+                }
+                Err(RootFindingError::ZeroDerivativeError(m)) => {
+                    (RootStatus::ZeroDerivative, Some(m))
+                }
+                Err(RootFindingError::UnacceptableToleranceError(m)) => {
+                    (RootStatus::UnacceptableTolerance, Some(m))
+                }
+                Err(RootFindingError::IterationLimitExceededError(m)) => {
+                    (RootStatus::IterationLimitExceeded, Some(m))
+                }
+                Err(RootFindingError::EarlyTerminationError(m)) => {
+                    (RootStatus::EarlyTermination, Some(m))
+                }
+            };
+            batch_metrics.status[i] = status;
+            if let Some(algo_metrics) = algo_metrics {
+                batch_metrics.roots[i] = algo_metrics.est_x;
+                batch_metrics.func_evals[i] = algo_metrics.func_evals;
+                batch_metrics.iter[i] = algo_metrics.iter;
+            }
+        }
+        batch_metrics
+    }
+}
+
+impl fmt::Display for BatchMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "converged={}/{}, func_evals={}, roots={}",
+            self.converged_count(),
+            self.status.len(),
+            self.func_evals.sum(),
+            self.roots
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum BatchError {
+    DimensionMismatchError,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::DimensionMismatchError => {
+                write!(
+                    f,
+                    "The initial brackets or guesses do not match the number of parameters."
+                )
+            }
+        }
+    }
+}
+
+impl Error for BatchError {}
+
+/// Brent's Method applied to f(x, p) = 0 for every parameter p, either on a common bracket
+/// [a, b] or on a bracket per parameter. Every element is solved independently, so a failure
+/// only shows up in its status.
+pub struct BatchBrent<'a, F> {
+    f: F,
+    params: &'a Array1<f64>,
+    a: Array1<f64>,
+    b: Array1<f64>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+    expand_bracket: bool,
+}
+
+impl<'a, F> BatchBrent<'a, F>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    pub fn initialize(f: F, params: &'a Array1<f64>, a: f64, b: f64) -> Self {
+        Self {
+            f,
+            params,
+            a: Array1::from_elem(params.len(), a),
+            b: Array1::from_elem(params.len(), b),
+            tol: 1e-8,
+            rtol: DEFAULT_RTOL,
+            iter: 100,
+            expand_bracket: false,
+        }
+    }
+
+    pub fn brackets(mut self, a: Array1<f64>, b: Array1<f64>) -> Self {
+        self.a = a;
+        self.b = b;
+        self
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn expand_bracket(mut self, expand_bracket: bool) -> Self {
+        self.expand_bracket = expand_bracket;
+        self
+    }
+
+    pub fn run(self) -> Result<BatchMetrics, BatchError> {
+        self.check_dimensions()?;
+        let results = (0..self.params.len())
+            .map(|i| self.solve_element(i))
+            .collect();
+        Ok(BatchMetrics::from_results(results))
+    }
+
+    fn check_dimensions(&self) -> Result<(), BatchError> {
+        if self.a.len() != self.params.len() || self.b.len() != self.params.len() {
+            return Err(BatchError::DimensionMismatchError);
+        }
+        Ok(())
+    }
+
+    fn solve_element(&self, i: usize) -> Result<AlgoMetrics, RootFindingError> {
+        let p = self.params[i];
+        Brent::initialize(|x| (self.f)(x, p), self.a[i], self.b[i])
+            .tol(self.tol)
+            .rtol(self.rtol)
+            .iter(self.iter)
+            .expand_bracket(self.expand_bracket)
+            .run()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, F> BatchBrent<'a, F>
+where
+    F: Fn(f64, f64) -> f64 + Sync,
+{
+    /// Same as `run`, with the elements solved in parallel on the rayon thread pool.
+    pub fn run_parallel(self) -> Result<BatchMetrics, BatchError> {
+        self.check_dimensions()?;
+        let results = (0..self.params.len())
+            .into_par_iter()
+            .map(|i| self.solve_element(i))
+            .collect();
+        Ok(BatchMetrics::from_results(results))
+    }
+}
+
+/// Newton-Raphson Method applied to f(x, p) = 0 for every parameter p, either from a common
+/// initial guess or from a guess per parameter. Without a derivative fp(x, p) the Secant Method
+/// is used instead.
+pub struct BatchNewton<'a, F, FP = NoParamDerivative> {
+    f: F,
+    fp: Option<FP>,
+    params: &'a Array1<f64>,
+    x0: Array1<f64>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<'a, F> BatchNewton<'a, F>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    pub fn initialize(f: F, params: &'a Array1<f64>, x0: f64) -> Self {
+        Self {
+            f,
+            fp: None,
+            params,
+            x0: Array1::from_elem(params.len(), x0),
+            tol: 1e-8,
+            rtol: DEFAULT_RTOL,
+            iter: 100,
+        }
+    }
+}
+
+impl<'a, F, FP> BatchNewton<'a, F, FP>
+where
+    F: Fn(f64, f64) -> f64,
+    FP: Fn(f64, f64) -> f64,
+{
+    pub fn fp<G>(self, fp: G) -> BatchNewton<'a, F, G>
+    where
+        G: Fn(f64, f64) -> f64,
+    {
+        BatchNewton {
+            f: self.f,
+            fp: Some(fp),
+            params: self.params,
+            x0: self.x0,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+        }
+    }
+
+    pub fn guesses(mut self, x0: Array1<f64>) -> Self {
+        self.x0 = x0;
+        self
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(self) -> Result<BatchMetrics, BatchError> {
+        self.check_dimensions()?;
+        let results = (0..self.params.len())
+            .map(|i| self.solve_element(i))
+            .collect();
+        Ok(BatchMetrics::from_results(results))
+    }
+
+    fn check_dimensions(&self) -> Result<(), BatchError> {
+        if self.x0.len() != self.params.len() {
+            return Err(BatchError::DimensionMismatchError);
+        }
+        Ok(())
+    }
+
+    fn solve_element(&self, i: usize) -> Result<AlgoMetrics, RootFindingError> {
+        let p = self.params[i];
+        match self.fp.as_ref() {
+            Some(fp) => Newton::initialize(|x| (self.f)(x, p), self.x0[i])
+                .fp(|x| fp(x, p))
+                .tol(self.tol)
+                .rtol(self.rtol)
+                .iter(self.iter)
+                .run(),
+            None => Secant::initialize(|x| (self.f)(x, p), self.x0[i])
+                .tol(self.tol)
+                .rtol(self.rtol)
+                .iter(self.iter)
+                .run(),
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, F, FP> BatchNewton<'a, F, FP>
+where
+    F: Fn(f64, f64) -> f64 + Sync,
+    FP: Fn(f64, f64) -> f64 + Sync,
+{
+    /// Same as `run`, with the elements solved in parallel on the rayon thread pool.
+    pub fn run_parallel(self) -> Result<BatchMetrics, BatchError> {
+        self.check_dimensions()?;
+        let results = (0..self.params.len())
+            .into_par_iter()
+            .map(|i| self.solve_element(i))
+            .collect();
+        Ok(BatchMetrics::from_results(results))
+    }
+}
@@ -0,0 +1,184 @@
+use core::fmt;
+
+use super::root_finding::RootFindingError;
+use crate::common::float::Real;
+
+pub(crate) static DEFAULT_EXPANSION_FACTOR: f64 = 1.6;
+pub(crate) static DEFAULT_EXPANSION_TRIES: usize = 50;
+
+///An interval [a, b] on which f changes sign.
+/// ## Attributes
+/// - a, b:T
+/// - f_a, f_b:T
+/// - func_evals:u32, evaluations of f made to find the interval
+#[derive(Debug, Clone, Copy)]
+pub struct Bracket<T = f64> {
+    pub a: T,
+    pub b: T,
+    pub f_a: T,
+    pub f_b: T,
+    pub func_evals: u32,
+}
+
+impl<T> fmt::Display for Bracket<T>
+where
+    T: Real,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "a={}, b={}, f_a={}, f_b={}, func_evals={}",
+            self.a, self.b, self.f_a, self.f_b, self.func_evals
+        )
+    }
+}
+
+/// Geometric outward search for an interval on which f changes sign, starting either from an
+/// interval [a, b] or from a single guess. At every step the end point with the smaller
+/// |f| is moved away from the other by `factor` times the width of the interval.
+pub struct BracketExpander<F, T = f64> {
+    f: F,
+    a: T,
+    b: T,
+    factor: T,
+    iter: usize,
+}
+
+impl<F> BracketExpander<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self::new(f, a, b)
+    }
+
+    /// Starts from the interval [x0 - h, x0 + h] with h = 0.1 max(|x0|, 1).
+    pub fn from_guess(f: F, x0: f64) -> Self {
+        Self::new_from_guess(f, x0)
+    }
+}
+
+impl<F, T> BracketExpander<F, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    pub fn new(f: F, a: T, b: T) -> Self {
+        Self {
+            f,
+            a,
+            b,
+            factor: T::constant(DEFAULT_EXPANSION_FACTOR),
+            iter: DEFAULT_EXPANSION_TRIES,
+        }
+    }
+
+    pub fn new_from_guess(f: F, x0: T) -> Self {
+        let h = T::constant(0.1) * x0.abs().max(T::one());
+        Self::new(f, x0 - h, x0 + h)
+    }
+
+    pub fn factor(mut self, factor: T) -> Self {
+        self.factor = factor;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(mut self) -> Result<Bracket<T>, RootFindingError<T>> {
+        expand_bracket(&mut self.f, self.a, self.b, self.factor, self.iter)
+    }
+}
+
+pub(crate) fn expand_bracket<F, T>(
+    f: &mut F,
+    a: T,
+    b: T,
+    factor: T,
+    iter: usize,
+) -> Result<Bracket<T>, RootFindingError<T>>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    let zero = T::zero();
+    if a == b {
+        return Err(RootFindingError::IdenticalInitialGuessesError);
+    }
+
+    let (mut a, mut b) = (a, b);
+    let mut f_a = f(a);
+    let mut f_b = f(b);
+    let mut func_evals = 2;
+
+    for _ in 0..iter {
+        if f_a.is_nan() || f_b.is_nan() {
+            break;
+        }
+        if f_a == zero || f_b == zero || f_a.signum() != f_b.signum() {
+            return Ok(Bracket {
+                a,
+                b,
+                f_a,
+                f_b,
+                func_evals,
+            });
+        }
+
+        if f_a.abs() < f_b.abs() {
+            a = a + factor * (a - b);
+            f_a = f(a);
+        } else {
+            b = b + factor * (b - a);
+            f_b = f(b);
+        }
+        func_evals += 1;
+    }
+
+    if f_a == zero || f_b == zero || f_a.signum() != f_b.signum() {
+        return Ok(Bracket {
+            a,
+            b,
+            f_a,
+            f_b,
+            func_evals,
+        });
+    }
+    Err(RootFindingError::SignAgreementError)
+}
+
+/// Splits [a, b] into n equal subintervals and returns the ones on which f changes sign,
+/// in increasing order. A root that falls exactly on a grid point is reported once, by the
+/// subinterval that starts there (or ends there, for b).
+/// Roots closer together than the width of a subinterval may be missed.
+pub fn find_sign_changes<F>(mut f: F, a: f64, b: f64, n: usize) -> Vec<(f64, f64)>
+where
+    F: FnMut(f64) -> f64,
+{
+    let mut brackets = Vec::new();
+    if n == 0 || a == b {
+        return brackets;
+    }
+
+    let (a, b) = (a.min(b), a.max(b));
+    let h = (b - a) / n as f64;
+    let mut x_lo = a;
+    let mut f_lo = f(x_lo);
+
+    for i in 1..=n {
+        let x_hi = if i == n { b } else { a + i as f64 * h };
+        let f_hi = f(x_hi);
+
+        let last_root = i == n && f_hi == 0.0;
+        if f_lo == 0.0 || last_root || (f_hi != 0.0 && f_lo.signum() != f_hi.signum()) {
+            brackets.push((x_lo, x_hi));
+        }
+
+        x_lo = x_hi;
+        f_lo = f_hi;
+    }
+    brackets
+}
@@ -0,0 +1,428 @@
+use num_complex::Complex64;
+
+use super::root_finding::{
+    AlgoMetrics, RootFinder, RootFindingError, DEFAULT_RTOL, MAX_ITER, SUCCESS_CONVERGENCE,
+};
+
+/// Derivative of a `ComplexNewton` solver that has not been given one.
+pub type NoComplexDerivative = fn(Complex64) -> Complex64;
+
+/// Newton's Method for analytic functions of a complex variable.
+/// If the derivative is not given, the complex Secant Method is used instead, started from
+/// x0 and x1 or from a small perturbation of x0 if x1 is not given.
+pub struct ComplexNewton<F, FP = NoComplexDerivative> {
+    f: F,
+    fp: Option<FP>,
+    x0: Complex64,
+    x1: Option<Complex64>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> ComplexNewton<F>
+where
+    F: FnMut(Complex64) -> Complex64,
+{
+    pub fn initialize(f: F, x0: Complex64) -> Self {
+        Self {
+            f,
+            fp: None,
+            x0,
+            x1: None,
+            tol: 1e-10,
+            rtol: DEFAULT_RTOL,
+            iter: 100,
+        }
+    }
+}
+
+impl<F, FP> ComplexNewton<F, FP>
+where
+    F: FnMut(Complex64) -> Complex64,
+    FP: FnMut(Complex64) -> Complex64,
+{
+    pub fn x1(mut self, x1: Complex64) -> Self {
+        self.x1 = Some(x1);
+        self
+    }
+
+    pub fn fp<G>(self, fp: G) -> ComplexNewton<F, G>
+    where
+        G: FnMut(Complex64) -> Complex64,
+    {
+        ComplexNewton {
+            f: self.f,
+            fp: Some(fp),
+            x0: self.x0,
+            x1: self.x1,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+        }
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(mut self) -> Result<AlgoMetrics<Complex64>, RootFindingError<Complex64>> {
+        self.solve()
+    }
+}
+
+impl<F, FP> RootFinder<Complex64, f64> for ComplexNewton<F, FP>
+where
+    F: FnMut(Complex64) -> Complex64,
+    FP: FnMut(Complex64) -> Complex64,
+{
+    fn solve(&mut self) -> Result<AlgoMetrics<Complex64>, RootFindingError<Complex64>> {
+        match self.fp.as_mut() {
+            Some(f_prime) => complex_newton(
+                &mut self.f,
+                f_prime,
+                self.x0,
+                self.tol,
+                self.rtol,
+                self.iter,
+            ),
+            None => complex_secant(
+                &mut self.f,
+                self.x0,
+                self.x1,
+                self.tol,
+                self.rtol,
+                self.iter,
+            ),
+        }
+    }
+
+    fn set_tol(&mut self, tol: f64) {
+        self.tol = tol;
+    }
+
+    fn set_rtol(&mut self, rtol: f64) {
+        self.rtol = rtol;
+    }
+
+    fn set_iter(&mut self, iter: usize) {
+        self.iter = iter;
+    }
+}
+
+/// Muller's Method, which fits a parabola through the last three iterates and moves to its
+/// root closest to the newest one. Complex roots are reached even from real starting points.
+/// If x1 and x2 are not given, x0 - h and x0 + h with h = 0.1 max(|x0|, 1) are used.
+pub struct Muller<F> {
+    f: F,
+    x0: Complex64,
+    x1: Option<Complex64>,
+    x2: Option<Complex64>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> Muller<F>
+where
+    F: FnMut(Complex64) -> Complex64,
+{
+    pub fn initialize(f: F, x0: Complex64) -> Self {
+        Self {
+            f,
+            x0,
+            x1: None,
+            x2: None,
+            tol: 1e-10,
+            rtol: DEFAULT_RTOL,
+            iter: 100,
+        }
+    }
+
+    pub fn x1(mut self, x1: Complex64) -> Self {
+        self.x1 = Some(x1);
+        self
+    }
+
+    pub fn x2(mut self, x2: Complex64) -> Self {
+        self.x2 = Some(x2);
+        self
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(mut self) -> Result<AlgoMetrics<Complex64>, RootFindingError<Complex64>> {
+        self.solve()
+    }
+}
+
+impl<F> RootFinder<Complex64, f64> for Muller<F>
+where
+    F: FnMut(Complex64) -> Complex64,
+{
+    fn solve(&mut self) -> Result<AlgoMetrics<Complex64>, RootFindingError<Complex64>> {
+        let mut algo_metrics = complex_metrics();
+        check_tolerances(self.tol, self.rtol, &mut algo_metrics)?;
+
+        let h = 0.1 * self.x0.norm().max(1.0);
+        let mut p0 = self.x0;
+        let mut p1 = self.x1.unwrap_or(self.x0 - h);
+        let mut p2 = self.x2.unwrap_or(self.x0 + h);
+        if p0 == p1 || p1 == p2 || p0 == p2 {
+            return Err(RootFindingError::IdenticalInitialGuessesError);
+        }
+
+        let f = &mut self.f;
+        let mut f_p0 = f(p0);
+        let mut f_p1 = f(p1);
+        let mut f_p2 = f(p2);
+        algo_metrics.func_evals += 3;
+
+        for i in 0..self.iter {
+            if f_p2 == Complex64::new(0.0, 0.0) {
+                algo_metrics.iter = i;
+                algo_metrics.est_x = p2;
+                algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+                return Ok(algo_metrics);
+            }
+
+            // Divided differences of the parabola through the three points
+            let h1 = p1 - p0;
+            let h2 = p2 - p1;
+            let d1 = (f_p1 - f_p0) / h1;
+            let d2 = (f_p2 - f_p1) / h2;
+            let a = (d2 - d1) / (h2 + h1);
+            let b = d2 + h2 * a;
+            let discriminant = (b * b - 4.0 * f_p2 * a).sqrt();
+
+            // Pick the sign that gives the larger denominator, i.e. the root closest to p2
+            let denominator = if (b + discriminant).norm() >= (b - discriminant).norm() {
+                b + discriminant
+            } else {
+                b - discriminant
+            };
+            if denominator.norm() == 0.0 || !denominator.is_finite() {
+                algo_metrics.iter = i;
+                algo_metrics.est_x = p2;
+                algo_metrics.msg.push_str(
+                    "Cannot apply Muller step because denominator became zero during computation.",
+                );
+                return Err(RootFindingError::NonConvergenceError(algo_metrics));
+            }
+            let step = -2.0 * f_p2 / denominator;
+            let p = p2 + step;
+
+            // Check for convergence
+            if complex_precision_equals(p, p2, self.tol, self.rtol) {
+                algo_metrics.iter = i;
+                algo_metrics.est_x = p;
+                algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+                return Ok(algo_metrics);
+            }
+
+            p0 = p1;
+            f_p0 = f_p1;
+            p1 = p2;
+            f_p1 = f_p2;
+            p2 = p;
+            f_p2 = f(p2);
+            algo_metrics.func_evals += 1;
+        }
+
+        algo_metrics.est_x = p2;
+        algo_metrics.iter = self.iter;
+        algo_metrics.msg.push_str(MAX_ITER);
+        Err(RootFindingError::IterationLimitExceededError(algo_metrics))
+    }
+
+    fn set_tol(&mut self, tol: f64) {
+        self.tol = tol;
+    }
+
+    fn set_rtol(&mut self, rtol: f64) {
+        self.rtol = rtol;
+    }
+
+    fn set_iter(&mut self, iter: usize) {
+        self.iter = iter;
+    }
+}
+
+/// Same test as `precision_equals`, with the modulus in place of the absolute value.
+pub fn complex_precision_equals(x1: Complex64, x2: Complex64, tol: f64, rtol: f64) -> bool {
+    (x1 - x2).norm() <= tol + rtol * x2.norm()
+}
+
+fn complex_metrics() -> AlgoMetrics<Complex64> {
+    AlgoMetrics {
+        msg: String::new(),
+        func_evals: 0,
+        iter: 0,
+        est_x: Complex64::new(f64::NAN, f64::NAN),
+        history: Vec::new(),
+    }
+}
+
+fn check_tolerances(
+    tol: f64,
+    rtol: f64,
+    algo_metrics: &mut AlgoMetrics<Complex64>,
+) -> Result<(), RootFindingError<Complex64>> {
+    if tol <= 0.0 {
+        algo_metrics
+            .msg
+            .push_str("Value of tol is either negative or zero.");
+        return Err(RootFindingError::UnacceptableToleranceError(
+            std::mem::replace(algo_metrics, complex_metrics()),
+        ));
+    }
+
+    if rtol < DEFAULT_RTOL {
+        algo_metrics
+            .msg
+            .push_str("Value of rtol is either negative or extremely small.");
+        return Err(RootFindingError::UnacceptableToleranceError(
+            std::mem::replace(algo_metrics, complex_metrics()),
+        ));
+    }
+    Ok(())
+}
+
+fn complex_newton<F, FP>(
+    f: &mut F,
+    f_prime: &mut FP,
+    x0: Complex64,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+) -> Result<AlgoMetrics<Complex64>, RootFindingError<Complex64>>
+where
+    F: FnMut(Complex64) -> Complex64,
+    FP: FnMut(Complex64) -> Complex64,
+{
+    let mut algo_metrics = complex_metrics();
+    check_tolerances(tol, rtol, &mut algo_metrics)?;
+    let zero = Complex64::new(0.0, 0.0);
+
+    let mut x = x0;
+    for i in 0..iter {
+        let f_x = f(x);
+        let f_prime_x = f_prime(x);
+        algo_metrics.func_evals += 2;
+
+        // If root has been found, terminate
+        if f_x == zero {
+            algo_metrics.iter = i;
+            algo_metrics.est_x = x;
+            algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+            return Ok(algo_metrics);
+        }
+
+        if f_prime_x == zero {
+            algo_metrics.iter = i;
+            algo_metrics.est_x = x;
+            return Err(RootFindingError::ZeroDerivativeError(algo_metrics));
+        }
+
+        let x_n = x - f_x / f_prime_x;
+
+        // Check for convergence
+        if complex_precision_equals(x, x_n, tol, rtol) {
+            algo_metrics.iter = i;
+            algo_metrics.est_x = x_n;
+            algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+            return Ok(algo_metrics);
+        }
+        x = x_n;
+    }
+
+    algo_metrics.est_x = x;
+    algo_metrics.iter = iter;
+    algo_metrics.msg.push_str(MAX_ITER);
+    Err(RootFindingError::IterationLimitExceededError(algo_metrics))
+}
+
+fn complex_secant<F>(
+    f: &mut F,
+    x0: Complex64,
+    x1: Option<Complex64>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+) -> Result<AlgoMetrics<Complex64>, RootFindingError<Complex64>>
+where
+    F: FnMut(Complex64) -> Complex64,
+{
+    let mut algo_metrics = complex_metrics();
+    check_tolerances(tol, rtol, &mut algo_metrics)?;
+
+    let mut p0 = x0;
+    let mut p1 = match x1 {
+        Some(x1) => {
+            if x1 == x0 {
+                return Err(RootFindingError::IdenticalInitialGuessesError);
+            }
+            x1
+        }
+        None => x0 * (1.0 + 1e-4) + 1e-4,
+    };
+
+    let mut f_p0 = f(p0);
+    let mut f_p1 = f(p1);
+    algo_metrics.func_evals += 2;
+
+    for i in 0..iter {
+        // If function values are the same, Secant cannot continue because denominator is zero
+        if f_p0 == f_p1 {
+            algo_metrics.iter = i;
+            algo_metrics.est_x = p1;
+            algo_metrics.msg.push_str(
+                "Cannot apply secant step because denominator became zero during computation.",
+            );
+            return Err(RootFindingError::NonConvergenceError(algo_metrics));
+        }
+        let p = p1 - f_p1 * (p1 - p0) / (f_p1 - f_p0);
+
+        // Check for convergence
+        if complex_precision_equals(p, p1, tol, rtol) {
+            algo_metrics.iter = i;
+            algo_metrics.est_x = p;
+            algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+            return Ok(algo_metrics);
+        }
+        p0 = p1;
+        f_p0 = f_p1;
+        p1 = p;
+        f_p1 = f(p1);
+        algo_metrics.func_evals += 1;
+    }
+
+    algo_metrics.est_x = p1;
+    algo_metrics.iter = iter;
+    algo_metrics.msg.push_str(MAX_ITER);
+    Err(RootFindingError::IterationLimitExceededError(algo_metrics))
+}
@@ -0,0 +1,498 @@
+use core::fmt;
+use std::collections::VecDeque;
+use std::error::Error;
+
+use ndarray::{s, Array1, Array2};
+
+use super::minimize::{check_tolerances, Evaluator, MinimizeError, MinimizeMetrics, NoGradient};
+use super::root_finding::{MAX_ITER, SUCCESS_CONVERGENCE};
+use crate::common::functions::max_norm;
+
+// Parameters of the spectral step length and of the nonmonotone line search
+static MIN_STEP: f64 = 1e-10;
+static MAX_STEP: f64 = 1e10;
+static SUFFICIENT_DECREASE: f64 = 1e-4;
+static NONMONOTONE_MEMORY: usize = 10;
+static LINE_SEARCH_ITER: usize = 50;
+
+/// Spectral Projected Gradient Method for minimization subject to the bounds lower <= x <= upper.
+/// Bounds may be infinite, and every iterate is projected onto the box. Without a gradient, the
+/// finite differences are one-sided at the bounds, so f is only evaluated inside the box.
+pub struct ProjectedGradient<F, G = NoGradient> {
+    f: F,
+    gradient: Option<G>,
+    x0: Array1<f64>,
+    lower: Option<Array1<f64>>,
+    upper: Option<Array1<f64>>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> ProjectedGradient<F>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+{
+    pub fn initialize(f: F, x0: Array1<f64>) -> Self {
+        Self {
+            f,
+            gradient: None,
+            x0,
+            lower: None,
+            upper: None,
+            tol: 1e-6,
+            rtol: 1e-15,
+            iter: 5000,
+        }
+    }
+}
+
+impl<F, G> ProjectedGradient<F, G>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+    G: FnMut(&Array1<f64>) -> Array1<f64>,
+{
+    pub fn gradient<H>(self, gradient: H) -> ProjectedGradient<F, H>
+    where
+        H: FnMut(&Array1<f64>) -> Array1<f64>,
+    {
+        ProjectedGradient {
+            f: self.f,
+            gradient: Some(gradient),
+            x0: self.x0,
+            lower: self.lower,
+            upper: self.upper,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+        }
+    }
+
+    pub fn bounds(mut self, lower: Array1<f64>, upper: Array1<f64>) -> Self {
+        self.lower = Some(lower);
+        self.upper = Some(upper);
+        self
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(self) -> Result<MinimizeMetrics, MinimizeError> {
+        check_tolerances(self.tol, self.rtol)?;
+        let n = self.x0.len();
+        let lower = self
+            .lower
+            .unwrap_or_else(|| Array1::from_elem(n, f64::NEG_INFINITY));
+        let upper = self
+            .upper
+            .unwrap_or_else(|| Array1::from_elem(n, f64::INFINITY));
+        if lower.len() != n
+            || upper.len() != n
+            || lower
+                .iter()
+                .zip(&upper)
+                .any(|(l, u)| l > u || l.is_nan() || u.is_nan())
+        {
+            return Err(MinimizeError::InvalidBoundsError);
+        }
+        let project = |x: Array1<f64>| -> Array1<f64> {
+            let mut x = x;
+            x.zip_mut_with(&lower, |x, l| *x = x.max(*l));
+            x.zip_mut_with(&upper, |x, u| *x = x.min(*u));
+            x
+        };
+
+        let mut evaluator = Evaluator::new(self.f, self.gradient);
+        let mut x = project(self.x0);
+        let mut f_x = evaluator.eval(&x);
+        let mut g_x = evaluator.gradient_in_box(&x, f_x, &lower, &upper)?;
+        let mut history: VecDeque<f64> = VecDeque::from([f_x]);
+
+        let mut projected_gradient = project(&x - &g_x) - &x;
+        let mut step = (1.0 / max_norm(&projected_gradient)).clamp(MIN_STEP, MAX_STEP);
+
+        for i in 0..self.iter {
+            let pg_norm = max_norm(&projected_gradient);
+            if pg_norm <= self.tol {
+                return Ok(evaluator.success(x, f_x, pg_norm, i));
+            }
+
+            // Spectral step projected onto the box, followed by a nonmonotone backtracking
+            let d = project(&x - &(step * &g_x)) - &x;
+            let slope = g_x.dot(&d);
+            let f_max = history.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+
+            let mut lambda = 1.0;
+            let mut accepted = None;
+            for _ in 0..LINE_SEARCH_ITER {
+                let x_n = &x + &(lambda * &d);
+                let f_x_n = evaluator.eval(&x_n);
+                if f_x_n <= f_max + SUFFICIENT_DECREASE * lambda * slope {
+                    accepted = Some((x_n, f_x_n));
+                    break;
+                }
+
+                // Minimizer of the quadratic interpolant, safeguarded by halving
+                let lambda_q = -0.5 * lambda * lambda * slope / (f_x_n - f_x - lambda * slope);
+                lambda = if lambda_q >= 0.1 && lambda_q <= 0.9 * lambda {
+                    lambda_q
+                } else {
+                    0.5 * lambda
+                };
+            }
+            let (x_n, f_x_n) = match accepted {
+                Some(point) => point,
+                None => {
+                    return Err(MinimizeError::LineSearchError(
+                        evaluator.failure(x, f_x, pg_norm, i),
+                    ))
+                }
+            };
+
+            let g_x_n = evaluator.gradient_in_box(&x_n, f_x_n, &lower, &upper)?;
+            let s = &x_n - &x;
+            let y = &g_x_n - &g_x;
+            let sy = s.dot(&y);
+            step = if sy <= 0.0 {
+                MAX_STEP
+            } else {
+                (s.dot(&s) / sy).clamp(MIN_STEP, MAX_STEP)
+            };
+
+            let converged_step = max_norm(&s) <= self.rtol * max_norm(&x_n);
+            x = x_n;
+            f_x = f_x_n;
+            g_x = g_x_n;
+            projected_gradient = project(&x - &g_x) - &x;
+
+            if history.len() == NONMONOTONE_MEMORY {
+                history.pop_front();
+            }
+            history.push_back(f_x);
+
+            if converged_step {
+                return Ok(evaluator.success(x, f_x, max_norm(&projected_gradient), i + 1));
+            }
+        }
+
+        let pg_norm = max_norm(&projected_gradient);
+        if pg_norm <= self.tol {
+            return Ok(evaluator.success(x, f_x, pg_norm, self.iter));
+        }
+        Err(MinimizeError::IterationLimitExceededError(
+            evaluator.failure(x, f_x, pg_norm, self.iter),
+        ))
+    }
+}
+
+///Output of the simplex method.
+/// ## Attributes
+/// - msg:String
+/// - x:Array1<f64>, the optimal point
+/// - objective:f64, the value of c^T x at the optimal point
+/// - iter:usize, pivots of both phases
+#[derive(Debug)]
+pub struct LinearProgramMetrics {
+    pub msg: String,
+    pub x: Array1<f64>,
+    pub objective: f64,
+    pub iter: usize,
+}
+
+impl fmt::Display for LinearProgramMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}iter={}, objective={}, x={}",
+            self.msg, self.iter, self.objective, self.x
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum LinearProgramError {
+    DimensionMismatchError,
+    InfeasibleError,
+    UnboundedError,
+    IterationLimitExceededError(LinearProgramMetrics),
+}
+
+impl fmt::Display for LinearProgramError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinearProgramError::DimensionMismatchError => {
+                write!(
+                    f,
+                    "The constraint matrices must have as many columns as c has entries and as many rows as their right hand sides."
+                )
+            }
+            LinearProgramError::InfeasibleError => {
+                write!(f, "No point satisfies all the constraints.")
+            }
+            LinearProgramError::UnboundedError => {
+                write!(f, "The objective is unbounded below on the feasible set.")
+            }
+            LinearProgramError::IterationLimitExceededError(metrics) => {
+                write!(f, "Maximum number of iterations reached.\n{}", metrics)
+            }
+        }
+    }
+}
+
+impl Error for LinearProgramError {}
+
+/// Two-phase Simplex Method for the linear program
+///
+/// minimize c^T x subject to A_ub x <= b_ub, A_eq x = b_eq and x >= 0,
+///
+/// using Bland's rule so that degenerate problems do not cycle.
+pub struct Simplex {
+    c: Array1<f64>,
+    a_ub: Option<(Array2<f64>, Array1<f64>)>,
+    a_eq: Option<(Array2<f64>, Array1<f64>)>,
+    tol: f64,
+    iter: usize,
+}
+
+impl Simplex {
+    pub fn initialize(c: Array1<f64>) -> Self {
+        Self {
+            c,
+            a_ub: None,
+            a_eq: None,
+            tol: 1e-9,
+            iter: 10000,
+        }
+    }
+
+    pub fn inequalities(mut self, a_ub: Array2<f64>, b_ub: Array1<f64>) -> Self {
+        self.a_ub = Some((a_ub, b_ub));
+        self
+    }
+
+    pub fn equalities(mut self, a_eq: Array2<f64>, b_eq: Array1<f64>) -> Self {
+        self.a_eq = Some((a_eq, b_eq));
+        self
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(self) -> Result<LinearProgramMetrics, LinearProgramError> {
+        let n = self.c.len();
+        let (a_ub, b_ub) = self
+            .a_ub
+            .unwrap_or_else(|| (Array2::zeros((0, n)), Array1::zeros(0)));
+        let (a_eq, b_eq) = self
+            .a_eq
+            .unwrap_or_else(|| (Array2::zeros((0, n)), Array1::zeros(0)));
+        if a_ub.ncols() != n
+            || a_eq.ncols() != n
+            || a_ub.nrows() != b_ub.len()
+            || a_eq.nrows() != b_eq.len()
+        {
+            return Err(LinearProgramError::DimensionMismatchError);
+        }
+
+        let m_ub = a_ub.nrows();
+        let m = m_ub + a_eq.nrows();
+
+        // Columns: x, one slack per inequality, one artificial per row, right hand side
+        let artificial = n + m_ub;
+        let rhs = artificial + m;
+        let mut tableau = Array2::<f64>::zeros((m + 1, rhs + 1));
+        tableau.slice_mut(s![..m_ub, ..n]).assign(&a_ub);
+        tableau.slice_mut(s![m_ub..m, ..n]).assign(&a_eq);
+        for r in 0..m_ub {
+            tableau[[r, n + r]] = 1.0;
+        }
+        for r in 0..m {
+            tableau[[r, rhs]] = if r < m_ub { b_ub[r] } else { b_eq[r - m_ub] };
+        }
+
+        // Make the right hand sides nonnegative and start from the artificial basis,
+        // or from the slack where it already has a positive coefficient
+        let mut basis = vec![0; m];
+        for r in 0..m {
+            if tableau[[r, rhs]] < 0.0 {
+                tableau.row_mut(r).mapv_inplace(|v| -v);
+            }
+            if r < m_ub && tableau[[r, n + r]] > 0.0 {
+                basis[r] = n + r;
+            } else {
+                tableau[[r, artificial + r]] = 1.0;
+                basis[r] = artificial + r;
+            }
+        }
+
+        let mut simplex_tableau = SimplexTableau {
+            tableau,
+            variables: n,
+            basis,
+            tol: self.tol,
+            pivots: 0,
+        };
+
+        // Phase 1: minimize the sum of the artificial variables
+        let mut phase_one = Array1::<f64>::zeros(rhs);
+        phase_one.slice_mut(s![artificial..]).fill(1.0);
+        simplex_tableau.set_objective(&phase_one);
+        if !simplex_tableau.solve(artificial + m, self.iter)? {
+            return Err(LinearProgramError::UnboundedError);
+        }
+        if -simplex_tableau.tableau[[m, rhs]]
+            > self.tol * (1.0 + max_norm(&b_ub).max(max_norm(&b_eq)))
+        {
+            return Err(LinearProgramError::InfeasibleError);
+        }
+        simplex_tableau.drive_out_artificials(artificial);
+
+        // Phase 2: minimize c^T x without the artificial columns
+        let mut costs = Array1::<f64>::zeros(rhs);
+        costs.slice_mut(s![..n]).assign(&self.c);
+        simplex_tableau.set_objective(&costs);
+        if !simplex_tableau.solve(artificial, self.iter)? {
+            return Err(LinearProgramError::UnboundedError);
+        }
+
+        Ok(simplex_tableau.metrics(SUCCESS_CONVERGENCE))
+    }
+}
+
+// Dense simplex tableau whose last row holds the reduced costs and the negated objective
+struct SimplexTableau {
+    tableau: Array2<f64>,
+    variables: usize,
+    basis: Vec<usize>,
+    tol: f64,
+    pivots: usize,
+}
+
+impl SimplexTableau {
+    fn set_objective(&mut self, costs: &Array1<f64>) {
+        let m = self.basis.len();
+        let rhs = self.tableau.ncols() - 1;
+        for j in 0..rhs {
+            self.tableau[[m, j]] = costs[j];
+        }
+        self.tableau[[m, rhs]] = 0.0;
+
+        // Eliminate the costs of the basic variables
+        for r in 0..m {
+            let cost = costs[self.basis[r]];
+            if cost != 0.0 {
+                let row = self.tableau.row(r).to_owned();
+                self.tableau.row_mut(m).scaled_add(-cost, &row);
+            }
+        }
+    }
+
+    // Pivots with Bland's rule over the first `columns` columns until optimality.
+    // Returns false if the objective is unbounded.
+    fn solve(&mut self, columns: usize, iter: usize) -> Result<bool, LinearProgramError> {
+        let m = self.basis.len();
+        let rhs = self.tableau.ncols() - 1;
+
+        loop {
+            let entering = match (0..columns).find(|&j| self.tableau[[m, j]] < -self.tol) {
+                Some(j) => j,
+                None => return Ok(true),
+            };
+
+            let mut leaving: Option<usize> = None;
+            let mut best_ratio = f64::INFINITY;
+            for r in 0..m {
+                let a = self.tableau[[r, entering]];
+                if a > self.tol {
+                    let ratio = self.tableau[[r, rhs]] / a;
+                    let better = match leaving {
+                        None => true,
+                        Some(l) => {
+                            ratio < best_ratio - self.tol
+                                || (ratio <= best_ratio + self.tol && self.basis[r] < self.basis[l])
+                        }
+                    };
+                    if better {
+                        leaving = Some(r);
+                        best_ratio = ratio;
+                    }
+                }
+            }
+            let leaving = match leaving {
+                Some(r) => r,
+                None => return Ok(false),
+            };
+
+            if self.pivots == iter {
+                return Err(LinearProgramError::IterationLimitExceededError(
+                    self.metrics(MAX_ITER),
+                ));
+            }
+            self.pivot(leaving, entering);
+        }
+    }
+
+    fn pivot(&mut self, r: usize, j: usize) {
+        let pivot = self.tableau[[r, j]];
+        self.tableau.row_mut(r).mapv_inplace(|v| v / pivot);
+        let pivot_row = self.tableau.row(r).to_owned();
+        for k in 0..self.tableau.nrows() {
+            if k != r {
+                let factor = self.tableau[[k, j]];
+                if factor != 0.0 {
+                    self.tableau.row_mut(k).scaled_add(-factor, &pivot_row);
+                }
+            }
+        }
+        self.basis[r] = j;
+        self.pivots += 1;
+    }
+
+    // Replaces artificial variables left in the basis at zero level by original or slack
+    // variables; rows where this is impossible are redundant constraints
+    fn drive_out_artificials(&mut self, artificial: usize) {
+        for r in 0..self.basis.len() {
+            if self.basis[r] >= artificial {
+                if let Some(j) = (0..artificial).find(|&j| self.tableau[[r, j]].abs() > self.tol) {
+                    self.pivot(r, j);
+                }
+            }
+        }
+    }
+
+    fn metrics(&self, msg: &str) -> LinearProgramMetrics {
+        let m = self.basis.len();
+        let rhs = self.tableau.ncols() - 1;
+        let mut x = Array1::zeros(self.variables);
+        for (r, &j) in self.basis.iter().enumerate() {
+            if j < self.variables {
+                x[j] = self.tableau[[r, rhs]];
+            }
+        }
+        LinearProgramMetrics {
+            msg: String::from(msg),
+            x,
+            objective: -self.tableau[[m, rhs]],
+            iter: self.pivots,
+        }
+    }
+}
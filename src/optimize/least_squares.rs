@@ -0,0 +1,462 @@
+use core::fmt;
+use std::error::Error;
+
+use ndarray::{Array1, Array2};
+use ndarray_linalg::{Inverse, Solve};
+
+use super::nonlinear_system::NoJacobian;
+use super::root_finding::{MACH_EPS, MAX_ITER, SUCCESS_CONVERGENCE};
+use crate::common::functions::max_norm;
+
+// Initial damping relative to the largest diagonal entry of J^T J, and its update factor
+static INITIAL_DAMPING: f64 = 1e-3;
+static DAMPING_FACTOR: f64 = 10.0;
+
+///Output of the least squares solvers.
+/// ## Attributes
+/// - msg:String
+/// - params:Array1<f64>, the parameters minimizing the sum of squared residuals
+/// - covariance:Array2<f64>, the estimated covariance of the parameters, s^2 (J^T J)^-1 with
+///   s^2 the reduced chi squared, infinite if it cannot be estimated
+/// - residuals:Array1<f64>, the residuals at params
+/// - ssr:f64, the sum of squared residuals
+/// - reduced_chi_squared:f64, ssr divided by the degrees of freedom
+/// - rmse:f64, the root mean squared residual
+/// - iter:usize
+/// - func_evals:u32, evaluations of the residuals, including the ones of finite difference Jacobians
+/// - jac_evals:u32
+#[derive(Debug)]
+pub struct FitMetrics {
+    pub msg: String,
+    pub params: Array1<f64>,
+    pub covariance: Array2<f64>,
+    pub residuals: Array1<f64>,
+    pub ssr: f64,
+    pub reduced_chi_squared: f64,
+    pub rmse: f64,
+    pub iter: usize,
+    pub func_evals: u32,
+    pub jac_evals: u32,
+}
+
+impl FitMetrics {
+    /// Standard errors of the parameters, the square roots of the diagonal of the covariance.
+    pub fn standard_errors(&self) -> Array1<f64> {
+        self.covariance.diag().mapv(f64::sqrt)
+    }
+}
+
+impl fmt::Display for FitMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}func_evals={}, jac_evals={}, iter={}, ssr={:.5e}, reduced_chi_squared={:.5e}, rmse={:.5e}, params={}",
+            self.msg,
+            self.func_evals,
+            self.jac_evals,
+            self.iter,
+            self.ssr,
+            self.reduced_chi_squared,
+            self.rmse,
+            self.params
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum LeastSquaresError {
+    DimensionMismatchError,
+    UnderdeterminedError,
+    InvalidBoundsError,
+    UnacceptableToleranceError,
+    IterationLimitExceededError(Box<FitMetrics>),
+}
+
+impl fmt::Display for LeastSquaresError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeastSquaresError::DimensionMismatchError => {
+                write!(
+                    f,
+                    "The Jacobian must have a row per residual and a column per parameter."
+                )
+            }
+            LeastSquaresError::UnderdeterminedError => {
+                write!(f, "There are fewer residuals than parameters.")
+            }
+            LeastSquaresError::InvalidBoundsError => {
+                write!(
+                    f,
+                    "The bounds must have as many entries as the parameters and no lower bound above the upper one."
+                )
+            }
+            LeastSquaresError::UnacceptableToleranceError => {
+                write!(f, "Value of tol or rtol is either negative or zero.")
+            }
+            LeastSquaresError::IterationLimitExceededError(fit_metrics) => {
+                write!(f, "Maximum number of iterations reached.\n{}", fit_metrics)
+            }
+        }
+    }
+}
+
+impl Error for LeastSquaresError {}
+
+/// Levenberg-Marquardt Method for minimizing the sum of squares of the residuals r(p),
+/// with a user supplied or forward difference Jacobian of r.
+/// If bounds are given, every step is projected onto them.
+pub struct LevenbergMarquardt<F, J = NoJacobian> {
+    residuals: F,
+    jacobian: Option<J>,
+    p0: Array1<f64>,
+    lower: Option<Array1<f64>>,
+    upper: Option<Array1<f64>>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> LevenbergMarquardt<F>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+{
+    pub fn initialize(residuals: F, p0: Array1<f64>) -> Self {
+        Self {
+            residuals,
+            jacobian: None,
+            p0,
+            lower: None,
+            upper: None,
+            tol: 1e-10,
+            rtol: 1e-10,
+            iter: 500,
+        }
+    }
+}
+
+impl<F, J> LevenbergMarquardt<F, J>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+    J: FnMut(&Array1<f64>) -> Array2<f64>,
+{
+    pub fn jacobian<G>(self, jacobian: G) -> LevenbergMarquardt<F, G>
+    where
+        G: FnMut(&Array1<f64>) -> Array2<f64>,
+    {
+        LevenbergMarquardt {
+            residuals: self.residuals,
+            jacobian: Some(jacobian),
+            p0: self.p0,
+            lower: self.lower,
+            upper: self.upper,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+        }
+    }
+
+    pub fn bounds(mut self, lower: Array1<f64>, upper: Array1<f64>) -> Self {
+        self.lower = Some(lower);
+        self.upper = Some(upper);
+        self
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(mut self) -> Result<FitMetrics, LeastSquaresError> {
+        if self.tol <= 0.0 || self.rtol <= 0.0 {
+            return Err(LeastSquaresError::UnacceptableToleranceError);
+        }
+        let n = self.p0.len();
+        let lower = self
+            .lower
+            .clone()
+            .unwrap_or_else(|| Array1::from_elem(n, f64::NEG_INFINITY));
+        let upper = self
+            .upper
+            .clone()
+            .unwrap_or_else(|| Array1::from_elem(n, f64::INFINITY));
+        if lower.len() != n
+            || upper.len() != n
+            || lower
+                .iter()
+                .zip(&upper)
+                .any(|(l, u)| l > u || l.is_nan() || u.is_nan())
+        {
+            return Err(LeastSquaresError::InvalidBoundsError);
+        }
+        let project = |p: Array1<f64>| -> Array1<f64> {
+            let mut p = p;
+            p.zip_mut_with(&lower, |p, l| *p = p.max(*l));
+            p.zip_mut_with(&upper, |p, u| *p = p.min(*u));
+            p
+        };
+
+        let mut func_evals = 0;
+        let mut jac_evals = 0;
+
+        let mut p = project(self.p0.clone());
+        let mut r = (self.residuals)(&p);
+        func_evals += 1;
+        let m = r.len();
+        if m < n {
+            return Err(LeastSquaresError::UnderdeterminedError);
+        }
+        let mut ssr = r.dot(&r);
+        let mut j = self.jacobian_at(&p, &r, &lower, &upper, &mut func_evals)?;
+        jac_evals += 1;
+
+        let mut jtj = j.t().dot(&j);
+        let mut damping =
+            INITIAL_DAMPING * jtj.diag().iter().cloned().fold(f64::MIN_POSITIVE, f64::max);
+
+        for i in 0..self.iter {
+            let g = j.t().dot(&r);
+            if max_norm(&g) <= self.tol {
+                return Ok(self.metrics(p, r, &j, i, func_evals, jac_evals, SUCCESS_CONVERGENCE));
+            }
+
+            // Solve (J^T J + damping diag(J^T J)) dp = -J^T r
+            let mut a = jtj.clone();
+            for k in 0..n {
+                a[[k, k]] += damping * jtj[[k, k]].max(MACH_EPS);
+            }
+            let dp = match a.solve(&(-&g)) {
+                Ok(dp) if dp.iter().all(|v| v.is_finite()) => dp,
+                _ => {
+                    damping *= DAMPING_FACTOR;
+                    continue;
+                }
+            };
+
+            let p_n = project(&p + &dp);
+            let r_n = (self.residuals)(&p_n);
+            func_evals += 1;
+            if r_n.len() != m {
+                return Err(LeastSquaresError::DimensionMismatchError);
+            }
+            let ssr_n = r_n.dot(&r_n);
+
+            if ssr_n < ssr {
+                let step = &p_n - &p;
+                let reduction = ssr - ssr_n;
+                let converged_step = max_norm(&step) <= self.rtol * (max_norm(&p_n) + self.rtol);
+                let converged_ssr = reduction <= self.rtol * ssr;
+
+                p = p_n;
+                r = r_n;
+                ssr = ssr_n;
+                j = self.jacobian_at(&p, &r, &lower, &upper, &mut func_evals)?;
+                jac_evals += 1;
+                jtj = j.t().dot(&j);
+                damping = (damping / DAMPING_FACTOR).max(MACH_EPS);
+
+                if converged_step || converged_ssr {
+                    return Ok(self.metrics(
+                        p,
+                        r,
+                        &j,
+                        i + 1,
+                        func_evals,
+                        jac_evals,
+                        SUCCESS_CONVERGENCE,
+                    ));
+                }
+            } else {
+                // Reject the step and move towards gradient descent
+                damping *= DAMPING_FACTOR;
+                if max_norm(&(&p_n - &p)) <= self.rtol * (max_norm(&p) + self.rtol) {
+                    return Ok(self.metrics(
+                        p,
+                        r,
+                        &j,
+                        i + 1,
+                        func_evals,
+                        jac_evals,
+                        SUCCESS_CONVERGENCE,
+                    ));
+                }
+            }
+        }
+
+        Err(LeastSquaresError::IterationLimitExceededError(Box::new(
+            self.metrics(p, r, &j, self.iter, func_evals, jac_evals, MAX_ITER),
+        )))
+    }
+
+    fn jacobian_at(
+        &mut self,
+        p: &Array1<f64>,
+        r: &Array1<f64>,
+        lower: &Array1<f64>,
+        upper: &Array1<f64>,
+        func_evals: &mut u32,
+    ) -> Result<Array2<f64>, LeastSquaresError> {
+        let (m, n) = (r.len(), p.len());
+        if let Some(jacobian) = self.jacobian.as_mut() {
+            let j = jacobian(p);
+            if j.dim() != (m, n) {
+                return Err(LeastSquaresError::DimensionMismatchError);
+            }
+            return Ok(j);
+        }
+
+        // Forward differences, stepping towards the farther bound and at most up to it,
+        // so that the residuals are never evaluated outside of the box
+        let mut j = Array2::zeros((m, n));
+        let mut p_h = p.clone();
+        for k in 0..n {
+            let h = MACH_EPS.sqrt() * p[k].abs().max(1.0);
+            let (below, above) = (p[k] - lower[k], upper[k] - p[k]);
+            let step = if above >= h || above >= below {
+                h.min(above)
+            } else {
+                -h.min(below)
+            };
+            p_h[k] = (p[k] + step).clamp(lower[k], upper[k]);
+            let h = p_h[k] - p[k];
+            if h == 0.0 {
+                p_h[k] = p[k];
+                continue;
+            }
+            let r_h = (self.residuals)(&p_h);
+            *func_evals += 1;
+            if r_h.len() != m {
+                return Err(LeastSquaresError::DimensionMismatchError);
+            }
+            j.column_mut(k).assign(&((r_h - r) / h));
+            p_h[k] = p[k];
+        }
+        Ok(j)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn metrics(
+        &self,
+        params: Array1<f64>,
+        residuals: Array1<f64>,
+        j: &Array2<f64>,
+        iter: usize,
+        func_evals: u32,
+        jac_evals: u32,
+        msg: &str,
+    ) -> FitMetrics {
+        let (m, n) = j.dim();
+        let ssr = residuals.dot(&residuals);
+        let reduced_chi_squared = if m > n {
+            ssr / (m - n) as f64
+        } else {
+            f64::NAN
+        };
+
+        let covariance = match j.t().dot(j).inv() {
+            Ok(inverse) if reduced_chi_squared.is_finite() => inverse * reduced_chi_squared,
+            _ => Array2::from_elem((n, n), f64::INFINITY),
+        };
+
+        FitMetrics {
+            msg: String::from(msg),
+            params,
+            covariance,
+            residuals,
+            ssr,
+            reduced_chi_squared,
+            rmse: (ssr / m as f64).sqrt(),
+            iter,
+            func_evals,
+            jac_evals,
+        }
+    }
+}
+
+/// Fits the parameters p of model(x, p) to the data points (x, y) by minimizing the sum of
+/// the squared residuals y - model(x, p) with the Levenberg-Marquardt Method.
+pub struct CurveFit<'a, M> {
+    model: M,
+    data: &'a [(f64, f64)],
+    p0: Array1<f64>,
+    bounds: Option<(Array1<f64>, Array1<f64>)>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<'a, M> CurveFit<'a, M>
+where
+    M: FnMut(f64, &Array1<f64>) -> f64,
+{
+    pub fn initialize(model: M, data: &'a [(f64, f64)], p0: Array1<f64>) -> Self {
+        Self {
+            model,
+            data,
+            p0,
+            bounds: None,
+            tol: 1e-10,
+            rtol: 1e-10,
+            iter: 500,
+        }
+    }
+
+    pub fn bounds(mut self, lower: Array1<f64>, upper: Array1<f64>) -> Self {
+        self.bounds = Some((lower, upper));
+        self
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(mut self) -> Result<FitMetrics, LeastSquaresError> {
+        let data = self.data;
+        let model = &mut self.model;
+        let residuals = |p: &Array1<f64>| -> Array1<f64> {
+            data.iter().map(|&(x, y)| y - model(x, p)).collect()
+        };
+
+        let mut solver = LevenbergMarquardt::initialize(residuals, self.p0)
+            .tol(self.tol)
+            .rtol(self.rtol)
+            .iter(self.iter);
+        if let Some((lower, upper)) = self.bounds {
+            solver = solver.bounds(lower, upper);
+        }
+        solver.run()
+    }
+}
+
+/// Fits the parameters p of model(x, p) to the data points (x, y) with the default
+/// settings of `CurveFit`.
+pub fn curve_fit<M>(
+    model: M,
+    data: &[(f64, f64)],
+    p0: Array1<f64>,
+) -> Result<FitMetrics, LeastSquaresError>
+where
+    M: FnMut(f64, &Array1<f64>) -> f64,
+{
+    CurveFit::initialize(model, data, p0).run()
+}
@@ -0,0 +1,767 @@
+use core::fmt;
+use std::collections::VecDeque;
+use std::error::Error;
+
+use ndarray::{Array1, Array2};
+
+use super::root_finding::{MACH_EPS, MAX_ITER, SUCCESS_CONVERGENCE};
+use crate::common::functions::max_norm;
+
+// Constants of the sufficient decrease and of the curvature condition of the line search
+static ARMIJO: f64 = 1e-4;
+static CURVATURE: f64 = 0.9;
+static LINE_SEARCH_ITER: usize = 30;
+
+// Point accepted by the line search with its function value and gradient
+type WolfePoint = (Array1<f64>, f64, Array1<f64>);
+
+/// Type of the gradient of a minimizer that has not been given one, which is then
+/// approximated with central finite differences.
+pub type NoGradient = fn(&Array1<f64>) -> Array1<f64>;
+
+///Output of the multivariate minimizers.
+/// ## Attributes
+/// - msg:String
+/// - x:Array1<f64>, the approximation of the minimizer
+/// - f_x:f64, the value of the function at x
+/// - grad_norm:f64, the maximum norm of the gradient at x, NaN for methods without gradients
+/// - iter:usize
+/// - func_evals:u32, evaluations of the function, including the ones of finite difference gradients
+/// - grad_evals:u32, evaluations of the gradient, user supplied or approximated
+#[derive(Debug)]
+pub struct MinimizeMetrics {
+    pub msg: String,
+    pub x: Array1<f64>,
+    pub f_x: f64,
+    pub grad_norm: f64,
+    pub iter: usize,
+    pub func_evals: u32,
+    pub grad_evals: u32,
+}
+
+impl fmt::Display for MinimizeMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}func_evals={}, grad_evals={}, iter={}, f_x={}, grad_norm={:.5e}, x={}",
+            self.msg, self.func_evals, self.grad_evals, self.iter, self.f_x, self.grad_norm, self.x
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum MinimizeError {
+    DimensionMismatchError,
+    InvalidBoundsError,
+    UnacceptableToleranceError,
+    LineSearchError(MinimizeMetrics),
+    IterationLimitExceededError(MinimizeMetrics),
+}
+
+impl fmt::Display for MinimizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MinimizeError::DimensionMismatchError => {
+                write!(f, "The gradient must have as many entries as x.")
+            }
+            MinimizeError::InvalidBoundsError => {
+                write!(
+                    f,
+                    "The bounds must have as many entries as x and no lower bound above the upper one."
+                )
+            }
+            MinimizeError::UnacceptableToleranceError => {
+                write!(f, "Value of tol or rtol is either negative or zero.")
+            }
+            MinimizeError::LineSearchError(minimize_metrics) => {
+                write!(
+                    f,
+                    "The line search failed to find a point of sufficient decrease.\n{}",
+                    minimize_metrics
+                )
+            }
+            MinimizeError::IterationLimitExceededError(minimize_metrics) => {
+                write!(
+                    f,
+                    "Maximum number of iterations reached.\n{}",
+                    minimize_metrics
+                )
+            }
+        }
+    }
+}
+
+impl Error for MinimizeError {}
+
+// Evaluates the function or its gradient and keeps count of the evaluations
+pub(crate) struct Evaluator<F, G> {
+    f: F,
+    gradient: Option<G>,
+    func_evals: u32,
+    grad_evals: u32,
+}
+
+impl<F, G> Evaluator<F, G>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+    G: FnMut(&Array1<f64>) -> Array1<f64>,
+{
+    pub(crate) fn new(f: F, gradient: Option<G>) -> Self {
+        Self {
+            f,
+            gradient,
+            func_evals: 0,
+            grad_evals: 0,
+        }
+    }
+
+    pub(crate) fn eval(&mut self, x: &Array1<f64>) -> f64 {
+        self.func_evals += 1;
+        (self.f)(x)
+    }
+
+    pub(crate) fn gradient(&mut self, x: &Array1<f64>) -> Result<Array1<f64>, MinimizeError> {
+        self.grad_evals += 1;
+        if let Some(gradient) = self.gradient.as_mut() {
+            let g = gradient(x);
+            if g.len() != x.len() {
+                return Err(MinimizeError::DimensionMismatchError);
+            }
+            return Ok(g);
+        }
+
+        // Central differences, two evaluations per variable
+        let mut g = Array1::zeros(x.len());
+        let mut x_h = x.clone();
+        for k in 0..x.len() {
+            let h = MACH_EPS.cbrt() * x[k].abs().max(1.0);
+            x_h[k] = x[k] + h;
+            let f_plus = self.eval(&x_h);
+            x_h[k] = x[k] - h;
+            let f_minus = self.eval(&x_h);
+            x_h[k] = x[k];
+            g[k] = (f_plus - f_minus) / (2.0 * h);
+        }
+        Ok(g)
+    }
+
+    // Gradient at a point x of the box lower <= x <= upper with f(x) = f_x. Without a user
+    // supplied gradient, the variables within h of a bound use a one-sided difference pointing
+    // into the box, so that f is never evaluated outside of it
+    pub(crate) fn gradient_in_box(
+        &mut self,
+        x: &Array1<f64>,
+        f_x: f64,
+        lower: &Array1<f64>,
+        upper: &Array1<f64>,
+    ) -> Result<Array1<f64>, MinimizeError> {
+        if self.gradient.is_some() {
+            return self.gradient(x);
+        }
+
+        self.grad_evals += 1;
+        let mut g = Array1::zeros(x.len());
+        let mut x_h = x.clone();
+        for k in 0..x.len() {
+            let h = MACH_EPS.cbrt() * x[k].abs().max(1.0);
+            let (below, above) = (x[k] - lower[k], upper[k] - x[k]);
+            if below >= h && above >= h {
+                x_h[k] = x[k] + h;
+                let f_plus = self.eval(&x_h);
+                x_h[k] = x[k] - h;
+                let f_minus = self.eval(&x_h);
+                g[k] = (f_plus - f_minus) / (2.0 * h);
+            } else {
+                // Towards the farther bound, at most up to it
+                let step = if above >= below {
+                    h.min(above)
+                } else {
+                    -h.min(below)
+                };
+                x_h[k] = (x[k] + step).clamp(lower[k], upper[k]);
+                let h = x_h[k] - x[k];
+                if h != 0.0 {
+                    g[k] = (self.eval(&x_h) - f_x) / h;
+                }
+            }
+            x_h[k] = x[k];
+        }
+        Ok(g)
+    }
+
+    fn metrics(&self, x: Array1<f64>, f_x: f64, grad_norm: f64, iter: usize) -> MinimizeMetrics {
+        MinimizeMetrics {
+            msg: String::new(),
+            x,
+            f_x,
+            grad_norm,
+            iter,
+            func_evals: self.func_evals,
+            grad_evals: self.grad_evals,
+        }
+    }
+
+    pub(crate) fn success(
+        &self,
+        x: Array1<f64>,
+        f_x: f64,
+        grad_norm: f64,
+        iter: usize,
+    ) -> MinimizeMetrics {
+        let mut minimize_metrics = self.metrics(x, f_x, grad_norm, iter);
+        minimize_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+        minimize_metrics
+    }
+
+    pub(crate) fn failure(
+        &self,
+        x: Array1<f64>,
+        f_x: f64,
+        grad_norm: f64,
+        iter: usize,
+    ) -> MinimizeMetrics {
+        let mut minimize_metrics = self.metrics(x, f_x, grad_norm, iter);
+        minimize_metrics.msg.push_str(MAX_ITER);
+        minimize_metrics
+    }
+
+    // Line search along the descent direction p for a step satisfying the strong Wolfe conditions
+    fn line_search(
+        &mut self,
+        x: &Array1<f64>,
+        f_x: f64,
+        g_x: &Array1<f64>,
+        p: &Array1<f64>,
+    ) -> Result<Option<WolfePoint>, MinimizeError> {
+        let slope = g_x.dot(p);
+        if slope >= 0.0 {
+            return Ok(None);
+        }
+
+        let mut alpha_prev = 0.0;
+        let mut f_prev = f_x;
+        let mut slope_prev = slope;
+        let mut alpha = 1.0;
+
+        for i in 0..LINE_SEARCH_ITER {
+            let x_a = x + &(alpha * p);
+            let f_a = self.eval(&x_a);
+            if !f_a.is_finite() {
+                alpha *= 0.5;
+                continue;
+            }
+
+            if f_a > f_x + ARMIJO * alpha * slope || (i > 0 && f_a >= f_prev) {
+                return self.zoom(
+                    x,
+                    f_x,
+                    slope,
+                    p,
+                    (alpha_prev, f_prev, slope_prev),
+                    (alpha, f_a),
+                );
+            }
+
+            let g_a = self.gradient(&x_a)?;
+            let slope_a = g_a.dot(p);
+            if slope_a.abs() <= -CURVATURE * slope {
+                return Ok(Some((x_a, f_a, g_a)));
+            }
+            if slope_a >= 0.0 {
+                return self.zoom(
+                    x,
+                    f_x,
+                    slope,
+                    p,
+                    (alpha, f_a, slope_a),
+                    (alpha_prev, f_prev),
+                );
+            }
+
+            alpha_prev = alpha;
+            f_prev = f_a;
+            slope_prev = slope_a;
+            alpha *= 2.0;
+        }
+        Ok(None)
+    }
+
+    // Shrinks the interval between the step low, which satisfies the sufficient decrease
+    // condition, and the step high until a step satisfying the strong Wolfe conditions is found
+    fn zoom(
+        &mut self,
+        x: &Array1<f64>,
+        f_x: f64,
+        slope: f64,
+        p: &Array1<f64>,
+        low: (f64, f64, f64),
+        high: (f64, f64),
+    ) -> Result<Option<WolfePoint>, MinimizeError> {
+        let (mut alpha_low, mut f_low, mut slope_low) = low;
+        let (mut alpha_high, mut f_high) = high;
+
+        for _ in 0..LINE_SEARCH_ITER {
+            // Minimizer of the quadratic interpolant, safeguarded by bisection
+            let d = alpha_high - alpha_low;
+            let denominator = 2.0 * (f_high - f_low - slope_low * d);
+            let mut alpha = alpha_low - slope_low * d * d / denominator;
+            let (lo, hi) = (alpha_low.min(alpha_high), alpha_low.max(alpha_high));
+            if !alpha.is_finite() || alpha < lo + 0.1 * (hi - lo) || alpha > hi - 0.1 * (hi - lo) {
+                alpha = 0.5 * (alpha_low + alpha_high);
+            }
+
+            let x_a = x + &(alpha * p);
+            let f_a = self.eval(&x_a);
+            if f_a > f_x + ARMIJO * alpha * slope || f_a >= f_low {
+                alpha_high = alpha;
+                f_high = f_a;
+            } else {
+                let g_a = self.gradient(&x_a)?;
+                let slope_a = g_a.dot(p);
+                if slope_a.abs() <= -CURVATURE * slope {
+                    return Ok(Some((x_a, f_a, g_a)));
+                }
+                if slope_a * (alpha_high - alpha_low) >= 0.0 {
+                    alpha_high = alpha_low;
+                    f_high = f_low;
+                }
+                alpha_low = alpha;
+                f_low = f_a;
+                slope_low = slope_a;
+            }
+        }
+
+        // Fall back to the best step found, if it decreased the function
+        if alpha_low > 0.0 {
+            let x_a = x + &(alpha_low * p);
+            let g_a = self.gradient(&x_a)?;
+            return Ok(Some((x_a, f_low, g_a)));
+        }
+        Ok(None)
+    }
+}
+
+/// Nelder-Mead Simplex Method, which only uses function values and thus tolerates noisy
+/// objectives. The initial simplex is built from x0 by moving each coordinate by `step`.
+pub struct NelderMead<F> {
+    f: F,
+    x0: Array1<f64>,
+    step: f64,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> NelderMead<F>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+{
+    pub fn initialize(f: F, x0: Array1<f64>) -> Self {
+        Self {
+            f,
+            x0,
+            step: 0.05,
+            tol: 1e-8,
+            rtol: 1e-8,
+            iter: 5000,
+        }
+    }
+
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(self) -> Result<MinimizeMetrics, MinimizeError> {
+        check_tolerances(self.tol, self.rtol)?;
+        if self.step == 0.0 {
+            return Err(MinimizeError::UnacceptableToleranceError);
+        }
+        let mut evaluator = Evaluator::<F, NoGradient>::new(self.f, None);
+
+        let n = self.x0.len();
+        let mut simplex: Vec<Array1<f64>> = vec![self.x0.clone()];
+        for k in 0..n {
+            let mut vertex = self.x0.clone();
+            vertex[k] += self.step * self.x0[k].abs().max(1.0);
+            simplex.push(vertex);
+        }
+        let mut values: Vec<f64> = simplex.iter().map(|x| evaluator.eval(x)).collect();
+
+        for i in 0..self.iter {
+            // Order the vertices from best to worst
+            let mut order: Vec<usize> = (0..=n).collect();
+            order.sort_by(|&a, &b| values[a].total_cmp(&values[b]));
+            simplex = order.iter().map(|&k| simplex[k].clone()).collect();
+            values = order.iter().map(|&k| values[k]).collect();
+
+            // Check for convergence on the spread of the values and of the vertices
+            let f_spread = values[n] - values[0];
+            let x_spread = simplex[1..]
+                .iter()
+                .map(|x| max_norm(&(x - &simplex[0])))
+                .fold(0.0, f64::max);
+            if f_spread <= self.tol + self.rtol * values[0].abs()
+                && x_spread <= self.tol + self.rtol * max_norm(&simplex[0])
+            {
+                return Ok(evaluator.success(simplex[0].clone(), values[0], f64::NAN, i));
+            }
+
+            let centroid = simplex[..n].iter().fold(Array1::zeros(n), |sum, x| sum + x) / n as f64;
+
+            // Reflection
+            let reflected = &centroid + &(&centroid - &simplex[n]);
+            let f_reflected = evaluator.eval(&reflected);
+
+            if f_reflected < values[0] {
+                // Expansion
+                let expanded = &centroid + &(2.0 * (&reflected - &centroid));
+                let f_expanded = evaluator.eval(&expanded);
+                if f_expanded < f_reflected {
+                    simplex[n] = expanded;
+                    values[n] = f_expanded;
+                } else {
+                    simplex[n] = reflected;
+                    values[n] = f_reflected;
+                }
+            } else if f_reflected < values[n - 1] {
+                simplex[n] = reflected;
+                values[n] = f_reflected;
+            } else {
+                // Contraction, outside or inside the simplex
+                let (contracted, bound) = if f_reflected < values[n] {
+                    (&centroid + &(0.5 * (&reflected - &centroid)), f_reflected)
+                } else {
+                    (&centroid + &(0.5 * (&simplex[n] - &centroid)), values[n])
+                };
+                let f_contracted = evaluator.eval(&contracted);
+
+                if f_contracted < bound {
+                    simplex[n] = contracted;
+                    values[n] = f_contracted;
+                } else {
+                    // Shrink towards the best vertex
+                    for k in 1..=n {
+                        simplex[k] = &simplex[0] + &(0.5 * (&simplex[k] - &simplex[0]));
+                        values[k] = evaluator.eval(&simplex[k]);
+                    }
+                }
+            }
+        }
+
+        let best = (0..=n)
+            .min_by(|&a, &b| values[a].total_cmp(&values[b]))
+            .unwrap();
+        Err(MinimizeError::IterationLimitExceededError(
+            evaluator.failure(simplex[best].clone(), values[best], f64::NAN, self.iter),
+        ))
+    }
+}
+
+/// Broyden-Fletcher-Goldfarb-Shanno Method, a quasi-Newton method which updates an
+/// approximation of the inverse Hessian, with a line search satisfying the strong Wolfe conditions.
+pub struct Bfgs<F, G = NoGradient> {
+    f: F,
+    gradient: Option<G>,
+    x0: Array1<f64>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> Bfgs<F>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+{
+    pub fn initialize(f: F, x0: Array1<f64>) -> Self {
+        Self {
+            f,
+            gradient: None,
+            x0,
+            tol: 1e-6,
+            rtol: MACH_EPS,
+            iter: 500,
+        }
+    }
+}
+
+impl<F, G> Bfgs<F, G>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+    G: FnMut(&Array1<f64>) -> Array1<f64>,
+{
+    pub fn gradient<H>(self, gradient: H) -> Bfgs<F, H>
+    where
+        H: FnMut(&Array1<f64>) -> Array1<f64>,
+    {
+        Bfgs {
+            f: self.f,
+            gradient: Some(gradient),
+            x0: self.x0,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+        }
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(self) -> Result<MinimizeMetrics, MinimizeError> {
+        check_tolerances(self.tol, self.rtol)?;
+        let mut evaluator = Evaluator::new(self.f, self.gradient);
+
+        let n = self.x0.len();
+        let mut x = self.x0;
+        let mut f_x = evaluator.eval(&x);
+        let mut g_x = evaluator.gradient(&x)?;
+        let mut h: Array2<f64> = Array2::eye(n);
+
+        for i in 0..self.iter {
+            if max_norm(&g_x) <= self.tol {
+                return Ok(evaluator.success(x, f_x, max_norm(&g_x), i));
+            }
+
+            let mut p = -h.dot(&g_x);
+            if g_x.dot(&p) >= 0.0 {
+                // Restart from steepest descent if the direction is not a descent one
+                h = Array2::eye(n);
+                p = -&g_x;
+            }
+
+            let (x_n, f_x_n, g_x_n) = match evaluator.line_search(&x, f_x, &g_x, &p)? {
+                Some(point) => point,
+                None => {
+                    let grad_norm = max_norm(&g_x);
+                    return Err(MinimizeError::LineSearchError(
+                        evaluator.failure(x, f_x, grad_norm, i),
+                    ));
+                }
+            };
+
+            let s = &x_n - &x;
+            let y = &g_x_n - &g_x;
+            let sy = s.dot(&y);
+            if i == 0 && sy > 0.0 {
+                // Scale the initial approximation before the first update
+                h *= sy / y.dot(&y);
+            }
+            if sy > 0.0 {
+                // H <- (I - rho s y^T) H (I - rho y s^T) + rho s s^T
+                let rho = 1.0 / sy;
+                let hy = h.dot(&y);
+                let yhy = y.dot(&hy);
+                for r in 0..n {
+                    for c in 0..n {
+                        h[[r, c]] += -rho * (s[r] * hy[c] + hy[r] * s[c])
+                            + (rho * rho * yhy + rho) * s[r] * s[c];
+                    }
+                }
+            }
+
+            let converged_step = max_norm(&s) <= self.rtol * max_norm(&x_n);
+            x = x_n;
+            f_x = f_x_n;
+            g_x = g_x_n;
+
+            if converged_step {
+                return Ok(evaluator.success(x, f_x, max_norm(&g_x), i + 1));
+            }
+        }
+
+        let grad_norm = max_norm(&g_x);
+        if grad_norm <= self.tol {
+            return Ok(evaluator.success(x, f_x, grad_norm, self.iter));
+        }
+        Err(MinimizeError::IterationLimitExceededError(
+            evaluator.failure(x, f_x, grad_norm, self.iter),
+        ))
+    }
+}
+
+/// Limited memory BFGS Method, which keeps only the last `memory` correction pairs instead of
+/// a dense inverse Hessian, for problems with many variables.
+pub struct Lbfgs<F, G = NoGradient> {
+    f: F,
+    gradient: Option<G>,
+    x0: Array1<f64>,
+    memory: usize,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> Lbfgs<F>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+{
+    pub fn initialize(f: F, x0: Array1<f64>) -> Self {
+        Self {
+            f,
+            gradient: None,
+            x0,
+            memory: 10,
+            tol: 1e-6,
+            rtol: MACH_EPS,
+            iter: 1000,
+        }
+    }
+}
+
+impl<F, G> Lbfgs<F, G>
+where
+    F: FnMut(&Array1<f64>) -> f64,
+    G: FnMut(&Array1<f64>) -> Array1<f64>,
+{
+    pub fn gradient<H>(self, gradient: H) -> Lbfgs<F, H>
+    where
+        H: FnMut(&Array1<f64>) -> Array1<f64>,
+    {
+        Lbfgs {
+            f: self.f,
+            gradient: Some(gradient),
+            x0: self.x0,
+            memory: self.memory,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+        }
+    }
+
+    pub fn memory(mut self, memory: usize) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(self) -> Result<MinimizeMetrics, MinimizeError> {
+        check_tolerances(self.tol, self.rtol)?;
+        if self.memory == 0 {
+            return Err(MinimizeError::UnacceptableToleranceError);
+        }
+        let mut evaluator = Evaluator::new(self.f, self.gradient);
+
+        let mut x = self.x0;
+        let mut f_x = evaluator.eval(&x);
+        let mut g_x = evaluator.gradient(&x)?;
+        let mut corrections: VecDeque<(Array1<f64>, Array1<f64>, f64)> = VecDeque::new();
+
+        for i in 0..self.iter {
+            if max_norm(&g_x) <= self.tol {
+                return Ok(evaluator.success(x, f_x, max_norm(&g_x), i));
+            }
+
+            let p = -two_loop_recursion(&g_x, &corrections);
+
+            let (x_n, f_x_n, g_x_n) = match evaluator.line_search(&x, f_x, &g_x, &p)? {
+                Some(point) => point,
+                None => {
+                    let grad_norm = max_norm(&g_x);
+                    return Err(MinimizeError::LineSearchError(
+                        evaluator.failure(x, f_x, grad_norm, i),
+                    ));
+                }
+            };
+
+            let s = &x_n - &x;
+            let y = &g_x_n - &g_x;
+            let sy = s.dot(&y);
+            let converged_step = max_norm(&s) <= self.rtol * max_norm(&x_n);
+            if sy > 0.0 {
+                if corrections.len() == self.memory {
+                    corrections.pop_front();
+                }
+                corrections.push_back((s, y, 1.0 / sy));
+            }
+
+            x = x_n;
+            f_x = f_x_n;
+            g_x = g_x_n;
+
+            if converged_step {
+                return Ok(evaluator.success(x, f_x, max_norm(&g_x), i + 1));
+            }
+        }
+
+        let grad_norm = max_norm(&g_x);
+        if grad_norm <= self.tol {
+            return Ok(evaluator.success(x, f_x, grad_norm, self.iter));
+        }
+        Err(MinimizeError::IterationLimitExceededError(
+            evaluator.failure(x, f_x, grad_norm, self.iter),
+        ))
+    }
+}
+
+// Product of the inverse Hessian approximation with the gradient, from the correction pairs
+// (s, y, 1 / s^T y) ordered from the oldest to the newest
+fn two_loop_recursion(
+    g: &Array1<f64>,
+    corrections: &VecDeque<(Array1<f64>, Array1<f64>, f64)>,
+) -> Array1<f64> {
+    let mut q = g.clone();
+    let mut alphas = Vec::with_capacity(corrections.len());
+    for (s, y, rho) in corrections.iter().rev() {
+        let alpha = rho * s.dot(&q);
+        q.scaled_add(-alpha, y);
+        alphas.push(alpha);
+    }
+
+    if let Some((s, y, _)) = corrections.back() {
+        q *= s.dot(y) / y.dot(y);
+    }
+
+    for ((s, y, rho), alpha) in corrections.iter().zip(alphas.iter().rev()) {
+        let beta = rho * y.dot(&q);
+        q.scaled_add(alpha - beta, s);
+    }
+    q
+}
+
+pub(crate) fn check_tolerances(tol: f64, rtol: f64) -> Result<(), MinimizeError> {
+    if tol <= 0.0 || rtol < 0.0 {
+        return Err(MinimizeError::UnacceptableToleranceError);
+    }
+    Ok(())
+}
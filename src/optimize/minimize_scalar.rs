@@ -0,0 +1,474 @@
+use core::fmt;
+use std::error::Error;
+
+use super::root_finding::{AlgoMetrics, DEFAULT_RTOL, MACH_EPS, MAX_ITER, SUCCESS_CONVERGENCE};
+
+// Golden ratio and the fraction 2 - golden ratio used for the golden section steps
+static GOLDEN_RATIO: f64 = 1.618033988749895;
+static GOLDEN_SECTION: f64 = 0.3819660112501051;
+
+// Largest magnification of a parabolic step of the bracket search
+static BRACKET_LIMIT: f64 = 100.0;
+
+#[derive(Debug)]
+pub enum MinimizationError {
+    IntervalError,
+    BracketNotFoundError(MinimumBracket),
+    UnacceptableToleranceError(AlgoMetrics),
+    IterationLimitExceededError(AlgoMetrics),
+}
+
+impl fmt::Display for MinimizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MinimizationError::IntervalError => {
+                write!(f, "The end points of the interval cannot be identical.")
+            }
+            MinimizationError::BracketNotFoundError(bracket) => {
+                write!(
+                    f,
+                    "No bracket of a minimum was found, the function may be unbounded below.\n{}",
+                    bracket
+                )
+            }
+            MinimizationError::UnacceptableToleranceError(algo_metrics) => {
+                write!(
+                    f,
+                    "Too small tolerance value was given.\n{}",
+                    algo_metrics.msg
+                )
+            }
+            MinimizationError::IterationLimitExceededError(algo_metrics) => {
+                write!(f, "Maximum number of iterations reached.\n{}", algo_metrics)
+            }
+        }
+    }
+}
+
+impl Error for MinimizationError {}
+
+///Three points a < b < c with f(b) below f(a) and f(c), so that [a, c] contains a minimum.
+/// ## Attributes
+/// - a, b, c:f64
+/// - f_a, f_b, f_c:f64
+/// - func_evals:u32
+#[derive(Debug)]
+pub struct MinimumBracket {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+    pub f_a: f64,
+    pub f_b: f64,
+    pub f_c: f64,
+    pub func_evals: u32,
+}
+
+impl MinimumBracket {
+    // Orders the points so that a < c
+    fn new(a: (f64, f64), b: (f64, f64), c: (f64, f64), func_evals: u32) -> Self {
+        let (a, c) = if a.0 < c.0 { (a, c) } else { (c, a) };
+        Self {
+            a: a.0,
+            b: b.0,
+            c: c.0,
+            f_a: a.1,
+            f_b: b.1,
+            f_c: c.1,
+            func_evals,
+        }
+    }
+}
+
+impl fmt::Display for MinimumBracket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "a={}, b={}, c={}, f_a={}, f_b={}, f_c={}, func_evals={}",
+            self.a, self.b, self.c, self.f_a, self.f_b, self.f_c, self.func_evals
+        )
+    }
+}
+
+/// Downhill search from the points a and b for a bracket of a minimum, which takes golden
+/// section steps combined with parabolic extrapolation.
+pub struct BracketSearch<F> {
+    f: F,
+    a: f64,
+    b: f64,
+    iter: usize,
+}
+
+impl<F> BracketSearch<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self { f, a, b, iter: 100 }
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(mut self) -> Result<MinimumBracket, MinimizationError> {
+        if self.a == self.b {
+            return Err(MinimizationError::IntervalError);
+        }
+
+        let (mut a, mut b) = (self.a, self.b);
+        let mut f_a = (self.f)(a);
+        let mut f_b = (self.f)(b);
+        let mut func_evals = 2;
+
+        // Search downhill from a to b
+        if f_b > f_a {
+            std::mem::swap(&mut a, &mut b);
+            std::mem::swap(&mut f_a, &mut f_b);
+        }
+
+        let mut c = b + GOLDEN_RATIO * (b - a);
+        let mut f_c = (self.f)(c);
+        func_evals += 1;
+
+        let mut i = 0;
+        while f_b > f_c {
+            if i == self.iter || !c.is_finite() {
+                return Err(MinimizationError::BracketNotFoundError(
+                    MinimumBracket::new((a, f_a), (b, f_b), (c, f_c), func_evals),
+                ));
+            }
+            i += 1;
+
+            // Extremum of the parabola through a, b, c
+            let r = (b - a) * (f_b - f_c);
+            let q = (b - c) * (f_b - f_a);
+            let denominator = 2.0 * (q - r).abs().max(1e-20).copysign(q - r);
+            let mut u = b - ((b - c) * q - (b - a) * r) / denominator;
+            let u_limit = b + BRACKET_LIMIT * (c - b);
+            let f_u;
+
+            if (b - u) * (u - c) > 0.0 {
+                // Parabolic u between b and c
+                let f_p = (self.f)(u);
+                func_evals += 1;
+                if f_p < f_c {
+                    return Ok(MinimumBracket::new(
+                        (b, f_b),
+                        (u, f_p),
+                        (c, f_c),
+                        func_evals,
+                    ));
+                } else if f_p > f_b {
+                    return Ok(MinimumBracket::new(
+                        (a, f_a),
+                        (b, f_b),
+                        (u, f_p),
+                        func_evals,
+                    ));
+                }
+                u = c + GOLDEN_RATIO * (c - b);
+                f_u = (self.f)(u);
+                func_evals += 1;
+            } else if (c - u) * (u - u_limit) > 0.0 {
+                // Parabolic u between c and its limit
+                let f_p = (self.f)(u);
+                func_evals += 1;
+                if f_p < f_c {
+                    b = c;
+                    f_b = f_c;
+                    c = u;
+                    f_c = f_p;
+                    u = c + GOLDEN_RATIO * (c - b);
+                    f_u = (self.f)(u);
+                    func_evals += 1;
+                } else {
+                    f_u = f_p;
+                }
+            } else if (u - u_limit) * (u_limit - c) >= 0.0 {
+                u = u_limit;
+                f_u = (self.f)(u);
+                func_evals += 1;
+            } else {
+                u = c + GOLDEN_RATIO * (c - b);
+                f_u = (self.f)(u);
+                func_evals += 1;
+            }
+
+            a = b;
+            f_a = f_b;
+            b = c;
+            f_b = f_c;
+            c = u;
+            f_c = f_u;
+        }
+
+        Ok(MinimumBracket::new(
+            (a, f_a),
+            (b, f_b),
+            (c, f_c),
+            func_evals,
+        ))
+    }
+}
+
+/// Golden Section Search for a minimum of f on the interval [a, b].
+pub struct GoldenSection<F> {
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> GoldenSection<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self {
+            f,
+            a,
+            b,
+            tol: 1e-8,
+            rtol: MACH_EPS.sqrt(),
+            iter: 200,
+        }
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(mut self) -> Result<AlgoMetrics, MinimizationError> {
+        let mut algo_metrics = AlgoMetrics::default();
+        check_tolerances(self.tol, self.rtol, &mut algo_metrics)?;
+        if self.a == self.b {
+            return Err(MinimizationError::IntervalError);
+        }
+
+        let (mut a, mut b) = (self.a.min(self.b), self.a.max(self.b));
+        let mut c = a + GOLDEN_SECTION * (b - a);
+        let mut d = b - GOLDEN_SECTION * (b - a);
+        let mut f_c = (self.f)(c);
+        let mut f_d = (self.f)(d);
+        algo_metrics.func_evals += 2;
+
+        for i in 0..self.iter {
+            if b - a <= self.tol + self.rtol * (c.abs() + d.abs()) {
+                algo_metrics.iter = i;
+                algo_metrics.est_x = if f_c < f_d { c } else { d };
+                algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+                return Ok(algo_metrics);
+            }
+
+            // Keep the sub-interval that contains the lower of the two inner points
+            if f_c < f_d {
+                b = d;
+                d = c;
+                f_d = f_c;
+                c = a + GOLDEN_SECTION * (b - a);
+                f_c = (self.f)(c);
+            } else {
+                a = c;
+                c = d;
+                f_c = f_d;
+                d = b - GOLDEN_SECTION * (b - a);
+                f_d = (self.f)(d);
+            }
+            algo_metrics.func_evals += 1;
+        }
+
+        algo_metrics.iter = self.iter;
+        algo_metrics.est_x = if f_c < f_d { c } else { d };
+        algo_metrics.msg.push_str(MAX_ITER);
+        Err(MinimizationError::IterationLimitExceededError(algo_metrics))
+    }
+}
+
+/// Brent's Method for a minimum of f on the interval [a, b], which combines parabolic
+/// interpolation with golden section steps.
+pub struct BrentMinimizer<F> {
+    f: F,
+    a: f64,
+    b: f64,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> BrentMinimizer<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self {
+            f,
+            a,
+            b,
+            tol: 1e-8,
+            rtol: MACH_EPS.sqrt(),
+            iter: 200,
+        }
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(mut self) -> Result<AlgoMetrics, MinimizationError> {
+        let mut algo_metrics = AlgoMetrics::default();
+        check_tolerances(self.tol, self.rtol, &mut algo_metrics)?;
+        if self.a == self.b {
+            return Err(MinimizationError::IntervalError);
+        }
+
+        let (mut a, mut b) = (self.a.min(self.b), self.a.max(self.b));
+
+        // x: lowest point so far, w: second lowest, v: previous value of w
+        let mut x = a + GOLDEN_SECTION * (b - a);
+        let (mut w, mut v) = (x, x);
+        let mut f_x = (self.f)(x);
+        algo_metrics.func_evals += 1;
+        let (mut f_w, mut f_v) = (f_x, f_x);
+
+        // d: current step, e: step before the last one
+        let mut d: f64 = 0.0;
+        let mut e: f64 = 0.0;
+
+        for i in 0..self.iter {
+            let m = 0.5 * (a + b);
+            let tol1 = self.rtol * x.abs() + self.tol / 3.0;
+            let tol2 = 2.0 * tol1;
+
+            // Check for convergence
+            if (x - m).abs() <= tol2 - 0.5 * (b - a) {
+                algo_metrics.iter = i;
+                algo_metrics.est_x = x;
+                algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+                return Ok(algo_metrics);
+            }
+
+            let mut golden = true;
+            if e.abs() > tol1 {
+                // Fit a parabola through x, w and v
+                let r = (x - w) * (f_x - f_v);
+                let mut q = (x - v) * (f_x - f_w);
+                let mut p = (x - v) * q - (x - w) * r;
+                q = 2.0 * (q - r);
+                if q > 0.0 {
+                    p = -p;
+                }
+                q = q.abs();
+                let e_prev = e;
+                e = d;
+
+                // Accept the parabolic step if it falls in (a, b) and is less than half
+                // the step before the last one
+                if p.abs() < (0.5 * q * e_prev).abs() && p > q * (a - x) && p < q * (b - x) {
+                    d = p / q;
+                    let u = x + d;
+                    if u - a < tol2 || b - u < tol2 {
+                        d = tol1.copysign(m - x);
+                    }
+                    golden = false;
+                }
+            }
+            if golden {
+                e = if x >= m { a - x } else { b - x };
+                d = GOLDEN_SECTION * e;
+            }
+
+            // Do not evaluate f closer than tol1 to x
+            let u = if d.abs() >= tol1 {
+                x + d
+            } else {
+                x + tol1.copysign(d)
+            };
+            let f_u = (self.f)(u);
+            algo_metrics.func_evals += 1;
+
+            if f_u <= f_x {
+                if u >= x {
+                    a = x;
+                } else {
+                    b = x;
+                }
+                v = w;
+                f_v = f_w;
+                w = x;
+                f_w = f_x;
+                x = u;
+                f_x = f_u;
+            } else {
+                if u < x {
+                    a = u;
+                } else {
+                    b = u;
+                }
+                if f_u <= f_w || w == x {
+                    v = w;
+                    f_v = f_w;
+                    w = u;
+                    f_w = f_u;
+                } else if f_u <= f_v || v == x || v == w {
+                    v = u;
+                    f_v = f_u;
+                }
+            }
+        }
+
+        algo_metrics.iter = self.iter;
+        algo_metrics.est_x = x;
+        algo_metrics.msg.push_str(MAX_ITER);
+        Err(MinimizationError::IterationLimitExceededError(algo_metrics))
+    }
+}
+
+fn check_tolerances(
+    tol: f64,
+    rtol: f64,
+    algo_metrics: &mut AlgoMetrics,
+) -> Result<(), MinimizationError> {
+    if tol <= 0.0 {
+        algo_metrics
+            .msg
+            .push_str("Value of tol is either negative or zero.");
+        return Err(MinimizationError::UnacceptableToleranceError(
+            std::mem::take(algo_metrics),
+        ));
+    }
+
+    if rtol < DEFAULT_RTOL {
+        algo_metrics
+            .msg
+            .push_str("Value of rtol is either negative or extremely small.");
+        return Err(MinimizationError::UnacceptableToleranceError(
+            std::mem::take(algo_metrics),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,603 @@
+use core::fmt;
+use std::error::Error;
+
+use ndarray::{Array1, Array2};
+use ndarray_linalg::Solve;
+
+use super::root_finding::{MACH_EPS, MAX_ITER, SUCCESS_CONVERGENCE};
+use crate::common::functions::max_norm;
+
+/// Type of the Jacobian of a solver that has not been given one, which is then
+/// approximated with forward finite differences.
+pub type NoJacobian = fn(&Array1<f64>) -> Array2<f64>;
+
+///Output of the solvers of nonlinear systems F(x) = 0.
+/// ## Attributes
+/// - msg:String
+/// - func_evals:u32, evaluations of F, including the ones of finite difference Jacobians
+/// - jac_evals:u32, evaluations of the Jacobian, user supplied or approximated
+/// - iter:usize
+/// - x:Array1<f64>, the last approximation of the root
+/// - f_norm:f64, the maximum norm of F(x)
+#[derive(Debug)]
+pub struct SystemMetrics {
+    pub msg: String,
+    pub func_evals: u32,
+    pub jac_evals: u32,
+    pub iter: usize,
+    pub x: Array1<f64>,
+    pub f_norm: f64,
+}
+
+impl fmt::Display for SystemMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}func_evals={}, jac_evals={}, iter={}, f_norm={:.5e}, x={}",
+            self.msg, self.func_evals, self.jac_evals, self.iter, self.f_norm, self.x
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum NonlinearSystemError {
+    DimensionMismatchError,
+    UnacceptableToleranceError,
+    SingularJacobianError(SystemMetrics),
+    IterationLimitExceededError(SystemMetrics),
+}
+
+impl fmt::Display for NonlinearSystemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NonlinearSystemError::DimensionMismatchError => {
+                write!(
+                    f,
+                    "F(x) and the Jacobian must have as many rows as x has entries."
+                )
+            }
+            NonlinearSystemError::UnacceptableToleranceError => {
+                write!(f, "Value of tol or rtol is either negative or zero.")
+            }
+            NonlinearSystemError::SingularJacobianError(system_metrics) => {
+                write!(
+                    f,
+                    "The Jacobian became singular during computation.\n{}",
+                    system_metrics
+                )
+            }
+            NonlinearSystemError::IterationLimitExceededError(system_metrics) => {
+                write!(
+                    f,
+                    "Maximum number of iterations reached.\n{}",
+                    system_metrics
+                )
+            }
+        }
+    }
+}
+
+impl Error for NonlinearSystemError {}
+
+// Evaluates F or the Jacobian and keeps count of the evaluations
+struct SystemEvaluator<F, J> {
+    f: F,
+    jacobian: Option<J>,
+    system_metrics: SystemMetrics,
+}
+
+impl<F, J> SystemEvaluator<F, J>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+    J: FnMut(&Array1<f64>) -> Array2<f64>,
+{
+    fn new(f: F, jacobian: Option<J>, x0: &Array1<f64>) -> Self {
+        Self {
+            f,
+            jacobian,
+            system_metrics: SystemMetrics {
+                msg: String::new(),
+                func_evals: 0,
+                jac_evals: 0,
+                iter: 0,
+                x: x0.clone(),
+                f_norm: f64::NAN,
+            },
+        }
+    }
+
+    fn eval(&mut self, x: &Array1<f64>) -> Result<Array1<f64>, NonlinearSystemError> {
+        let f_x = (self.f)(x);
+        self.system_metrics.func_evals += 1;
+        if f_x.len() != x.len() {
+            return Err(NonlinearSystemError::DimensionMismatchError);
+        }
+        Ok(f_x)
+    }
+
+    fn jacobian(
+        &mut self,
+        x: &Array1<f64>,
+        f_x: &Array1<f64>,
+    ) -> Result<Array2<f64>, NonlinearSystemError> {
+        self.system_metrics.jac_evals += 1;
+        let n = x.len();
+        if let Some(jacobian) = self.jacobian.as_mut() {
+            let j = jacobian(x);
+            if j.dim() != (n, n) {
+                return Err(NonlinearSystemError::DimensionMismatchError);
+            }
+            return Ok(j);
+        }
+
+        // Forward differences, one column per perturbed variable
+        let mut j = Array2::zeros((n, n));
+        let mut x_h = x.clone();
+        for k in 0..n {
+            let h = MACH_EPS.sqrt() * x[k].abs().max(1.0);
+            x_h[k] = x[k] + h;
+            let f_h = self.eval(&x_h)?;
+            j.column_mut(k).assign(&((f_h - f_x) / h));
+            x_h[k] = x[k];
+        }
+        Ok(j)
+    }
+
+    fn success(mut self, x: Array1<f64>, f_x: &Array1<f64>, iter: usize) -> SystemMetrics {
+        self.system_metrics.iter = iter;
+        self.system_metrics.f_norm = max_norm(f_x);
+        self.system_metrics.x = x;
+        self.system_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+        self.system_metrics
+    }
+
+    fn failure(mut self, x: Array1<f64>, f_x: &Array1<f64>, iter: usize) -> SystemMetrics {
+        self.system_metrics.iter = iter;
+        self.system_metrics.f_norm = max_norm(f_x);
+        self.system_metrics.x = x;
+        self.system_metrics.msg.push_str(MAX_ITER);
+        self.system_metrics
+    }
+}
+
+/// Newton's Method for systems, with a user supplied or finite difference Jacobian.
+/// The step is halved until the Euclidean norm of F decreases, for at most `max_halvings` times.
+pub struct NewtonSystem<F, J = NoJacobian> {
+    f: F,
+    jacobian: Option<J>,
+    x0: Array1<f64>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+    max_halvings: usize,
+}
+
+impl<F> NewtonSystem<F>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+{
+    pub fn initialize(f: F, x0: Array1<f64>) -> Self {
+        Self {
+            f,
+            jacobian: None,
+            x0,
+            tol: 1e-10,
+            rtol: 1e-12,
+            iter: 100,
+            max_halvings: 10,
+        }
+    }
+}
+
+impl<F, J> NewtonSystem<F, J>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+    J: FnMut(&Array1<f64>) -> Array2<f64>,
+{
+    pub fn jacobian<G>(self, jacobian: G) -> NewtonSystem<F, G>
+    where
+        G: FnMut(&Array1<f64>) -> Array2<f64>,
+    {
+        NewtonSystem {
+            f: self.f,
+            jacobian: Some(jacobian),
+            x0: self.x0,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            max_halvings: self.max_halvings,
+        }
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn max_halvings(mut self, max_halvings: usize) -> Self {
+        self.max_halvings = max_halvings;
+        self
+    }
+
+    pub fn run(self) -> Result<SystemMetrics, NonlinearSystemError> {
+        check_tolerances(self.tol, self.rtol)?;
+        let mut evaluator = SystemEvaluator::new(self.f, self.jacobian, &self.x0);
+
+        let mut x = self.x0;
+        let mut f_x = evaluator.eval(&x)?;
+
+        for i in 0..self.iter {
+            if max_norm(&f_x) <= self.tol {
+                return Ok(evaluator.success(x, &f_x, i));
+            }
+
+            let j = evaluator.jacobian(&x, &f_x)?;
+            let dx = match solve_linear(&j, &(-&f_x)) {
+                Some(dx) => dx,
+                None => {
+                    return Err(NonlinearSystemError::SingularJacobianError(
+                        evaluator.failure(x, &f_x, i),
+                    ))
+                }
+            };
+
+            // Backtrack until the residual decreases
+            let f_norm = euclidean_norm(&f_x);
+            let mut lambda = 1.0;
+            let mut x_n = &x + &dx;
+            let mut f_x_n = evaluator.eval(&x_n)?;
+            for _ in 0..self.max_halvings {
+                if euclidean_norm(&f_x_n) < f_norm {
+                    break;
+                }
+                lambda *= 0.5;
+                x_n = &x + &(lambda * &dx);
+                f_x_n = evaluator.eval(&x_n)?;
+            }
+
+            let step = lambda * max_norm(&dx);
+            x = x_n;
+            f_x = f_x_n;
+
+            if step <= self.rtol * max_norm(&x) {
+                if max_norm(&f_x) <= self.tol {
+                    return Ok(evaluator.success(x, &f_x, i + 1));
+                }
+                return Err(NonlinearSystemError::IterationLimitExceededError(
+                    evaluator.failure(x, &f_x, i + 1),
+                ));
+            }
+        }
+
+        if max_norm(&f_x) <= self.tol {
+            return Ok(evaluator.success(x, &f_x, self.iter));
+        }
+        Err(NonlinearSystemError::IterationLimitExceededError(
+            evaluator.failure(x, &f_x, self.iter),
+        ))
+    }
+}
+
+/// Broyden's ("good") Method, which updates an approximation of the Jacobian with rank one
+/// corrections instead of evaluating it at every iteration. The initial Jacobian is the user
+/// supplied one or a finite difference approximation.
+pub struct Broyden<F, J = NoJacobian> {
+    f: F,
+    jacobian: Option<J>,
+    x0: Array1<f64>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+}
+
+impl<F> Broyden<F>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+{
+    pub fn initialize(f: F, x0: Array1<f64>) -> Self {
+        Self {
+            f,
+            jacobian: None,
+            x0,
+            tol: 1e-10,
+            rtol: 1e-12,
+            iter: 200,
+        }
+    }
+}
+
+impl<F, J> Broyden<F, J>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+    J: FnMut(&Array1<f64>) -> Array2<f64>,
+{
+    pub fn jacobian<G>(self, jacobian: G) -> Broyden<F, G>
+    where
+        G: FnMut(&Array1<f64>) -> Array2<f64>,
+    {
+        Broyden {
+            f: self.f,
+            jacobian: Some(jacobian),
+            x0: self.x0,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+        }
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn run(self) -> Result<SystemMetrics, NonlinearSystemError> {
+        check_tolerances(self.tol, self.rtol)?;
+        let mut evaluator = SystemEvaluator::new(self.f, self.jacobian, &self.x0);
+
+        let mut x = self.x0;
+        let mut f_x = evaluator.eval(&x)?;
+        let mut j = evaluator.jacobian(&x, &f_x)?;
+
+        for i in 0..self.iter {
+            if max_norm(&f_x) <= self.tol {
+                return Ok(evaluator.success(x, &f_x, i));
+            }
+
+            let dx = match solve_linear(&j, &(-&f_x)) {
+                Some(dx) => dx,
+                None => {
+                    return Err(NonlinearSystemError::SingularJacobianError(
+                        evaluator.failure(x, &f_x, i),
+                    ))
+                }
+            };
+
+            let x_n = &x + &dx;
+            let f_x_n = evaluator.eval(&x_n)?;
+
+            // J <- J + (dF - J dx) dx^T / (dx^T dx)
+            let dx_dx = dx.dot(&dx);
+            if dx_dx > 0.0 {
+                let correction = (&f_x_n - &f_x - j.dot(&dx)) / dx_dx;
+                for (r, c) in correction.iter().enumerate() {
+                    j.row_mut(r).scaled_add(*c, &dx);
+                }
+            }
+
+            let step = max_norm(&dx);
+            x = x_n;
+            f_x = f_x_n;
+
+            if step <= self.rtol * max_norm(&x) {
+                if max_norm(&f_x) <= self.tol {
+                    return Ok(evaluator.success(x, &f_x, i + 1));
+                }
+                return Err(NonlinearSystemError::IterationLimitExceededError(
+                    evaluator.failure(x, &f_x, i + 1),
+                ));
+            }
+        }
+
+        if max_norm(&f_x) <= self.tol {
+            return Ok(evaluator.success(x, &f_x, self.iter));
+        }
+        Err(NonlinearSystemError::IterationLimitExceededError(
+            evaluator.failure(x, &f_x, self.iter),
+        ))
+    }
+}
+
+/// Powell's Hybrid Method: a trust region method which combines the Gauss-Newton step with the
+/// steepest descent step of 0.5 * |F(x)|^2 along a dogleg path.
+pub struct PowellHybrid<F, J = NoJacobian> {
+    f: F,
+    jacobian: Option<J>,
+    x0: Array1<f64>,
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+    radius: f64,
+}
+
+impl<F> PowellHybrid<F>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+{
+    pub fn initialize(f: F, x0: Array1<f64>) -> Self {
+        Self {
+            f,
+            jacobian: None,
+            x0,
+            tol: 1e-10,
+            rtol: 1e-12,
+            iter: 200,
+            radius: 1.0,
+        }
+    }
+}
+
+impl<F, J> PowellHybrid<F, J>
+where
+    F: FnMut(&Array1<f64>) -> Array1<f64>,
+    J: FnMut(&Array1<f64>) -> Array2<f64>,
+{
+    pub fn jacobian<G>(self, jacobian: G) -> PowellHybrid<F, G>
+    where
+        G: FnMut(&Array1<f64>) -> Array2<f64>,
+    {
+        PowellHybrid {
+            f: self.f,
+            jacobian: Some(jacobian),
+            x0: self.x0,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            radius: self.radius,
+        }
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    /// Initial radius of the trust region.
+    pub fn radius(mut self, radius: f64) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    pub fn run(self) -> Result<SystemMetrics, NonlinearSystemError> {
+        check_tolerances(self.tol, self.rtol)?;
+        if self.radius <= 0.0 {
+            return Err(NonlinearSystemError::UnacceptableToleranceError);
+        }
+        let mut evaluator = SystemEvaluator::new(self.f, self.jacobian, &self.x0);
+
+        let mut radius = self.radius;
+        let mut x = self.x0;
+        let mut f_x = evaluator.eval(&x)?;
+        let mut j = evaluator.jacobian(&x, &f_x)?;
+
+        for i in 0..self.iter {
+            if max_norm(&f_x) <= self.tol {
+                return Ok(evaluator.success(x, &f_x, i));
+            }
+
+            // Steepest descent direction of 0.5 * |F|^2 and the Cauchy point along it
+            let gradient = j.t().dot(&f_x);
+            let j_gradient = j.dot(&gradient);
+            let gradient_norm = euclidean_norm(&gradient);
+            if gradient_norm == 0.0 {
+                return Err(NonlinearSystemError::SingularJacobianError(
+                    evaluator.failure(x, &f_x, i),
+                ));
+            }
+            let cauchy = -(gradient_norm.powi(2)
+                / j_gradient.dot(&j_gradient).max(f64::MIN_POSITIVE))
+                * &gradient;
+
+            let dx = match solve_linear(&j, &(-&f_x)) {
+                Some(gauss_newton) => dogleg(&gauss_newton, &cauchy, radius),
+                None => {
+                    let cauchy_norm = euclidean_norm(&cauchy);
+                    if cauchy_norm > radius {
+                        (radius / cauchy_norm) * &cauchy
+                    } else {
+                        cauchy
+                    }
+                }
+            };
+
+            let x_n = &x + &dx;
+            let f_x_n = evaluator.eval(&x_n)?;
+
+            // Ratio of the actual to the predicted reduction of |F|^2
+            let f_norm_sq = f_x.dot(&f_x);
+            let predicted = &f_x + &j.dot(&dx);
+            let predicted_reduction = f_norm_sq - predicted.dot(&predicted);
+            let actual_reduction = f_norm_sq - f_x_n.dot(&f_x_n);
+            let rho = if predicted_reduction > 0.0 {
+                actual_reduction / predicted_reduction
+            } else {
+                -1.0
+            };
+
+            let step = euclidean_norm(&dx);
+            if rho < 0.25 {
+                radius = 0.25 * step;
+            } else if rho > 0.75 && step >= 0.99 * radius {
+                radius *= 2.0;
+            }
+
+            if rho > 1e-4 {
+                x = x_n;
+                f_x = f_x_n;
+                j = evaluator.jacobian(&x, &f_x)?;
+            }
+
+            if radius <= self.rtol * euclidean_norm(&x).max(self.rtol) {
+                if max_norm(&f_x) <= self.tol {
+                    return Ok(evaluator.success(x, &f_x, i + 1));
+                }
+                return Err(NonlinearSystemError::IterationLimitExceededError(
+                    evaluator.failure(x, &f_x, i + 1),
+                ));
+            }
+        }
+
+        if max_norm(&f_x) <= self.tol {
+            return Ok(evaluator.success(x, &f_x, self.iter));
+        }
+        Err(NonlinearSystemError::IterationLimitExceededError(
+            evaluator.failure(x, &f_x, self.iter),
+        ))
+    }
+}
+
+// Point of the dogleg path from the Cauchy point to the Gauss-Newton step at distance radius
+fn dogleg(gauss_newton: &Array1<f64>, cauchy: &Array1<f64>, radius: f64) -> Array1<f64> {
+    if euclidean_norm(gauss_newton) <= radius {
+        return gauss_newton.clone();
+    }
+
+    let cauchy_norm = euclidean_norm(cauchy);
+    if cauchy_norm >= radius {
+        return (radius / cauchy_norm) * cauchy;
+    }
+
+    // Solve |cauchy + t (gauss_newton - cauchy)| = radius for t in [0, 1]
+    let d = gauss_newton - cauchy;
+    let a = d.dot(&d);
+    let b = 2.0 * cauchy.dot(&d);
+    let c = cauchy_norm.powi(2) - radius.powi(2);
+    let t = (-b + (b * b - 4.0 * a * c).sqrt()) / (2.0 * a);
+    cauchy + &(t * &d)
+}
+
+fn solve_linear(a: &Array2<f64>, b: &Array1<f64>) -> Option<Array1<f64>> {
+    a.solve(b)
+        .ok()
+        .filter(|x| x.iter().all(|value| value.is_finite()))
+}
+
+fn check_tolerances(tol: f64, rtol: f64) -> Result<(), NonlinearSystemError> {
+    if tol <= 0.0 || rtol <= 0.0 {
+        return Err(NonlinearSystemError::UnacceptableToleranceError);
+    }
+    Ok(())
+}
+
+fn euclidean_norm(x: &Array1<f64>) -> f64 {
+    x.dot(x).sqrt()
+}
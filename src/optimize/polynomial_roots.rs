@@ -0,0 +1,282 @@
+use core::fmt;
+use std::error::Error;
+
+use num_complex::Complex64;
+
+use super::root_finding::{Newton, DEFAULT_RTOL, MACH_EPS, MAX_ITER, SUCCESS_CONVERGENCE};
+use crate::special::polynomials::poly_evaluate;
+
+///Output of the polynomial root finder.
+/// ## Attributes
+/// - msg:String
+/// - func_evals:u32, evaluations of the polynomial and of its derivative
+/// - iter:usize, iterations of the simultaneous Aberth-Ehrlich iteration
+/// - roots:Vec<Complex64>, all roots counted with multiplicity and sorted by real and imaginary part
+#[derive(Debug)]
+pub struct PolynomialRoots {
+    pub msg: String,
+    pub func_evals: u32,
+    pub iter: usize,
+    pub roots: Vec<Complex64>,
+}
+
+impl PolynomialRoots {
+    /// Real parts of the roots whose imaginary part is at most `tol` in absolute value.
+    pub fn real_roots(&self, tol: f64) -> Vec<f64> {
+        self.roots
+            .iter()
+            .filter(|root| root.im.abs() <= tol)
+            .map(|root| root.re)
+            .collect()
+    }
+}
+
+impl fmt::Display for PolynomialRoots {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{}func_evals={}, iter={}, roots={:?}",
+            self.msg, self.func_evals, self.iter, self.roots
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum PolynomialRootsError {
+    ZeroPolynomialError,
+    UnacceptableToleranceError,
+    IterationLimitExceededError(PolynomialRoots),
+}
+
+impl fmt::Display for PolynomialRootsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolynomialRootsError::ZeroPolynomialError => {
+                write!(f, "All coefficients of the polynomial are zero.")
+            }
+            PolynomialRootsError::UnacceptableToleranceError => {
+                write!(f, "Value of tol or rtol is either negative or too small.")
+            }
+            PolynomialRootsError::IterationLimitExceededError(polynomial_roots) => {
+                write!(
+                    f,
+                    "Maximum number of iterations reached.\n{}",
+                    polynomial_roots
+                )
+            }
+        }
+    }
+}
+
+impl Error for PolynomialRootsError {}
+
+/// Finds all complex roots of a polynomial with the Aberth-Ehrlich Method.
+/// The coefficients are ordered from the constant term to the highest power,
+/// as produced by `special::polynomials`.
+/// With `polish(true)` the real roots are refined with `Newton` and the complex ones
+/// with Newton steps in complex arithmetic.
+pub struct Aberth<'a> {
+    coefficients: &'a [f64],
+    tol: f64,
+    rtol: f64,
+    iter: usize,
+    polish: bool,
+}
+
+impl<'a> Aberth<'a> {
+    pub fn initialize(coefficients: &'a [f64]) -> Self {
+        Self {
+            coefficients,
+            tol: 1e-12,
+            rtol: DEFAULT_RTOL,
+            iter: 500,
+            polish: false,
+        }
+    }
+
+    pub fn tol(mut self, tol: f64) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: f64) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn polish(mut self, polish: bool) -> Self {
+        self.polish = polish;
+        self
+    }
+
+    pub fn run(self) -> Result<PolynomialRoots, PolynomialRootsError> {
+        if self.tol <= 0.0 || self.rtol < DEFAULT_RTOL {
+            return Err(PolynomialRootsError::UnacceptableToleranceError);
+        }
+
+        // Drop vanishing leading coefficients, and factor out the roots at zero
+        let degree = match self.coefficients.iter().rposition(|&c| c != 0.0) {
+            Some(degree) => degree,
+            None => return Err(PolynomialRootsError::ZeroPolynomialError),
+        };
+        let zero_roots = self.coefficients.iter().position(|&c| c != 0.0).unwrap();
+        let coefficients = self.coefficients[zero_roots..=degree].to_vec();
+        let n = coefficients.len() - 1;
+
+        let mut polynomial_roots = PolynomialRoots {
+            msg: String::new(),
+            func_evals: 0,
+            iter: 0,
+            roots: vec![Complex64::new(0.0, 0.0); zero_roots],
+        };
+
+        if n == 0 {
+            polynomial_roots.msg.push_str(SUCCESS_CONVERGENCE);
+            return Ok(polynomial_roots);
+        }
+
+        // Initial guesses on a circle whose radius is the geometric mean of the root moduli,
+        // rotated so that none of them lies on the real axis
+        let radius = (coefficients[0].abs() / coefficients[n].abs()).powf(1.0 / n as f64);
+        let mut z: Vec<Complex64> = (0..n)
+            .map(|k| {
+                let angle = 2.0 * std::f64::consts::PI * k as f64 / n as f64
+                    + std::f64::consts::FRAC_PI_2 / n as f64;
+                Complex64::from_polar(radius, angle)
+            })
+            .collect();
+        let mut converged = vec![false; n];
+
+        let mut iterations = 0;
+        while iterations < self.iter && converged.contains(&false) {
+            for i in 0..n {
+                if converged[i] {
+                    continue;
+                }
+
+                let (p, dp, bound) = horner(&coefficients, z[i]);
+                polynomial_roots.func_evals += 2;
+
+                // The value is below the rounding error of its evaluation
+                if p.norm() <= 4.0 * MACH_EPS * bound {
+                    converged[i] = true;
+                    continue;
+                }
+
+                let repulsion: Complex64 = (0..n)
+                    .filter(|&j| j != i)
+                    .map(|j| (z[i] - z[j]).inv())
+                    .sum();
+                let step = (dp / p - repulsion).inv();
+                if !step.is_finite() {
+                    continue;
+                }
+
+                z[i] -= step;
+                if step.norm() <= self.tol + self.rtol * z[i].norm() {
+                    converged[i] = true;
+                }
+            }
+            iterations += 1;
+        }
+
+        polynomial_roots.iter = iterations;
+        polynomial_roots.roots.extend(z);
+        sort_roots(&mut polynomial_roots.roots);
+
+        if converged.contains(&false) {
+            polynomial_roots.msg.push_str(MAX_ITER);
+            return Err(PolynomialRootsError::IterationLimitExceededError(
+                polynomial_roots,
+            ));
+        }
+
+        if self.polish {
+            self.polish_roots(&coefficients, &mut polynomial_roots);
+            sort_roots(&mut polynomial_roots.roots);
+        }
+
+        polynomial_roots.msg.push_str(SUCCESS_CONVERGENCE);
+        Ok(polynomial_roots)
+    }
+
+    fn polish_roots(&self, coefficients: &[f64], polynomial_roots: &mut PolynomialRoots) {
+        let derivative: Vec<f64> = coefficients
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(k, &c)| k as f64 * c)
+            .collect();
+
+        for root in polynomial_roots.roots.iter_mut() {
+            if *root == Complex64::new(0.0, 0.0) {
+                continue;
+            }
+
+            // Roots that are real up to rounding are refined on the real line
+            if root.im.abs() <= MACH_EPS.sqrt() * root.norm().max(1.0) {
+                let newton = Newton::initialize(|x| poly_evaluate(coefficients, x), root.re)
+                    .fp(|x| poly_evaluate(&derivative, x))
+                    .tol(self.tol)
+                    .rtol(self.rtol)
+                    .iter(self.iter)
+                    .run();
+                if let Ok(algo_metrics) = newton {
+                    polynomial_roots.func_evals += algo_metrics.func_evals;
+                    let x = Complex64::new(algo_metrics.est_x, 0.0);
+                    if x.is_finite()
+                        && horner(coefficients, x).0.norm() <= horner(coefficients, *root).0.norm()
+                    {
+                        *root = x;
+                    }
+                }
+                continue;
+            }
+
+            for _ in 0..self.iter {
+                let (p, dp, _) = horner(coefficients, *root);
+                polynomial_roots.func_evals += 2;
+                let step = p / dp;
+                if !step.is_finite() {
+                    break;
+                }
+                *root -= step;
+                if step.norm() <= self.tol + self.rtol * root.norm() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Finds all complex roots of the polynomial with the given coefficients, ordered from the
+/// constant term to the highest power, using the default settings of `Aberth`.
+pub fn polynomial_roots(coefficients: &[f64]) -> Result<PolynomialRoots, PolynomialRootsError> {
+    Aberth::initialize(coefficients).polish(true).run()
+}
+
+// Evaluates the polynomial and its derivative at z, along with a bound of the rounding error
+// of the evaluation
+fn horner(coefficients: &[f64], z: Complex64) -> (Complex64, Complex64, f64) {
+    let n = coefficients.len() - 1;
+    let mut p = Complex64::new(coefficients[n], 0.0);
+    let mut dp = Complex64::new(0.0, 0.0);
+    let mut bound = coefficients[n].abs();
+    let modulus = z.norm();
+
+    for &c in coefficients[..n].iter().rev() {
+        dp = dp * z + p;
+        p = p * z + c;
+        bound = bound * modulus + c.abs();
+    }
+    (p, dp, bound)
+}
+
+fn sort_roots(roots: &mut [Complex64]) {
+    roots.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+}
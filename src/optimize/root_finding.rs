@@ -1,20 +1,48 @@
 use core::fmt;
+use std::error::Error;
 
-static MACH_EPS: f64 = f64::EPSILON;
-static DEFAULT_RTOL: f64 = 4.0 * MACH_EPS;
+use super::bracket::{expand_bracket, Bracket, DEFAULT_EXPANSION_FACTOR, DEFAULT_EXPANSION_TRIES};
+use crate::common::float::Real;
 
-static SUCCESS_CONVERGENCE: &str =
+pub(crate) static MACH_EPS: f64 = f64::EPSILON;
+pub(crate) static DEFAULT_RTOL: f64 = 4.0 * MACH_EPS;
+
+pub(crate) static SUCCESS_CONVERGENCE: &str =
     "The algorithm achieved convergence with the specified tolerance.\n";
-static MAX_ITER: &str = "Variable est_x is the last approximation made by the algorithm.\n";
+pub(crate) static MAX_ITER: &str =
+    "Variable est_x is the last approximation made by the algorithm.\n";
+static OBSERVER_STOP: &str =
+    "Variable est_x is the approximation at the iteration the observer stopped.\n";
 
-pub struct AlgoMetrics {
+#[derive(Debug)]
+pub struct AlgoMetrics<T = f64> {
     pub msg: String,
     pub func_evals: u32,
     pub iter: usize,
-    pub est_x: f64,
+    pub est_x: T,
+    /// States of every iteration, only filled in when `record_history(true)` is set on the solver
+    pub history: Vec<IterationState<T>>,
+}
+
+impl<T> Default for AlgoMetrics<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        Self {
+            msg: String::from(""),
+            func_evals: 0,
+            iter: 0,
+            est_x: T::nan(),
+            history: Vec::new(),
+        }
+    }
 }
 
-impl fmt::Display for AlgoMetrics {
+impl<T> fmt::Display for AlgoMetrics<T>
+where
+    T: fmt::Display,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(
             f,
@@ -24,16 +52,96 @@ impl fmt::Display for AlgoMetrics {
     }
 }
 
-pub enum RootFindingError {
+/// State of a solver at one iteration, passed to the observer and stored in the history.
+/// - x, f_x: the point evaluated in this iteration and its function value
+/// - bracket: the interval known to contain the root, for bracketing methods
+/// - step: the change of the root estimate made in this iteration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IterationState<T = f64> {
+    pub iter: usize,
+    pub x: T,
+    pub f_x: T,
+    pub bracket: Option<(T, T)>,
+    pub step: T,
+}
+
+/// Returned by an observer to let the solver continue or to stop it early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObserverAction {
+    Continue,
+    Stop,
+}
+
+/// Type of the observer of a solver that has not been given one.
+pub type NoObserver<T = f64> = fn(&IterationState<T>) -> ObserverAction;
+
+struct Monitor<O> {
+    observer: Option<O>,
+    record_history: bool,
+}
+
+impl<T> Monitor<NoObserver<T>> {
+    fn new() -> Self {
+        Self {
+            observer: None,
+            record_history: false,
+        }
+    }
+}
+
+impl<O> Monitor<O> {
+    fn with_observer<G>(self, observer: G) -> Monitor<G> {
+        Monitor {
+            observer: Some(observer),
+            record_history: self.record_history,
+        }
+    }
+
+    // Records the state if requested and fails with the metrics so far if the observer stops the solver
+    fn notify<T>(
+        &mut self,
+        state: IterationState<T>,
+        algo_metrics: &mut AlgoMetrics<T>,
+    ) -> Result<(), RootFindingError<T>>
+    where
+        T: Real,
+        O: FnMut(&IterationState<T>) -> ObserverAction,
+    {
+        if self.record_history {
+            algo_metrics.history.push(state);
+        }
+        let action = match self.observer.as_mut() {
+            Some(observer) => observer(&state),
+            None => ObserverAction::Continue,
+        };
+        if action == ObserverAction::Stop {
+            algo_metrics.iter = state.iter;
+            algo_metrics.est_x = state.x;
+            algo_metrics.msg.push_str(OBSERVER_STOP);
+            return Err(RootFindingError::EarlyTerminationError(std::mem::take(
+                algo_metrics,
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum RootFindingError<T = f64> {
     SignAgreementError,
-    NonConvergenceError(AlgoMetrics),
-    ZeroDerivativeError(AlgoMetrics),
+    NonConvergenceError(AlgoMetrics<T>),
+    ZeroDerivativeError(AlgoMetrics<T>),
     IdenticalInitialGuessesError,
-    UnacceptableToleranceError(AlgoMetrics),
-    IterationLimitExceededError(AlgoMetrics),
+    MissingDerivativeError,
+    UnacceptableToleranceError(AlgoMetrics<T>),
+    IterationLimitExceededError(AlgoMetrics<T>),
+    EarlyTerminationError(AlgoMetrics<T>),
 }
 
-impl fmt::Display for RootFindingError {
+impl<T> fmt::Display for RootFindingError<T>
+where
+    T: fmt::Display,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             RootFindingError::SignAgreementError => {
@@ -52,6 +160,12 @@ impl fmt::Display for RootFindingError {
             RootFindingError::IdenticalInitialGuessesError => {
                 write!(f, "Initial guesses x0 and x1 cannot be identical.")
             }
+            RootFindingError::MissingDerivativeError => {
+                write!(
+                    f,
+                    "Newton's Method needs f prime, the Secant Method does not."
+                )
+            }
             RootFindingError::UnacceptableToleranceError(algo_metrics) => {
                 write!(
                     f,
@@ -62,35 +176,150 @@ impl fmt::Display for RootFindingError {
             RootFindingError::IterationLimitExceededError(algo_metrics) => {
                 write!(f, "Maximum number of iterations reached.\n{}", algo_metrics)
             }
+            RootFindingError::EarlyTerminationError(algo_metrics) => {
+                write!(f, "The observer stopped the algorithm.\n{}", algo_metrics)
+            }
         }
     }
 }
 
-pub struct Bisection {
-    f: fn(f64) -> f64,
+impl<T> Error for RootFindingError<T> where T: fmt::Debug + fmt::Display {}
+
+/// Common interface of the scalar root finders, so that a solver can be chosen at runtime
+/// and used behind a `Box<dyn RootFinder>`. The tolerances have their own type `Tol`, which is
+/// the real type of the norm the solver measures the step with, e.g. f64 for Complex64.
+pub trait RootFinder<T = f64, Tol = T> {
+    fn solve(&mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>>;
+    fn set_tol(&mut self, tol: Tol);
+    fn set_rtol(&mut self, rtol: Tol);
+    fn set_iter(&mut self, iter: usize);
+}
+
+/// Root finders that start from an interval [a, b] on which f changes sign.
+pub trait BracketingRootFinder<F, T = f64>: RootFinder<T> {
+    fn from_bracket(f: F, a: T, b: T) -> Self
+    where
+        Self: Sized;
+}
+
+/// Root finders that start from an initial guess x0.
+pub trait OpenRootFinder<F, T = f64>: RootFinder<T> {
+    fn from_guess(f: F, x0: T) -> Self
+    where
+        Self: Sized;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RootFindingMethod {
+    Bisection,
+    Ridders,
+    Brent,
+    Newton,
+    Secant,
+}
+
+impl RootFindingMethod {
+    pub fn is_bracketing(&self) -> bool {
+        !matches!(self, RootFindingMethod::Newton | RootFindingMethod::Secant)
+    }
+}
+
+/// Builds the root finder selected by `method`. Bracketing methods search the interval [a, b],
+/// while the Secant Method uses a and b as its two initial guesses. Newton's Method needs a
+/// derivative, so it is rejected with `MissingDerivativeError`, see `root_finder_with_derivative`.
+pub fn root_finder<'a, F>(
+    method: RootFindingMethod,
+    f: F,
     a: f64,
     b: f64,
-    tol: f64,
-    rtol: f64,
+) -> Result<Box<dyn RootFinder + 'a>, RootFindingError>
+where
+    F: FnMut(f64) -> f64 + 'a,
+{
+    match method {
+        RootFindingMethod::Bisection => Ok(Box::new(Bisection::initialize(f, a, b))),
+        RootFindingMethod::Ridders => Ok(Box::new(Ridders::initialize(f, a, b))),
+        RootFindingMethod::Brent => Ok(Box::new(Brent::initialize(f, a, b))),
+        RootFindingMethod::Newton => Err(RootFindingError::MissingDerivativeError),
+        RootFindingMethod::Secant => Ok(Box::new(Secant::initialize(f, a).x1(b))),
+    }
+}
+
+/// Builds the root finder selected by `method` like `root_finder`, giving the derivative fp of f
+/// to Newton's Method, which starts from a. The other methods do not use fp.
+pub fn root_finder_with_derivative<'a, F, FP>(
+    method: RootFindingMethod,
+    f: F,
+    fp: FP,
+    a: f64,
+    b: f64,
+) -> Box<dyn RootFinder + 'a>
+where
+    F: FnMut(f64) -> f64 + 'a,
+    FP: FnMut(f64) -> f64 + 'a,
+{
+    match method {
+        RootFindingMethod::Bisection => Box::new(Bisection::initialize(f, a, b)),
+        RootFindingMethod::Ridders => Box::new(Ridders::initialize(f, a, b)),
+        RootFindingMethod::Brent => Box::new(Brent::initialize(f, a, b)),
+        RootFindingMethod::Newton => Box::new(Newton::initialize(f, a).fp(fp)),
+        RootFindingMethod::Secant => Box::new(Secant::initialize(f, a).x1(b)),
+    }
+}
+
+/// The solvers are generic over the floating-point type T, see [`Real`]. `initialize` builds
+/// them for f64, while `new` accepts any `Real` type, which is inferred from the initial values,
+/// e.g. `Bisection::new(|x: f32| x * x - 2.0, 0.0f32, 2.0)`.
+pub struct Bisection<F, O = NoObserver, T = f64> {
+    f: F,
+    a: T,
+    b: T,
+    tol: T,
+    rtol: T,
     iter: usize,
+    expand_bracket: bool,
+    monitor: Monitor<O>,
+}
+
+impl<F> Bisection<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self::new(f, a, b)
+    }
 }
 
-impl Bisection {
-    pub fn initialize(f: fn(f64) -> f64, a: f64, b: f64) -> Self {
+impl<F, T> Bisection<F, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    pub fn new(f: F, a: T, b: T) -> Self {
         Self {
             f,
             a,
             b,
-            tol: 1e-8,
+            tol: T::default_tol(),
             iter: 100,
-            rtol: DEFAULT_RTOL,
+            rtol: T::default_rtol(),
+            expand_bracket: false,
+            monitor: Monitor::new(),
         }
     }
-    pub fn tol(mut self, tol: f64) -> Self {
+}
+
+impl<F, O, T> Bisection<F, O, T>
+where
+    F: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    pub fn tol(mut self, tol: T) -> Self {
         self.tol = tol;
         self
     }
-    pub fn rtol(mut self, rtol: f64) -> Self {
+    pub fn rtol(mut self, rtol: T) -> Self {
         self.rtol = rtol;
         self
     }
@@ -100,45 +329,86 @@ impl Bisection {
         self
     }
 
-    pub fn run(self) -> Result<AlgoMetrics, RootFindingError> {
-        let mut algo_metrics = AlgoMetrics {
-            est_x: f64::NAN,
-            msg: String::from(""),
-            func_evals: 0,
-            iter: 0,
-        };
+    /// If f does not change sign on [a, b], the interval is first expanded outwards
+    /// until it does, see [`BracketExpander`](super::bracket::BracketExpander).
+    pub fn expand_bracket(mut self, expand_bracket: bool) -> Self {
+        self.expand_bracket = expand_bracket;
+        self
+    }
 
-        if self.tol <= 0.0 {
-            algo_metrics
-                .msg
-                .push_str("Value of tol is either negative or zero.");
-            return Err(RootFindingError::UnacceptableToleranceError(algo_metrics));
+    pub fn observer<G>(self, observer: G) -> Bisection<F, G, T>
+    where
+        G: FnMut(&IterationState<T>) -> ObserverAction,
+    {
+        Bisection {
+            f: self.f,
+            a: self.a,
+            b: self.b,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            expand_bracket: self.expand_bracket,
+            monitor: self.monitor.with_observer(observer),
         }
+    }
 
-        if self.rtol < DEFAULT_RTOL {
-            algo_metrics
-                .msg
-                .push_str("Value of rtol is either negative or extremely small.");
-            return Err(RootFindingError::UnacceptableToleranceError(algo_metrics));
-        }
+    pub fn record_history(mut self, record_history: bool) -> Self {
+        self.monitor.record_history = record_history;
+        self
+    }
 
-        let mut a = self.a;
-        let mut b = self.b;
-        let mut m = a + (b - a) * 0.5;
+    pub fn run(mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        self.solve()
+    }
 
-        let f_a = (self.f)(a);
-        algo_metrics.func_evals += 1;
-        let f_b = (self.f)(b);
-        algo_metrics.func_evals += 1;
+    fn convergence_achieved(&self, a: &T, b: &T, m: &T) -> bool {
+        (*a - *b).abs() < self.tol + self.rtol * *m
+    }
+}
+
+impl<F, T> BracketingRootFinder<F, T> for Bisection<F, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn from_bracket(f: F, a: T, b: T) -> Self {
+        Self::new(f, a, b)
+    }
+}
+
+impl<F, O, T> RootFinder<T> for Bisection<F, O, T>
+where
+    F: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    fn solve(&mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        let mut algo_metrics = AlgoMetrics::default();
+        let half = T::constant(0.5);
+
+        check_tolerances(self.tol, self.rtol, &mut algo_metrics)?;
+
+        let bracket = initial_bracket(
+            &mut self.f,
+            self.a,
+            self.b,
+            self.expand_bracket,
+            &mut algo_metrics,
+        )?;
+        let (mut a, mut b) = (bracket.a, bracket.b);
+        let mut m = a + (b - a) * half;
+
+        let (f_a, f_b) = (bracket.f_a, bracket.f_b);
         let mut f_m;
+        let mut m_prev = a;
 
-        if f_a == 0.0 {
+        if f_a == T::zero() {
             algo_metrics.est_x = a;
             algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
             return Ok(algo_metrics);
         }
 
-        if f_b == 0.0 {
+        if f_b == T::zero() {
             algo_metrics.est_x = b;
             algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
             return Ok(algo_metrics);
@@ -149,7 +419,7 @@ impl Bisection {
         }
 
         for i in 0..self.iter {
-            m = a + (b - a) * 0.5;
+            m = a + (b - a) * half;
             f_m = (self.f)(m);
             algo_metrics.func_evals += 1;
             if f_m.signum() == f_a.signum() {
@@ -158,6 +428,18 @@ impl Bisection {
                 b = m;
             }
 
+            self.monitor.notify(
+                IterationState {
+                    iter: i,
+                    x: m,
+                    f_x: f_m,
+                    bracket: Some((a, b)),
+                    step: m - m_prev,
+                },
+                &mut algo_metrics,
+            )?;
+            m_prev = m;
+
             if self.convergence_achieved(&a, &b, &m) {
                 algo_metrics.iter = i;
                 algo_metrics.est_x = m;
@@ -171,57 +453,368 @@ impl Bisection {
         Err(RootFindingError::IterationLimitExceededError(algo_metrics))
     }
 
-    fn convergence_achieved(&self, a: &f64, b: &f64, m: &f64) -> bool {
-        (a - b).abs() < self.tol + self.rtol * m
+    fn set_tol(&mut self, tol: T) {
+        self.tol = tol;
+    }
+
+    fn set_rtol(&mut self, rtol: T) {
+        self.rtol = rtol;
+    }
+
+    fn set_iter(&mut self, iter: usize) {
+        self.iter = iter;
     }
 }
 
-pub struct Newton {
-    f: fn(f64) -> f64,
-    fp: Option<fn(f64) -> f64>,
-    fdp: Option<fn(f64) -> f64>,
-    x0: f64,
-    x1: Option<f64>,
-    tol: f64,
-    rtol: f64,
+/// Newton's Method, which becomes Halley's Method if f double prime is given as well.
+/// f prime must be given with `fp`, otherwise the solver fails with `MissingDerivativeError`;
+/// use `Secant` when no derivative is available. Exact derivatives of a function written
+/// for any `common::dual::DualNum` are obtained with `derivative` and `second_derivative`.
+pub struct Newton<F, FP = fn(f64) -> f64, FDP = fn(f64) -> f64, O = NoObserver, T = f64> {
+    f: F,
+    fp: Option<FP>,
+    fdp: Option<FDP>,
+    x0: T,
+    tol: T,
+    rtol: T,
     iter: usize,
+    monitor: Monitor<O>,
+}
+
+impl<F> Newton<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, x0: f64) -> Self {
+        Self::new(f, x0)
+    }
 }
 
-impl Newton {
-    pub fn initialize(f: fn(f64) -> f64, x0: f64) -> Self {
+impl<F, T> Newton<F, fn(T) -> T, fn(T) -> T, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    pub fn new(f: F, x0: T) -> Self {
         Self {
             f,
             fp: None,
             fdp: None,
             x0,
+            tol: T::default_tol(),
+            iter: 100,
+            rtol: T::default_rtol(),
+            monitor: Monitor::new(),
+        }
+    }
+}
+
+impl<F, FP, FDP, O, T> Newton<F, FP, FDP, O, T>
+where
+    F: FnMut(T) -> T,
+    FP: FnMut(T) -> T,
+    FDP: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    pub fn fp<G>(self, fp: G) -> Newton<F, G, FDP, O, T>
+    where
+        G: FnMut(T) -> T,
+    {
+        Newton {
+            f: self.f,
+            fp: Some(fp),
+            fdp: self.fdp,
+            x0: self.x0,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            monitor: self.monitor,
+        }
+    }
+
+    pub fn fdp<G>(self, fdp: G) -> Newton<F, FP, G, O, T>
+    where
+        G: FnMut(T) -> T,
+    {
+        Newton {
+            f: self.f,
+            fp: self.fp,
+            fdp: Some(fdp),
+            x0: self.x0,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            monitor: self.monitor,
+        }
+    }
+
+    pub fn tol(mut self, tol: T) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: T) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn observer<G>(self, observer: G) -> Newton<F, FP, FDP, G, T>
+    where
+        G: FnMut(&IterationState<T>) -> ObserverAction,
+    {
+        Newton {
+            f: self.f,
+            fp: self.fp,
+            fdp: self.fdp,
+            x0: self.x0,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            monitor: self.monitor.with_observer(observer),
+        }
+    }
+
+    pub fn record_history(mut self, record_history: bool) -> Self {
+        self.monitor.record_history = record_history;
+        self
+    }
+
+    pub fn run(mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        self.solve()
+    }
+}
+
+impl<F, FP, FDP, O, T> RootFinder<T> for Newton<F, FP, FDP, O, T>
+where
+    F: FnMut(T) -> T,
+    FP: FnMut(T) -> T,
+    FDP: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    fn solve(&mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        match self.fp.as_mut() {
+            Some(f_prime) => newton_raphson(
+                &mut self.f,
+                f_prime,
+                self.fdp.as_mut(),
+                self.x0,
+                self.tol,
+                self.rtol,
+                self.iter,
+                &mut self.monitor,
+            ),
+            None => Err(RootFindingError::MissingDerivativeError),
+        }
+    }
+
+    fn set_tol(&mut self, tol: T) {
+        self.tol = tol;
+    }
+
+    fn set_rtol(&mut self, rtol: T) {
+        self.rtol = rtol;
+    }
+
+    fn set_iter(&mut self, iter: usize) {
+        self.iter = iter;
+    }
+}
+
+/// Secant Method, started from x0 and x1 or from a small perturbation of x0 if x1 is not given.
+pub struct Secant<F, O = NoObserver, T = f64> {
+    f: F,
+    x0: T,
+    x1: Option<T>,
+    tol: T,
+    rtol: T,
+    iter: usize,
+    monitor: Monitor<O>,
+}
+
+impl<F> Secant<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, x0: f64) -> Self {
+        Self::new(f, x0)
+    }
+}
+
+impl<F, T> Secant<F, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    pub fn new(f: F, x0: T) -> Self {
+        Self {
+            f,
+            x0,
             x1: None,
-            tol: 1e-8,
+            tol: T::default_tol(),
             iter: 100,
-            rtol: DEFAULT_RTOL,
+            rtol: T::default_rtol(),
+            monitor: Monitor::new(),
         }
     }
+}
 
-    pub fn x1(mut self, x1: f64) -> Self {
+impl<F, O, T> Secant<F, O, T>
+where
+    F: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    pub fn x1(mut self, x1: T) -> Self {
         self.x1 = Some(x1);
         self
     }
 
-    pub fn fp(mut self, fp: fn(f64) -> f64) -> Self {
-        self.fp = Some(fp);
+    pub fn tol(mut self, tol: T) -> Self {
+        self.tol = tol;
         self
     }
 
-    pub fn fdp(mut self, fdp: fn(f64) -> f64) -> Self {
-        self.fdp = Some(fdp);
+    pub fn rtol(mut self, rtol: T) -> Self {
+        self.rtol = rtol;
         self
     }
 
-    pub fn tol(mut self, tol: f64) -> Self {
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn observer<G>(self, observer: G) -> Secant<F, G, T>
+    where
+        G: FnMut(&IterationState<T>) -> ObserverAction,
+    {
+        Secant {
+            f: self.f,
+            x0: self.x0,
+            x1: self.x1,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            monitor: self.monitor.with_observer(observer),
+        }
+    }
+
+    pub fn record_history(mut self, record_history: bool) -> Self {
+        self.monitor.record_history = record_history;
+        self
+    }
+
+    pub fn run(mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        self.solve()
+    }
+}
+
+impl<F, T> OpenRootFinder<F, T> for Secant<F, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn from_guess(f: F, x0: T) -> Self {
+        Self::new(f, x0)
+    }
+}
+
+impl<F, O, T> RootFinder<T> for Secant<F, O, T>
+where
+    F: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    fn solve(&mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        secant(
+            &mut self.f,
+            self.x0,
+            self.x1,
+            self.tol,
+            self.rtol,
+            self.iter,
+            &mut self.monitor,
+        )
+    }
+
+    fn set_tol(&mut self, tol: T) {
+        self.tol = tol;
+    }
+
+    fn set_rtol(&mut self, rtol: T) {
+        self.rtol = rtol;
+    }
+
+    fn set_iter(&mut self, iter: usize) {
+        self.iter = iter;
+    }
+}
+
+/// Halley's Method, which uses the first and second derivative of f for cubic convergence.
+pub struct Halley<F, FP, FDP, O = NoObserver, T = f64> {
+    f: F,
+    fp: FP,
+    fdp: FDP,
+    x0: T,
+    tol: T,
+    rtol: T,
+    iter: usize,
+    monitor: Monitor<O>,
+}
+
+impl<F, FP, FDP> Halley<F, FP, FDP>
+where
+    F: FnMut(f64) -> f64,
+    FP: FnMut(f64) -> f64,
+    FDP: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, fp: FP, fdp: FDP, x0: f64) -> Self {
+        Self::new(f, fp, fdp, x0)
+    }
+}
+
+impl<F, FP, FDP, T> Halley<F, FP, FDP, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    FP: FnMut(T) -> T,
+    FDP: FnMut(T) -> T,
+    T: Real,
+{
+    pub fn new(f: F, fp: FP, fdp: FDP, x0: T) -> Self {
+        Self {
+            f,
+            fp,
+            fdp,
+            x0,
+            tol: T::default_tol(),
+            iter: 100,
+            rtol: T::default_rtol(),
+            monitor: Monitor::new(),
+        }
+    }
+}
+
+impl<F, FP, FDP, O, T> Halley<F, FP, FDP, O, T>
+where
+    F: FnMut(T) -> T,
+    FP: FnMut(T) -> T,
+    FDP: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    pub fn tol(mut self, tol: T) -> Self {
         self.tol = tol;
         self
     }
 
-    pub fn rtol(mut self, rtol: f64) -> Self {
+    pub fn rtol(mut self, rtol: T) -> Self {
         self.rtol = rtol;
         self
     }
@@ -231,185 +824,587 @@ impl Newton {
         self
     }
 
-    pub fn run(self) -> Result<AlgoMetrics, RootFindingError> {
-        let mut algo_metrics = AlgoMetrics {
-            est_x: f64::NAN,
-            msg: String::from(""),
-            func_evals: 0,
-            iter: 0,
+    pub fn observer<G>(self, observer: G) -> Halley<F, FP, FDP, G, T>
+    where
+        G: FnMut(&IterationState<T>) -> ObserverAction,
+    {
+        Halley {
+            f: self.f,
+            fp: self.fp,
+            fdp: self.fdp,
+            x0: self.x0,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            monitor: self.monitor.with_observer(observer),
+        }
+    }
+
+    pub fn record_history(mut self, record_history: bool) -> Self {
+        self.monitor.record_history = record_history;
+        self
+    }
+
+    pub fn run(mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        self.solve()
+    }
+}
+
+impl<F, FP, FDP, O, T> RootFinder<T> for Halley<F, FP, FDP, O, T>
+where
+    F: FnMut(T) -> T,
+    FP: FnMut(T) -> T,
+    FDP: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    fn solve(&mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        newton_raphson(
+            &mut self.f,
+            &mut self.fp,
+            Some(&mut self.fdp),
+            self.x0,
+            self.tol,
+            self.rtol,
+            self.iter,
+            &mut self.monitor,
+        )
+    }
+
+    fn set_tol(&mut self, tol: T) {
+        self.tol = tol;
+    }
+
+    fn set_rtol(&mut self, rtol: T) {
+        self.rtol = rtol;
+    }
+
+    fn set_iter(&mut self, iter: usize) {
+        self.iter = iter;
+    }
+}
+
+/// Safeguarded Newton-Raphson Method on an interval [a, b] on which f changes sign.
+/// A bisection step is taken whenever the Newton step would leave the bracket
+/// or would not reduce the bracket fast enough.
+pub struct NewtonBracketed<F, FP, O = NoObserver, T = f64> {
+    f: F,
+    fp: FP,
+    a: T,
+    b: T,
+    tol: T,
+    rtol: T,
+    iter: usize,
+    monitor: Monitor<O>,
+}
+
+impl<F, FP> NewtonBracketed<F, FP>
+where
+    F: FnMut(f64) -> f64,
+    FP: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, fp: FP, a: f64, b: f64) -> Self {
+        Self::new(f, fp, a, b)
+    }
+}
+
+impl<F, FP, T> NewtonBracketed<F, FP, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    FP: FnMut(T) -> T,
+    T: Real,
+{
+    pub fn new(f: F, fp: FP, a: T, b: T) -> Self {
+        Self {
+            f,
+            fp,
+            a,
+            b,
+            tol: T::default_tol(),
+            iter: 100,
+            rtol: T::default_rtol(),
+            monitor: Monitor::new(),
+        }
+    }
+}
+
+impl<F, FP, O, T> NewtonBracketed<F, FP, O, T>
+where
+    F: FnMut(T) -> T,
+    FP: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    pub fn tol(mut self, tol: T) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    pub fn rtol(mut self, rtol: T) -> Self {
+        self.rtol = rtol;
+        self
+    }
+
+    pub fn iter(mut self, iter: usize) -> Self {
+        self.iter = iter;
+        self
+    }
+
+    pub fn observer<G>(self, observer: G) -> NewtonBracketed<F, FP, G, T>
+    where
+        G: FnMut(&IterationState<T>) -> ObserverAction,
+    {
+        NewtonBracketed {
+            f: self.f,
+            fp: self.fp,
+            a: self.a,
+            b: self.b,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            monitor: self.monitor.with_observer(observer),
+        }
+    }
+
+    pub fn record_history(mut self, record_history: bool) -> Self {
+        self.monitor.record_history = record_history;
+        self
+    }
+
+    pub fn run(mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        self.solve()
+    }
+}
+
+impl<F, FP, O, T> RootFinder<T> for NewtonBracketed<F, FP, O, T>
+where
+    F: FnMut(T) -> T,
+    FP: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    fn solve(&mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        let mut algo_metrics = AlgoMetrics::default();
+        check_tolerances(self.tol, self.rtol, &mut algo_metrics)?;
+        let zero = T::zero();
+        let half = T::constant(0.5);
+        let two = T::constant(2.0);
+
+        let f_a = (self.f)(self.a);
+        algo_metrics.func_evals += 1;
+        let f_b = (self.f)(self.b);
+        algo_metrics.func_evals += 1;
+
+        if f_a == zero {
+            algo_metrics.est_x = self.a;
+            algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+            return Ok(algo_metrics);
+        }
+
+        if f_b == zero {
+            algo_metrics.est_x = self.b;
+            algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+            return Ok(algo_metrics);
+        }
+
+        if f_a.signum() == f_b.signum() {
+            return Err(RootFindingError::SignAgreementError);
+        }
+
+        // Orient the bracket so that f(low) < 0 < f(high)
+        let (mut low, mut high) = if f_a < zero {
+            (self.a, self.b)
+        } else {
+            (self.b, self.a)
         };
 
-        if self.tol <= 0.0 {
-            algo_metrics
-                .msg
-                .push_str("Value of tol is either negative or zero.");
-            return Err(RootFindingError::UnacceptableToleranceError(algo_metrics));
-        }
-
-        if self.rtol < DEFAULT_RTOL {
-            algo_metrics
-                .msg
-                .push_str("Value of rtol is either negative or extremely small.");
-            return Err(RootFindingError::UnacceptableToleranceError(algo_metrics));
-        }
-
-        let mut x = self.x0;
-
-        match &self.fp {
-            // If f prime is given, proceed with the Newton-Raphson Method
-            Some(f_prime) => {
-                let mut x_n;
-                let mut f_x = (self.f)(x);
-                algo_metrics.func_evals += 1;
-                let mut f_prime_x = f_prime(x);
-                algo_metrics.func_evals += 1;
-                let mut newton_step;
-
-                for i in 0..self.iter {
-                    // If root has been found, terminate
-                    if f_x == 0.0 {
-                        algo_metrics.iter = i;
-                        algo_metrics.est_x = x;
-                        algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
-                        return Ok(algo_metrics);
-                    }
-
-                    if f_prime_x == 0.0 {
-                        algo_metrics.iter = i;
-                        algo_metrics.est_x = x;
-                        return Err(RootFindingError::ZeroDerivativeError(algo_metrics));
-                    }
-
-                    newton_step = f_x / f_prime_x;
-
-                    match &self.fdp {
-                        // If f double prime is given, use Halley's Method
-                        Some(f_double_prime) => {
-                            let f_d_prime_x = f_double_prime(x);
-                            algo_metrics.func_evals += 1;
-                            let adjustment = newton_step * f_d_prime_x / f_prime_x / 2.0;
-                            if adjustment.abs() < 1.0 {
-                                newton_step /= 1.0 - adjustment;
-                            }
-                        }
-                        None => {}
-                    }
-
-                    x_n = x - newton_step;
-
-                    // Check for convergence
-                    if precision_equals(x, x_n, self.tol, self.rtol) {
-                        algo_metrics.est_x = x;
-                        algo_metrics.iter = i;
-                        algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
-                        return Ok(algo_metrics);
-                    }
-
-                    // Update variables
-                    x = x_n;
-                    f_x = (self.f)(x);
-                    algo_metrics.func_evals += 1;
-                    f_prime_x = f_prime(x);
-                    algo_metrics.func_evals += 1;
-                }
+        let mut x = half * (self.a + self.b);
+        let mut prev_step = (self.b - self.a).abs();
+        let mut step = prev_step;
+        let mut f_x = (self.f)(x);
+        algo_metrics.func_evals += 1;
+        let mut f_prime_x = (self.fp)(x);
+        algo_metrics.func_evals += 1;
+
+        for i in 0..self.iter {
+            if f_x == zero {
+                algo_metrics.iter = i;
                 algo_metrics.est_x = x;
-                algo_metrics.iter = self.iter;
-                algo_metrics.msg.push_str(MAX_ITER);
-                Err(RootFindingError::IterationLimitExceededError(algo_metrics))
+                algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+                return Ok(algo_metrics);
             }
-            // In case f prime is not given, proceed with Secant Method
-            None => {
-                let mut p0 = self.x0;
-                let mut p1;
-
-                match self.x1 {
-                    Some(x1) => {
-                        if x1 == self.x0 {
-                            return Err(RootFindingError::IdenticalInitialGuessesError);
-                        }
-                        p1 = x1;
-                    }
-                    None => {
-                        let delta = 1e-4;
-                        p1 = p0 * (1.0 + delta);
-                        p1 += if p1 >= 0.0 { delta } else { -delta }
-                    }
-                }
 
-                let mut f_p0 = (self.f)(p0);
-                algo_metrics.func_evals += 1;
-                let mut f_p1 = (self.f)(p1);
-                algo_metrics.func_evals += 1;
-                if f_p1.abs() < f_p0.abs() {
-                    std::mem::swap(&mut p0, &mut p1);
-                    std::mem::swap(&mut f_p0, &mut f_p1);
-                }
-                let mut p = p0;
-                for i in 0..self.iter {
-                    // If function values are not the same, we have not converged yet
-                    if f_p0 != f_p1 {
-                        if f_p1.abs() > f_p0.abs() {
-                            p = (-f_p0 / f_p1 * p1 + p0) / (1.0 - f_p0 / f_p1);
-                        } else {
-                            p = (-f_p1 / f_p0 * p0 + p1) / (1.0 - f_p1 / f_p0);
-                        }
-                    } else {
-                        // If function values are the same, Secant cannot continue because denominator is zero
-                        algo_metrics.msg.push_str("Cannot apply secant step because denominator became zero during computation.");
-                        return Err(RootFindingError::NonConvergenceError(algo_metrics));
-                    }
-                    // Check for convergence
-                    if precision_equals(p, p1, self.tol, self.rtol) {
-                        algo_metrics.iter = i;
-                        algo_metrics.est_x = p;
-                        algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
-                        return Ok(algo_metrics);
-                    }
-                    p0 = p1;
-                    f_p0 = f_p1;
-                    p1 = p;
-                    f_p1 = (self.f)(p1);
-                    algo_metrics.func_evals += 1;
-                }
+            // Shrink the bracket around the current iterate
+            if f_x < zero {
+                low = x;
+            } else {
+                high = x;
+            }
+
+            // Bisect if the Newton step leaves the bracket or if the step size
+            // is not decreasing fast enough
+            let x_n;
+            let leaves_bracket =
+                ((x - high) * f_prime_x - f_x) * ((x - low) * f_prime_x - f_x) > zero;
+            if leaves_bracket || (two * f_x).abs() > (prev_step * f_prime_x).abs() {
+                prev_step = step;
+                step = half * (high - low);
+                x_n = low + step;
+            } else {
+                prev_step = step;
+                step = f_x / f_prime_x;
+                x_n = x - step;
+            }
+
+            self.monitor.notify(
+                IterationState {
+                    iter: i,
+                    x,
+                    f_x,
+                    bracket: Some((low.min(high), low.max(high))),
+                    step: x_n - x,
+                },
+                &mut algo_metrics,
+            )?;
+
+            // Check for convergence
+            if precision_equals(x_n, x, self.tol, self.rtol) {
+                algo_metrics.iter = i;
+                algo_metrics.est_x = x_n;
+                algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+                return Ok(algo_metrics);
+            }
+
+            // Update variables
+            x = x_n;
+            f_x = (self.f)(x);
+            algo_metrics.func_evals += 1;
+            f_prime_x = (self.fp)(x);
+            algo_metrics.func_evals += 1;
+        }
+
+        algo_metrics.iter = self.iter;
+        algo_metrics.est_x = x;
+        algo_metrics.msg.push_str(MAX_ITER);
+        Err(RootFindingError::IterationLimitExceededError(algo_metrics))
+    }
+
+    fn set_tol(&mut self, tol: T) {
+        self.tol = tol;
+    }
+
+    fn set_rtol(&mut self, rtol: T) {
+        self.rtol = rtol;
+    }
+
+    fn set_iter(&mut self, iter: usize) {
+        self.iter = iter;
+    }
+}
+
+// Returns the interval a bracketing solver starts from with the values of f at its end points,
+// expanded until f changes sign on it when requested and the initial one is not a bracket
+fn initial_bracket<F, T>(
+    f: &mut F,
+    a: T,
+    b: T,
+    expand: bool,
+    algo_metrics: &mut AlgoMetrics<T>,
+) -> Result<Bracket<T>, RootFindingError<T>>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    let bracket = if expand {
+        expand_bracket(
+            f,
+            a,
+            b,
+            T::constant(DEFAULT_EXPANSION_FACTOR),
+            DEFAULT_EXPANSION_TRIES,
+        )?
+    } else {
+        Bracket {
+            a,
+            b,
+            f_a: f(a),
+            f_b: f(b),
+            func_evals: 2,
+        }
+    };
+    algo_metrics.func_evals += bracket.func_evals;
+    Ok(bracket)
+}
+
+// Checks the tolerances shared by all solvers, recording the reason of a failure in the metrics
+fn check_tolerances<T>(
+    tol: T,
+    rtol: T,
+    algo_metrics: &mut AlgoMetrics<T>,
+) -> Result<(), RootFindingError<T>>
+where
+    T: Real,
+{
+    if tol <= T::zero() {
+        algo_metrics
+            .msg
+            .push_str("Value of tol is either negative or zero.");
+        return Err(RootFindingError::UnacceptableToleranceError(
+            std::mem::take(algo_metrics),
+        ));
+    }
+
+    if rtol < T::default_rtol() {
+        algo_metrics
+            .msg
+            .push_str("Value of rtol is either negative or extremely small.");
+        return Err(RootFindingError::UnacceptableToleranceError(
+            std::mem::take(algo_metrics),
+        ));
+    }
+    Ok(())
+}
+
+// Newton-Raphson iterations, which become Halley's Method when f double prime is given
+#[allow(clippy::too_many_arguments)]
+fn newton_raphson<F, FP, FDP, O, T>(
+    f: &mut F,
+    f_prime: &mut FP,
+    mut f_double_prime: Option<&mut FDP>,
+    x0: T,
+    tol: T,
+    rtol: T,
+    iter: usize,
+    monitor: &mut Monitor<O>,
+) -> Result<AlgoMetrics<T>, RootFindingError<T>>
+where
+    F: FnMut(T) -> T,
+    FP: FnMut(T) -> T,
+    FDP: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    let mut algo_metrics = AlgoMetrics::default();
+    check_tolerances(tol, rtol, &mut algo_metrics)?;
+
+    let mut x = x0;
+    let mut x_n;
+    let mut f_x = f(x);
+    algo_metrics.func_evals += 1;
+    let mut f_prime_x = f_prime(x);
+    algo_metrics.func_evals += 1;
+    let mut newton_step;
+
+    for i in 0..iter {
+        // If root has been found, terminate
+        if f_x == T::zero() {
+            algo_metrics.iter = i;
+            algo_metrics.est_x = x;
+            algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+            return Ok(algo_metrics);
+        }
 
-                algo_metrics.est_x = p;
-                algo_metrics.msg.push_str(MAX_ITER);
-                algo_metrics.iter = self.iter;
-                Err(RootFindingError::IterationLimitExceededError(algo_metrics))
+        if f_prime_x == T::zero() {
+            algo_metrics.iter = i;
+            algo_metrics.est_x = x;
+            return Err(RootFindingError::ZeroDerivativeError(algo_metrics));
+        }
+
+        newton_step = f_x / f_prime_x;
+
+        // If f double prime is given, use Halley's Method
+        if let Some(f_double_prime) = f_double_prime.as_mut() {
+            let f_d_prime_x = f_double_prime(x);
+            algo_metrics.func_evals += 1;
+            let adjustment = newton_step * f_d_prime_x / f_prime_x / T::constant(2.0);
+            if adjustment.abs() < T::one() {
+                newton_step = newton_step / (T::one() - adjustment);
             }
         }
+
+        x_n = x - newton_step;
+
+        monitor.notify(
+            IterationState {
+                iter: i,
+                x,
+                f_x,
+                bracket: None,
+                step: -newton_step,
+            },
+            &mut algo_metrics,
+        )?;
+
+        // Check for convergence
+        if precision_equals(x, x_n, tol, rtol) {
+            algo_metrics.est_x = x;
+            algo_metrics.iter = i;
+            algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+            return Ok(algo_metrics);
+        }
+
+        // Update variables
+        x = x_n;
+        f_x = f(x);
+        algo_metrics.func_evals += 1;
+        f_prime_x = f_prime(x);
+        algo_metrics.func_evals += 1;
     }
+    algo_metrics.est_x = x;
+    algo_metrics.iter = iter;
+    algo_metrics.msg.push_str(MAX_ITER);
+    Err(RootFindingError::IterationLimitExceededError(algo_metrics))
 }
 
-pub fn precision_equals(x1: f64, x2: f64, tol: f64, rtol: f64) -> bool {
+// Secant iterations starting from x0 and x1, or from a small perturbation of x0
+fn secant<F, O, T>(
+    f: &mut F,
+    x0: T,
+    x1: Option<T>,
+    tol: T,
+    rtol: T,
+    iter: usize,
+    monitor: &mut Monitor<O>,
+) -> Result<AlgoMetrics<T>, RootFindingError<T>>
+where
+    F: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    let mut algo_metrics = AlgoMetrics::default();
+    check_tolerances(tol, rtol, &mut algo_metrics)?;
+    let one = T::one();
+
+    let mut p0 = x0;
+    let mut p1;
+
+    match x1 {
+        Some(x1) => {
+            if x1 == x0 {
+                return Err(RootFindingError::IdenticalInitialGuessesError);
+            }
+            p1 = x1;
+        }
+        None => {
+            let delta = T::constant(1e-4);
+            p1 = p0 * (one + delta);
+            p1 = p1 + if p1 >= T::zero() { delta } else { -delta }
+        }
+    }
+
+    let mut f_p0 = f(p0);
+    algo_metrics.func_evals += 1;
+    let mut f_p1 = f(p1);
+    algo_metrics.func_evals += 1;
+    if f_p1.abs() < f_p0.abs() {
+        std::mem::swap(&mut p0, &mut p1);
+        std::mem::swap(&mut f_p0, &mut f_p1);
+    }
+    let mut p = p0;
+    for i in 0..iter {
+        // If function values are not the same, we have not converged yet
+        if f_p0 != f_p1 {
+            if f_p1.abs() > f_p0.abs() {
+                p = (-f_p0 / f_p1 * p1 + p0) / (one - f_p0 / f_p1);
+            } else {
+                p = (-f_p1 / f_p0 * p0 + p1) / (one - f_p1 / f_p0);
+            }
+        } else {
+            // If function values are the same, Secant cannot continue because denominator is zero
+            algo_metrics.msg.push_str(
+                "Cannot apply secant step because denominator became zero during computation.",
+            );
+            return Err(RootFindingError::NonConvergenceError(algo_metrics));
+        }
+
+        monitor.notify(
+            IterationState {
+                iter: i,
+                x: p1,
+                f_x: f_p1,
+                bracket: None,
+                step: p - p1,
+            },
+            &mut algo_metrics,
+        )?;
+
+        // Check for convergence
+        if precision_equals(p, p1, tol, rtol) {
+            algo_metrics.iter = i;
+            algo_metrics.est_x = p;
+            algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
+            return Ok(algo_metrics);
+        }
+        p0 = p1;
+        f_p0 = f_p1;
+        p1 = p;
+        f_p1 = f(p1);
+        algo_metrics.func_evals += 1;
+    }
+
+    algo_metrics.est_x = p;
+    algo_metrics.msg.push_str(MAX_ITER);
+    algo_metrics.iter = iter;
+    Err(RootFindingError::IterationLimitExceededError(algo_metrics))
+}
+
+pub fn precision_equals<T>(x1: T, x2: T, tol: T, rtol: T) -> bool
+where
+    T: Real,
+{
     (x1 - x2).abs() <= tol + rtol * x2.abs()
 }
 
-pub struct Ridders {
-    f: fn(f64) -> f64,
-    a: f64,
-    b: f64,
-    tol: f64,
-    rtol: f64,
+pub struct Ridders<F, O = NoObserver, T = f64> {
+    f: F,
+    a: T,
+    b: T,
+    tol: T,
+    rtol: T,
     iter: usize,
+    expand_bracket: bool,
+    monitor: Monitor<O>,
+}
+
+impl<F> Ridders<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self::new(f, a, b)
+    }
 }
 
-impl Ridders {
-    pub fn initialize(f: fn(f64) -> f64, a: f64, b: f64) -> Self {
+impl<F, T> Ridders<F, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    pub fn new(f: F, a: T, b: T) -> Self {
         Self {
             f,
             a,
             b,
-            tol: 1e-8,
+            tol: T::default_tol(),
             iter: 100,
-            rtol: DEFAULT_RTOL,
+            rtol: T::default_rtol(),
+            expand_bracket: false,
+            monitor: Monitor::new(),
         }
     }
-    pub fn tol(mut self, tol: f64) -> Self {
+}
+
+impl<F, O, T> Ridders<F, O, T>
+where
+    F: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    pub fn tol(mut self, tol: T) -> Self {
         self.tol = tol;
         self
     }
-    pub fn rtol(mut self, rtol: f64) -> Self {
+    pub fn rtol(mut self, rtol: T) -> Self {
         self.rtol = rtol;
         self
     }
@@ -419,49 +1414,86 @@ impl Ridders {
         self
     }
 
-    pub fn run(self) -> Result<AlgoMetrics, RootFindingError> {
-        // Initialize metrics for the algorithm
-        let mut algo_metrics = AlgoMetrics {
-            est_x: f64::NAN,
-            msg: String::from(""),
-            func_evals: 0,
-            iter: 0,
-        };
+    /// If f does not change sign on [a, b], the interval is first expanded outwards
+    /// until it does, see [`BracketExpander`](super::bracket::BracketExpander).
+    pub fn expand_bracket(mut self, expand_bracket: bool) -> Self {
+        self.expand_bracket = expand_bracket;
+        self
+    }
 
-        // Check for acceptable tolerances
-        if self.tol <= 0.0 {
-            algo_metrics
-                .msg
-                .push_str("Value of tol is either negative or zero.");
-            return Err(RootFindingError::UnacceptableToleranceError(algo_metrics));
+    pub fn observer<G>(self, observer: G) -> Ridders<F, G, T>
+    where
+        G: FnMut(&IterationState<T>) -> ObserverAction,
+    {
+        Ridders {
+            f: self.f,
+            a: self.a,
+            b: self.b,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            expand_bracket: self.expand_bracket,
+            monitor: self.monitor.with_observer(observer),
         }
+    }
 
-        if self.rtol < DEFAULT_RTOL {
-            algo_metrics
-                .msg
-                .push_str("Value of rtol is either negative or extremely small.");
-            return Err(RootFindingError::UnacceptableToleranceError(algo_metrics));
-        }
+    pub fn record_history(mut self, record_history: bool) -> Self {
+        self.monitor.record_history = record_history;
+        self
+    }
+
+    pub fn run(mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        self.solve()
+    }
+}
+
+impl<F, T> BracketingRootFinder<F, T> for Ridders<F, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn from_bracket(f: F, a: T, b: T) -> Self {
+        Self::new(f, a, b)
+    }
+}
 
-        let mut a = self.a;
-        let mut b = self.b;
+impl<F, O, T> RootFinder<T> for Ridders<F, O, T>
+where
+    F: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    fn solve(&mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        // Initialize metrics for the algorithm
+        let mut algo_metrics = AlgoMetrics::default();
+        let zero = T::zero();
+
+        // Check for acceptable tolerances
+        check_tolerances(self.tol, self.rtol, &mut algo_metrics)?;
+
+        let bracket = initial_bracket(
+            &mut self.f,
+            self.a,
+            self.b,
+            self.expand_bracket,
+            &mut algo_metrics,
+        )?;
+        let (mut a, mut b) = (bracket.a, bracket.b);
         let mut m;
-        let mut x_prev = f64::MAX; // To track previous x value
+        let mut x_prev = T::max_value(); // To track previous x value
+        let mut x_last = a; // To report the step of the first iteration
 
-        let mut f_a = (self.f)(a);
-        algo_metrics.func_evals += 1;
-        let mut f_b = (self.f)(b);
-        algo_metrics.func_evals += 1;
+        let (mut f_a, mut f_b) = (bracket.f_a, bracket.f_b);
         let mut f_m;
 
         // Check if either boundary is a root
-        if f_a == 0.0 {
+        if f_a == zero {
             algo_metrics.est_x = a;
             algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
             return Ok(algo_metrics);
         }
 
-        if f_b == 0.0 {
+        if f_b == zero {
             algo_metrics.est_x = b;
             algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
             return Ok(algo_metrics);
@@ -474,13 +1506,13 @@ impl Ridders {
 
         // Main iteration loop
         for i in 0..self.iter {
-            m = 0.5 * (a + b); // Update midpoint
+            m = T::constant(0.5) * (a + b); // Update midpoint
             f_m = (self.f)(m);
             algo_metrics.func_evals += 1;
 
             // Calculate 's' for Ridders' formula
-            let s = f64::sqrt(f_m.powi(2) - f_a * f_b);
-            if s == 0.0 {
+            let s = (f_m.powi(2) - f_a * f_b).sqrt();
+            if s == zero {
                 // Denominator became zero, non-convergence
                 algo_metrics.msg.push_str("Cannot apply Ridders' step because denominator became zero during computation.");
                 algo_metrics.iter = i;
@@ -488,13 +1520,25 @@ impl Ridders {
             }
             // Calculate dx and x using Ridders' formula
             let mut dx = (m - a) * f_m / s;
-            if (f_a - f_b) < 0.0 {
+            if (f_a - f_b) < zero {
                 dx = -dx;
             }
             let x = m + dx;
             let f_x = (self.f)(x);
             algo_metrics.func_evals += 1;
 
+            self.monitor.notify(
+                IterationState {
+                    iter: i,
+                    x,
+                    f_x,
+                    bracket: Some((a.min(b), a.max(b))),
+                    step: x - x_last,
+                },
+                &mut algo_metrics,
+            )?;
+            x_last = x;
+
             // Check for convergence
             if precision_equals(x, x_prev, self.tol, self.rtol) {
                 algo_metrics.iter = i;
@@ -527,33 +1571,70 @@ impl Ridders {
         algo_metrics.msg.push_str(MAX_ITER);
         Err(RootFindingError::IterationLimitExceededError(algo_metrics))
     }
+
+    fn set_tol(&mut self, tol: T) {
+        self.tol = tol;
+    }
+
+    fn set_rtol(&mut self, rtol: T) {
+        self.rtol = rtol;
+    }
+
+    fn set_iter(&mut self, iter: usize) {
+        self.iter = iter;
+    }
 }
 
-pub struct Brent {
-    f: fn(f64) -> f64,
-    a: f64,
-    b: f64,
-    tol: f64,
-    rtol: f64,
+pub struct Brent<F, O = NoObserver, T = f64> {
+    f: F,
+    a: T,
+    b: T,
+    tol: T,
+    rtol: T,
     iter: usize,
+    expand_bracket: bool,
+    monitor: Monitor<O>,
 }
 
-impl Brent {
-    pub fn initialize(f: fn(f64) -> f64, a: f64, b: f64) -> Self {
+impl<F> Brent<F>
+where
+    F: FnMut(f64) -> f64,
+{
+    pub fn initialize(f: F, a: f64, b: f64) -> Self {
+        Self::new(f, a, b)
+    }
+}
+
+impl<F, T> Brent<F, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    pub fn new(f: F, a: T, b: T) -> Self {
         Self {
             f,
             a,
             b,
-            tol: 1e-8,
+            tol: T::default_tol(),
             iter: 100,
-            rtol: DEFAULT_RTOL,
+            rtol: T::default_rtol(),
+            expand_bracket: false,
+            monitor: Monitor::new(),
         }
     }
-    pub fn tol(mut self, tol: f64) -> Self {
+}
+
+impl<F, O, T> Brent<F, O, T>
+where
+    F: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    pub fn tol(mut self, tol: T) -> Self {
         self.tol = tol;
         self
     }
-    pub fn rtol(mut self, rtol: f64) -> Self {
+    pub fn rtol(mut self, rtol: T) -> Self {
         self.rtol = rtol;
         self
     }
@@ -563,47 +1644,90 @@ impl Brent {
         self
     }
 
-    pub fn run(self) -> Result<AlgoMetrics, RootFindingError> {
-        let mut algo_metrics = AlgoMetrics {
-            est_x: f64::NAN,
-            msg: String::from(""),
-            func_evals: 0,
-            iter: 0,
-        };
+    /// If f does not change sign on [a, b], the interval is first expanded outwards
+    /// until it does, see [`BracketExpander`](super::bracket::BracketExpander).
+    pub fn expand_bracket(mut self, expand_bracket: bool) -> Self {
+        self.expand_bracket = expand_bracket;
+        self
+    }
 
-        if self.tol <= 0.0 {
-            algo_metrics
-                .msg
-                .push_str("Value of tol is either negative or zero.");
-            return Err(RootFindingError::UnacceptableToleranceError(algo_metrics));
+    pub fn observer<G>(self, observer: G) -> Brent<F, G, T>
+    where
+        G: FnMut(&IterationState<T>) -> ObserverAction,
+    {
+        Brent {
+            f: self.f,
+            a: self.a,
+            b: self.b,
+            tol: self.tol,
+            rtol: self.rtol,
+            iter: self.iter,
+            expand_bracket: self.expand_bracket,
+            monitor: self.monitor.with_observer(observer),
         }
+    }
 
-        if self.rtol < DEFAULT_RTOL {
-            algo_metrics
-                .msg
-                .push_str("Value of rtol is either negative or extremely small.");
-            return Err(RootFindingError::UnacceptableToleranceError(algo_metrics));
-        }
+    pub fn record_history(mut self, record_history: bool) -> Self {
+        self.monitor.record_history = record_history;
+        self
+    }
 
-        let mut a = self.a;
-        let mut b = self.b;
-        let mut f_a = (self.f)(a);
-        algo_metrics.func_evals += 1;
-        let mut f_b = (self.f)(b);
-        algo_metrics.func_evals += 1;
+    pub fn run(mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        self.solve()
+    }
+}
+
+impl<F, T> BracketingRootFinder<F, T> for Brent<F, NoObserver<T>, T>
+where
+    F: FnMut(T) -> T,
+    T: Real,
+{
+    fn from_bracket(f: F, a: T, b: T) -> Self {
+        Self::new(f, a, b)
+    }
+}
 
-        if precision_equals(f_a, 0.0, self.tol, self.rtol) {
+impl<F, O, T> RootFinder<T> for Brent<F, O, T>
+where
+    F: FnMut(T) -> T,
+    O: FnMut(&IterationState<T>) -> ObserverAction,
+    T: Real,
+{
+    fn solve(&mut self) -> Result<AlgoMetrics<T>, RootFindingError<T>> {
+        let mut algo_metrics = AlgoMetrics::default();
+        let zero = T::zero();
+        let one = T::one();
+        let half = T::constant(0.5);
+        let two = T::constant(2.0);
+
+        check_tolerances(self.tol, self.rtol, &mut algo_metrics)?;
+
+        let bracket = initial_bracket(
+            &mut self.f,
+            self.a,
+            self.b,
+            self.expand_bracket,
+            &mut algo_metrics,
+        )?;
+        let (mut a, mut b) = (bracket.a, bracket.b);
+        let (mut f_a, mut f_b) = (bracket.f_a, bracket.f_b);
+
+        if precision_equals(f_a, zero, self.tol, self.rtol) {
             algo_metrics.est_x = a;
             algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
             return Ok(algo_metrics);
         }
 
-        if precision_equals(f_b, 0.0, self.tol, self.rtol) {
+        if precision_equals(f_b, zero, self.tol, self.rtol) {
             algo_metrics.est_x = b;
             algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
             return Ok(algo_metrics);
         }
 
+        if f_a.signum() == f_b.signum() {
+            return Err(RootFindingError::SignAgreementError);
+        }
+
         let mut last_bracket = a;
         let mut f_last_bracket = f_a;
         let mut last_interval_size = b - a;
@@ -629,12 +1753,12 @@ impl Brent {
             }
 
             // Calculate effective tolerance and midpoint
-            effective_tol = self.tol + 2.0 * self.rtol * b.abs();
-            m = 0.5 * (last_bracket - b);
+            effective_tol = self.tol + two * self.rtol * b.abs();
+            m = half * (last_bracket - b);
 
             // If the absolute value of the midpoint is less than or equal to the effective tolerance,
             // or if f_b is zero, then a root has been found. Return b.
-            if m.abs() <= effective_tol || precision_equals(f_b, 0.0, self.tol, self.rtol) {
+            if m.abs() <= effective_tol || precision_equals(f_b, zero, self.tol, self.rtol) {
                 algo_metrics.est_x = b;
                 algo_metrics.iter = i;
                 algo_metrics.msg.push_str(SUCCESS_CONVERGENCE);
@@ -650,17 +1774,17 @@ impl Brent {
                 s = f_b / f_a;
                 if a == last_bracket {
                     // Do linear interpolation
-                    p = 2.0 * m * s;
-                    q = 1.0 - s;
+                    p = two * m * s;
+                    q = one - s;
                 } else {
                     // Do inverse quadratic interpolation
                     q = f_a / f_last_bracket;
                     r = f_b / f_last_bracket;
-                    p = s * (2.0 * m * q * (q - r) - (b - a) * (r - 1.0));
-                    q = (q - 1.0) * (r - 1.0) * (s - 1.0);
+                    p = s * (two * m * q * (q - r) - (b - a) * (r - one));
+                    q = (q - one) * (r - one) * (s - one);
                 }
 
-                if p > 0.0 {
+                if p > zero {
                     q = -q;
                 } else {
                     p = -p;
@@ -671,7 +1795,9 @@ impl Brent {
                 // We evaluate whether the interpolation is likely to be beneficial. If the calculated p is
                 // too large compared to the midpoint and the effective tolerance, or if it's larger than half
                 // of the previous interval size multiplied by q, we decide that interpolation isn't helping us much.
-                if (p >= 1.5 * m * q - (effective_tol * q).abs()) || (p >= (0.5 * s * q).abs()) {
+                if (p >= T::constant(1.5) * m * q - (effective_tol * q).abs())
+                    || (p >= (half * s * q).abs())
+                {
                     last_interval_size = m;
                     prev_interval_size = last_interval_size;
                 } else {
@@ -681,24 +1807,50 @@ impl Brent {
             a = b;
             f_a = f_b;
             if last_interval_size.abs() > effective_tol {
-                b += last_interval_size;
-            } else if m > 0.0 {
-                b += effective_tol;
+                b = b + last_interval_size;
+            } else if m > zero {
+                b = b + effective_tol;
             } else {
-                b -= effective_tol;
+                b = b - effective_tol;
             }
 
             f_b = (self.f)(b);
             algo_metrics.func_evals += 1;
 
-            if (f_b > 0.0 && f_last_bracket > 0.0) || (f_b <= 0.0 && f_last_bracket <= 0.0) {
+            if (f_b > zero && f_last_bracket > zero) || (f_b <= zero && f_last_bracket <= zero) {
                 last_bracket = a;
                 f_last_bracket = f_a;
                 last_interval_size = b - a;
                 prev_interval_size = last_interval_size;
             }
+
+            self.monitor.notify(
+                IterationState {
+                    iter: i,
+                    x: b,
+                    f_x: f_b,
+                    bracket: Some((b.min(last_bracket), b.max(last_bracket))),
+                    step: b - a,
+                },
+                &mut algo_metrics,
+            )?;
         }
 
+        algo_metrics.iter = self.iter;
+        algo_metrics.est_x = b;
+        algo_metrics.msg.push_str(MAX_ITER);
         Err(RootFindingError::IterationLimitExceededError(algo_metrics))
     }
+
+    fn set_tol(&mut self, tol: T) {
+        self.tol = tol;
+    }
+
+    fn set_rtol(&mut self, rtol: T) {
+        self.rtol = rtol;
+    }
+
+    fn set_iter(&mut self, iter: usize) {
+        self.iter = iter;
+    }
 }
@@ -168,7 +168,7 @@ pub fn alt_sign(number:u64)->i64{
 }
 ///A functions that returns a value of a given polynomial at an input x, 
 /// when its coefficients are given
-pub fn poly_evaluate(coefficients:&Vec<f64>,x:f64)->f64{
+pub fn poly_evaluate(coefficients:&[f64],x:f64)->f64{
 
     let mut result = 0.0;
     let mut x_power = 1.0;
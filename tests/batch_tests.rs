@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod batch_tests {
+    use ndarray::Array1;
+    use numix::optimize::batch::{BatchBrent, BatchError, BatchNewton, RootStatus};
+
+    #[test]
+    fn test_brent_common_bracket() {
+        // x^3 = p for p in [1, 1000]
+        let params = Array1::linspace(1.0, 1000.0, 200);
+        let result = BatchBrent::initialize(|x: f64, p: f64| x.powi(3) - p, &params, 0.0, 11.0)
+            .tol(1e-12)
+            .run();
+
+        match result {
+            Ok(batch_metrics) => {
+                println!("{}", batch_metrics);
+                assert!(batch_metrics.all_converged());
+                for (root, p) in batch_metrics.roots.iter().zip(params.iter()) {
+                    assert!((root - p.cbrt()).abs() < 1e-9);
+                }
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_brent_per_element_status() {
+        // cos(x) = p has no solution for |p| > 1
+        let params = Array1::from(vec![0.5, 2.0, -0.5, 0.0]);
+        let a = Array1::zeros(4);
+        let b = Array1::from_elem(4, std::f64::consts::PI);
+        let result = BatchBrent::initialize(|x: f64, p: f64| x.cos() - p, &params, 0.0, 1.0)
+            .brackets(a, b)
+            .run();
+
+        match result {
+            Ok(batch_metrics) => {
+                assert_eq!(batch_metrics.converged_count(), 3);
+                assert_eq!(batch_metrics.status[1], RootStatus::SignAgreement);
+                assert!(batch_metrics.roots[1].is_nan());
+                for i in [0, 2, 3] {
+                    assert!((batch_metrics.roots[i] - params[i].acos()).abs() < 1e-8);
+                }
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_brent_dimension_mismatch() {
+        let params = Array1::from(vec![1.0, 2.0]);
+        let result = BatchBrent::initialize(|x: f64, p: f64| x - p, &params, 0.0, 3.0)
+            .brackets(Array1::zeros(3), Array1::from_elem(3, 3.0))
+            .run();
+        assert!(matches!(result, Err(BatchError::DimensionMismatchError)));
+    }
+
+    #[test]
+    fn test_newton_guesses() {
+        // Kepler's equation E - e sin(E) = M for a range of eccentricities
+        let params = Array1::linspace(0.0, 0.9, 50);
+        let m = 1.2;
+        let result = BatchNewton::initialize(|x: f64, e: f64| x - e * x.sin() - m, &params, m)
+            .fp(|x, e| 1.0 - e * x.cos())
+            .guesses(params.mapv(|e| m + e))
+            .tol(1e-12)
+            .run();
+
+        match result {
+            Ok(batch_metrics) => {
+                assert!(batch_metrics.all_converged());
+                for (x, e) in batch_metrics.roots.iter().zip(params.iter()) {
+                    assert!((x - e * x.sin() - m).abs() < 1e-10);
+                }
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_newton_without_derivative() {
+        let params = Array1::from(vec![2.0, 3.0, 5.0]);
+        let result = BatchNewton::initialize(|x: f64, p: f64| x * x - p, &params, 1.0).run();
+
+        match result {
+            Ok(batch_metrics) => {
+                assert!(batch_metrics.all_converged());
+                for (root, p) in batch_metrics.roots.iter().zip(params.iter()) {
+                    assert!((root - p.sqrt()).abs() < 1e-7);
+                }
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_sequential() {
+        let params = Array1::linspace(0.1, 10.0, 500);
+        let f = |x: f64, p: f64| x.exp() - p * x - 2.0;
+        let sequential = BatchBrent::initialize(f, &params, 0.0, 5.0)
+            .expand_bracket(true)
+            .run();
+        let parallel = BatchBrent::initialize(f, &params, 0.0, 5.0)
+            .expand_bracket(true)
+            .run_parallel();
+
+        match (sequential, parallel) {
+            (Ok(sequential), Ok(parallel)) => {
+                assert_eq!(sequential.status, parallel.status);
+                assert_eq!(sequential.roots, parallel.roots);
+                assert_eq!(sequential.func_evals, parallel.func_evals);
+            }
+            _ => panic!("Test failed due to error"),
+        }
+    }
+}
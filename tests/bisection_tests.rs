@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod bisection_tests {
-    use numix::optimize::root_finding::Ridders;
+    use numix::optimize::root_finding::{Bisection, Ridders};
 
     #[test]
     fn test_quadratic() {
@@ -180,4 +180,21 @@ mod bisection_tests {
             Err(e) => panic!("Test failed due to error: {}", e),
         }
     }
+
+    #[test]
+    fn test_bisection_captured_parameter() {
+        // Solve x^2 - k = 0 for a sweep of k, with k captured by the closure
+        for k in [2.0_f64, 3.0, 5.0, 10.0] {
+            let root = Bisection::initialize(|x| x * x - k, 0.0, 4.0)
+                .tol(1e-10)
+                .run();
+
+            match root {
+                Ok(root) => {
+                    assert!((root.est_x - k.sqrt()).abs() < 1e-8);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
 }
@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod bracket_tests {
+    use numix::optimize::bracket::{find_sign_changes, BracketExpander};
+    use numix::optimize::root_finding::{Bisection, Brent, Ridders, RootFindingError};
+
+    #[test]
+    fn test_expand_interval() {
+        let result = BracketExpander::initialize(|x: f64| x.powi(3) - 100.0, 0.0, 1.0).run();
+
+        match result {
+            Ok(bracket) => {
+                println!("{}", bracket);
+                assert!(bracket.f_a * bracket.f_b <= 0.0);
+                assert!(bracket.a.min(bracket.b) <= 100f64.cbrt());
+                assert!(bracket.a.max(bracket.b) >= 100f64.cbrt());
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_expand_from_guess() {
+        let result = BracketExpander::from_guess(|x: f64| x.exp() - 0.5, 10.0)
+            .factor(2.0)
+            .run();
+
+        match result {
+            Ok(bracket) => {
+                println!("{}", bracket);
+                assert!(bracket.f_a * bracket.f_b <= 0.0);
+                assert!(bracket.a < 0.5f64.ln());
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_expand_no_root() {
+        let result = BracketExpander::initialize(|x: f64| x * x + 1.0, -1.0, 2.0)
+            .iter(20)
+            .run();
+        assert!(matches!(result, Err(RootFindingError::SignAgreementError)));
+    }
+
+    #[test]
+    fn test_solvers_expand_bracket() {
+        let f = |x: f64| x.powi(3) - 2.0 * x - 5.0;
+        let root = 2.0945514815423265;
+
+        assert!(matches!(
+            Bisection::initialize(f, 3.0, 4.0).run(),
+            Err(RootFindingError::SignAgreementError)
+        ));
+
+        let results = [
+            Bisection::initialize(f, 3.0, 4.0)
+                .tol(1e-12)
+                .expand_bracket(true)
+                .run(),
+            Ridders::initialize(f, 3.0, 4.0)
+                .tol(1e-12)
+                .expand_bracket(true)
+                .run(),
+            Brent::initialize(f, 3.0, 4.0)
+                .tol(1e-12)
+                .expand_bracket(true)
+                .run(),
+        ];
+
+        for result in results {
+            match result {
+                Ok(algo_metrics) => {
+                    println!("{}", algo_metrics);
+                    assert!((algo_metrics.est_x - root).abs() < 1e-10);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_expanded_bracket_evaluations() {
+        // The values of f at the expanded end points are reused, so the solvers only add
+        // their iterations to the evaluations of the expansion
+        let f = |x: f64| x.powi(3) - 2.0 * x - 5.0;
+        let bracket = match BracketExpander::initialize(f, 3.0, 4.0).run() {
+            Ok(bracket) => bracket,
+            Err(e) => panic!("Test failed due to error: {}", e),
+        };
+        let (a, b) = (bracket.a, bracket.b);
+
+        let results = [
+            (
+                Bisection::initialize(f, 3.0, 4.0)
+                    .expand_bracket(true)
+                    .run(),
+                Bisection::initialize(f, a, b).run(),
+            ),
+            (
+                Ridders::initialize(f, 3.0, 4.0).expand_bracket(true).run(),
+                Ridders::initialize(f, a, b).run(),
+            ),
+            (
+                Brent::initialize(f, 3.0, 4.0).expand_bracket(true).run(),
+                Brent::initialize(f, a, b).run(),
+            ),
+        ];
+        for result in results {
+            match result {
+                (Ok(expanded), Ok(direct)) => {
+                    assert_eq!(expanded.est_x, direct.est_x);
+                    assert_eq!(
+                        expanded.func_evals,
+                        bracket.func_evals + direct.func_evals - 2
+                    );
+                }
+                (Err(e), _) | (_, Err(e)) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_sign_changes() {
+        let brackets = find_sign_changes(|x: f64| x.sin(), 0.5, 10.0, 100);
+        assert_eq!(brackets.len(), 3);
+
+        for (k, (a, b)) in brackets.into_iter().enumerate() {
+            match Brent::initialize(|x: f64| x.sin(), a, b).tol(1e-12).run() {
+                Ok(algo_metrics) => {
+                    let expected = (k + 1) as f64 * std::f64::consts::PI;
+                    assert!((algo_metrics.est_x - expected).abs() < 1e-10);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_sign_changes_grid_roots() {
+        // Roots at 0, 1 and 2 all fall on grid points
+        let brackets = find_sign_changes(|x: f64| x * (x - 1.0) * (x - 2.0), 0.0, 2.0, 4);
+        assert_eq!(brackets, vec![(0.0, 0.5), (1.0, 1.5), (1.5, 2.0)]);
+    }
+}
@@ -181,4 +181,29 @@ mod brent_tests {
             Err(e) => panic!("Test failed due to error: {}", e),
         }
     }
+
+    #[test]
+    fn test_stateful_closure() {
+        // The closure captures a parameter and counts its own evaluations
+        let k = 3.0;
+        let mut calls = 0;
+        let root = Brent::initialize(
+            |x| {
+                calls += 1;
+                x.powi(3) - k
+            },
+            0.0,
+            3.0,
+        )
+        .tol(1e-10)
+        .run();
+
+        match root {
+            Ok(root) => {
+                assert!((root.est_x - k.cbrt()).abs() < 1e-10);
+                assert_eq!(calls, root.func_evals);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
 }
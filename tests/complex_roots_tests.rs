@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod complex_roots_tests {
+    use num_complex::Complex64;
+    use numix::optimize::complex_roots::{ComplexNewton, Muller};
+    use numix::optimize::root_finding::{RootFinder, RootFindingError};
+
+    #[test]
+    fn test_muller_from_real_guess() {
+        // x^2 + 1 has no real roots, but Muller's parabola leaves the real axis
+        let result = Muller::initialize(|z: Complex64| z * z + 1.0, Complex64::new(0.5, 0.0))
+            .tol(1e-12)
+            .run();
+
+        match result {
+            Ok(algo_metrics) => {
+                println!("{}", algo_metrics);
+                assert!((algo_metrics.est_x.re).abs() < 1e-12);
+                assert!((algo_metrics.est_x.im.abs() - 1.0).abs() < 1e-12);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_muller_transcendental() {
+        // z e^z = -1, the principal branch of the Lambert W function at -1
+        let f = |z: Complex64| z * z.exp() + 1.0;
+        let w = Complex64::new(-0.318_131_505_204_764_1, 1.337_235_701_430_689_4);
+
+        match Muller::initialize(f, Complex64::new(0.0, 1.0))
+            .tol(1e-13)
+            .run()
+        {
+            Ok(algo_metrics) => {
+                assert!((algo_metrics.est_x - w).norm() < 1e-12);
+                assert!(f(algo_metrics.est_x).norm() < 1e-12);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_newton_cube_roots_of_unity() {
+        let root = Complex64::from_polar(1.0, 2.0 * std::f64::consts::PI / 3.0);
+
+        let results = [
+            ComplexNewton::initialize(|z: Complex64| z.powu(3) - 1.0, Complex64::new(-1.0, 1.0))
+                .fp(|z| 3.0 * z * z)
+                .tol(1e-13)
+                .run(),
+            ComplexNewton::initialize(|z: Complex64| z.powu(3) - 1.0, Complex64::new(-1.0, 1.0))
+                .tol(1e-13)
+                .run(),
+        ];
+
+        for result in results {
+            match result {
+                Ok(algo_metrics) => assert!((algo_metrics.est_x - root).norm() < 1e-12),
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let f = |z: Complex64| z * z + 1.0;
+        let zero = Complex64::new(0.0, 0.0);
+
+        let result = ComplexNewton::initialize(f, zero).fp(|z| 2.0 * z).run();
+        assert!(matches!(
+            result,
+            Err(RootFindingError::ZeroDerivativeError(_))
+        ));
+
+        let result = Muller::initialize(f, zero).x1(zero).run();
+        assert!(matches!(
+            result,
+            Err(RootFindingError::IdenticalInitialGuessesError)
+        ));
+
+        let mut solver: Box<dyn RootFinder<Complex64, f64>> =
+            Box::new(ComplexNewton::initialize(f, Complex64::new(1.0, 1.0)).tol(-1.0));
+        assert!(matches!(
+            solver.solve(),
+            Err(RootFindingError::UnacceptableToleranceError(_))
+        ));
+        solver.set_tol(1e-12);
+        match solver.solve() {
+            Ok(algo_metrics) => assert!((algo_metrics.est_x - Complex64::i()).norm() < 1e-12),
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+}
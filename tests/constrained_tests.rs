@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod constrained_tests {
+    use ndarray::{array, Array1};
+    use numix::optimize::constrained::{LinearProgramError, ProjectedGradient, Simplex};
+    use numix::optimize::minimize::MinimizeError;
+
+    #[test]
+    fn test_projected_gradient_active_bounds() {
+        let f = |x: &Array1<f64>| (x[0] - 3.0).powi(2) + (x[1] + 1.0).powi(2);
+
+        let result = ProjectedGradient::initialize(f, array![1.0, 1.0])
+            .bounds(array![0.0, 0.0], array![2.0, 2.0])
+            .run();
+
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!((result.x[0] - 2.0).abs() < 1e-8);
+                assert!(result.x[1].abs() < 1e-8);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_projected_gradient_rosenbrock() {
+        let f = |x: &Array1<f64>| (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2);
+        let gradient = |x: &Array1<f64>| {
+            array![
+                -2.0 * (1.0 - x[0]) - 400.0 * x[0] * (x[1] - x[0] * x[0]),
+                200.0 * (x[1] - x[0] * x[0])
+            ]
+        };
+
+        let result = ProjectedGradient::initialize(f, array![-1.2, 1.0])
+            .gradient(gradient)
+            .bounds(array![-2.0, -1.0], array![0.5, 2.0])
+            .tol(1e-8)
+            .iter(100000)
+            .run();
+
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!((result.x[0] - 0.5).abs() < 1e-8);
+                assert!((result.x[1] - 0.25).abs() < 1e-6);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_projected_gradient_start_on_bound() {
+        // sqrt is not defined below the bound, where the finite differences must not reach
+        let f = |x: &Array1<f64>| {
+            assert!(x[0] >= 0.0, "f evaluated outside the box at {}", x);
+            x[0].sqrt() + (x[1] - 1.0).powi(2)
+        };
+
+        let result = ProjectedGradient::initialize(f, array![0.0, 0.0])
+            .bounds(
+                array![0.0, f64::NEG_INFINITY],
+                array![f64::INFINITY, f64::INFINITY],
+            )
+            .run();
+
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert_eq!(result.x[0], 0.0);
+                assert!((result.x[1] - 1.0).abs() < 1e-6);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_projected_gradient_invalid_bounds() {
+        let result = ProjectedGradient::initialize(|x: &Array1<f64>| x.sum(), array![0.0, 0.0])
+            .bounds(array![1.0, 0.0], array![0.0, 1.0])
+            .run();
+
+        assert!(matches!(result, Err(MinimizeError::InvalidBoundsError)));
+    }
+
+    #[test]
+    fn test_simplex_inequalities() {
+        // maximize 3x + 5y subject to x <= 4, 2y <= 12, 3x + 2y <= 18
+        let result = Simplex::initialize(array![-3.0, -5.0])
+            .inequalities(
+                array![[1.0, 0.0], [0.0, 2.0], [3.0, 2.0]],
+                array![4.0, 12.0, 18.0],
+            )
+            .run();
+
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!((result.x[0] - 2.0).abs() < 1e-10);
+                assert!((result.x[1] - 6.0).abs() < 1e-10);
+                assert!((result.objective + 36.0).abs() < 1e-10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_simplex_equalities() {
+        // minimize x + y subject to x + 2y >= 4 and x - y = 1
+        let result = Simplex::initialize(array![1.0, 1.0])
+            .inequalities(array![[-1.0, -2.0]], array![-4.0])
+            .equalities(array![[1.0, -1.0]], array![1.0])
+            .run();
+
+        match result {
+            Ok(result) => {
+                assert!((result.x[0] - 2.0).abs() < 1e-10);
+                assert!((result.x[1] - 1.0).abs() < 1e-10);
+                assert!((result.objective - 3.0).abs() < 1e-10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_simplex_infeasible() {
+        let result = Simplex::initialize(array![1.0])
+            .inequalities(array![[1.0], [-1.0]], array![1.0, -2.0])
+            .run();
+
+        assert!(matches!(result, Err(LinearProgramError::InfeasibleError)));
+    }
+
+    #[test]
+    fn test_simplex_unbounded() {
+        let result = Simplex::initialize(array![-1.0, 0.0])
+            .inequalities(array![[1.0, -1.0]], array![1.0])
+            .run();
+
+        assert!(matches!(result, Err(LinearProgramError::UnboundedError)));
+    }
+}
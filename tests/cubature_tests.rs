@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod cubature_tests {
+    use numix::common::functions::precision_equals;
+    use numix::integrate::cubature::{Cubature, CubatureError, NestedQuad};
+    use numix::integrate::quasi_monte_carlo::{
+        HaltonSequence, LowDiscrepancy, QuasiMonteCarlo, SobolSequence,
+    };
+    use std::f64::consts::E;
+
+    #[test]
+    fn test_genz_malik_box() {
+        let result = Cubature::initialize(|x| (x[0] + x[1]).exp(), &[0.0, 0.0], &[1.0, 1.0]).run();
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!(precision_equals(
+                    result.integral,
+                    (E - 1.0).powi(2),
+                    1e-9,
+                    0.0
+                ));
+                assert!(result.error_estimate < 3e-8);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+
+        // Degree 7 polynomials are integrated exactly by every rule, reversed bounds flip the sign
+        let result = Cubature::initialize(
+            |x| x[0].powi(4) * x[1].powi(2) + x[2].powi(6),
+            &[0.0, 2.0, 0.0],
+            &[1.0, 0.0, 1.0],
+        )
+        .run();
+        match result {
+            Ok(result) => {
+                let exact = -(8.0 / 15.0 + 2.0 / 7.0);
+                assert!(precision_equals(result.integral, exact, 1e-13, 0.0));
+                assert!(result.func_evals.is_multiple_of(33));
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_genz_malik_simplex() {
+        let triangle = vec![vec![0.0, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]];
+        match Cubature::simplex(|x| x[0] * x[1], &triangle).run() {
+            Ok(result) => assert!(precision_equals(result.integral, 1.0 / 24.0, 1e-12, 0.0)),
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+
+        // Volume of a tetrahedron, |det| / 6 = 4
+        let tetrahedron = vec![
+            vec![1.0, 1.0, 1.0],
+            vec![3.0, 1.0, 1.0],
+            vec![1.0, 4.0, 1.0],
+            vec![1.0, 1.0, 5.0],
+        ];
+        match Cubature::simplex(|_| 1.0, &tetrahedron).run() {
+            Ok(result) => assert!(precision_equals(result.integral, 4.0, 1e-12, 0.0)),
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_nested_quad() {
+        // Singular along both axes, where a polynomial rule in every region would struggle
+        let result =
+            NestedQuad::initialize(|x| 1.0 / (x[0] * x[1]).sqrt(), &[0.0, 0.0], &[1.0, 1.0]).run();
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!(precision_equals(result.integral, 4.0, 1e-8, 0.0));
+                assert!(result.error_estimate < 1e-7);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+
+        let result = NestedQuad::initialize(
+            |x| x[0] * x[1].sin() * x[2].exp(),
+            &[0.0, 0.0, 1.0],
+            &[2.0, std::f64::consts::PI, 0.0],
+        )
+        .run();
+        match result {
+            Ok(result) => {
+                let exact = -2.0 * 2.0 * (E - 1.0);
+                assert!(precision_equals(result.integral, exact, 1e-10, 0.0));
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_low_discrepancy_sequences() {
+        match SobolSequence::new(2) {
+            Ok(sobol) => {
+                let points: Vec<Vec<f64>> = sobol.take(4).collect();
+                let expected = [[0.5, 0.5], [0.75, 0.25], [0.25, 0.75], [0.375, 0.375]];
+                for (point, expected) in points.iter().zip(expected) {
+                    assert_eq!(point.as_slice(), expected.as_slice());
+                }
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+        match HaltonSequence::new(2) {
+            Ok(halton) => {
+                let points: Vec<Vec<f64>> = halton.take(3).collect();
+                assert_eq!(points[2][0], 0.75);
+                assert!(precision_equals(points[2][1], 1.0 / 9.0, 1e-15, 0.0));
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_quasi_monte_carlo() {
+        let exact = (E - 1.0).powi(6);
+        for sequence in [LowDiscrepancy::Sobol, LowDiscrepancy::Halton] {
+            let run = || {
+                QuasiMonteCarlo::initialize(|x| x.iter().sum::<f64>().exp(), &[0.0; 6], &[1.0; 6])
+                    .sequence(sequence)
+                    .points(1 << 14)
+                    .seed(7)
+                    .run()
+            };
+            match (run(), run()) {
+                (Ok(first), Ok(second)) => {
+                    println!("{:?}: {}", sequence, first);
+                    assert_eq!(first.integral, second.integral);
+                    assert!(first.error_estimate < 1e-2);
+                    assert!((first.integral - exact).abs() < 5.0 * first.error_estimate);
+                    assert_eq!(first.func_evals, 8 << 14);
+                }
+                (Err(e), _) | (_, Err(e)) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cubature_errors() {
+        assert!(matches!(
+            Cubature::initialize(|x| x[0], &[0.0], &[1.0]).run(),
+            Err(CubatureError::DimensionError)
+        ));
+        assert!(matches!(
+            Cubature::initialize(|x| x[0], &[0.0, 0.0], &[1.0, f64::INFINITY]).run(),
+            Err(CubatureError::RegionError)
+        ));
+        let degenerate = vec![vec![0.0, 0.0], vec![1.0, 1.0], vec![2.0, 2.0]];
+        assert!(matches!(
+            Cubature::simplex(|x| x[0], &degenerate).run(),
+            Err(CubatureError::RegionError)
+        ));
+        assert!(matches!(
+            Cubature::initialize(|x| (x[0] * x[1]).abs().sqrt(), &[-1.0, -1.0], &[1.0, 1.0])
+                .change_max_evals(1000)
+                .run(),
+            Err(CubatureError::UnacceptableTolearanceError(_))
+        ));
+        assert!(matches!(
+            SobolSequence::new(30),
+            Err(CubatureError::DimensionError)
+        ));
+        assert!(matches!(
+            QuasiMonteCarlo::initialize(|x| x[0], &[0.0], &[1.0])
+                .randomizations(1)
+                .run(),
+            Err(CubatureError::InvalidInput(_))
+        ));
+    }
+}
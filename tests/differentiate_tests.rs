@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod differentiate_tests {
+    use ndarray::{array, Array1};
+    use num_complex::Complex64;
+    use numix::differentiate::derivative::{
+        ComplexStep, DifferentiationError, FiniteDifference, Richardson, Stencil,
+    };
+    use numix::differentiate::multivariate::{gradient, hessian, jacobian};
+
+    #[test]
+    fn test_finite_difference_orders() {
+        let x: f64 = 1.0;
+        let exact = x.cos();
+        let mut previous_error = f64::INFINITY;
+
+        // With a fixed step the error drops with every increase of the accuracy order
+        for accuracy in [2, 4, 6, 8] {
+            match FiniteDifference::initialize(f64::sin, x)
+                .accuracy(accuracy)
+                .h(0.1)
+                .run()
+            {
+                Ok(result) => {
+                    let error = (result.derivative - exact).abs();
+                    assert!(error < previous_error, "accuracy={}", accuracy);
+                    previous_error = error;
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+        assert!(previous_error < 1e-9);
+
+        for (stencil, accuracy) in [(Stencil::Forward, 1), (Stencil::Backward, 2)] {
+            match FiniteDifference::initialize(f64::sin, x)
+                .stencil(stencil)
+                .accuracy(accuracy)
+                .run()
+            {
+                Ok(result) => assert!((result.derivative - exact).abs() < 1e-7),
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_second_derivative() {
+        let f = |x: f64| x.exp() * x.sin();
+        let exact = 2.0 * 0.5f64.exp() * 0.5f64.cos();
+
+        for accuracy in [2, 4] {
+            match FiniteDifference::initialize(f, 0.5)
+                .derivative(2)
+                .accuracy(accuracy)
+                .run()
+            {
+                Ok(result) => {
+                    println!("{}", result);
+                    assert!((result.derivative - exact).abs() < 1e-6);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_richardson() {
+        let f = |x: f64| x.exp() / (x.sin().powi(3) + x.cos().powi(3)).sqrt();
+        let exact = 4.053_427_893_898_621;
+
+        match Richardson::initialize(f, 1.5).run() {
+            Ok(result) => {
+                println!("{}", result);
+                let error = (result.derivative - exact).abs();
+                assert!(error < 1e-11);
+                assert!(result.error_estimate < 1e-9);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+
+        match Richardson::initialize(f64::ln, 2.0).derivative(2).run() {
+            Ok(result) => assert!((result.derivative + 0.25).abs() < 1e-9),
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_complex_step() {
+        let f = |z: Complex64| z.exp() / (z.sin().powu(3) + z.cos().powu(3)).sqrt();
+
+        match ComplexStep::initialize(f, 1.5).run() {
+            Ok(result) => {
+                assert!((result.derivative - 4.053_427_893_898_621).abs() < 1e-14);
+                assert_eq!(result.func_evals, 1);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_multivariate() {
+        let rosenbrock =
+            |x: &Array1<f64>| (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2);
+        let x = array![0.5, 1.5];
+
+        let g = gradient(rosenbrock, &x);
+        assert!((g[0] - (-1.0 - 400.0 * 0.5 * 1.25)).abs() < 1e-6);
+        assert!((g[1] - 200.0 * 1.25).abs() < 1e-6);
+
+        let h = hessian(rosenbrock, &x);
+        let exact = array![[2.0 - 400.0 * 1.25 + 800.0 * 0.25, -200.0], [-200.0, 200.0]];
+        for (value, expected) in h.iter().zip(exact.iter()) {
+            assert!((value - expected).abs() < 1e-4);
+        }
+
+        let polar = |x: &Array1<f64>| array![x[0] * x[1].cos(), x[0] * x[1].sin(), x[0]];
+        match jacobian(polar, &array![2.0, 0.3]) {
+            Ok(j) => {
+                assert_eq!(j.dim(), (3, 2));
+                assert!((j[[0, 1]] + 2.0 * 0.3f64.sin()).abs() < 1e-9);
+                assert!((j[[1, 0]] - 0.3f64.sin()).abs() < 1e-9);
+                assert!((j[[2, 0]] - 1.0).abs() < 1e-9);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let result = FiniteDifference::initialize(f64::sin, 1.0)
+            .accuracy(3)
+            .run();
+        assert!(matches!(
+            result,
+            Err(DifferentiationError::UnacceptableOrderError)
+        ));
+
+        let result = Richardson::initialize(f64::sin, 1.0).h(-0.1).run();
+        assert!(matches!(
+            result,
+            Err(DifferentiationError::UnacceptableStepError)
+        ));
+
+        let mut calls = 0;
+        let growing = |x: &Array1<f64>| {
+            calls += 1;
+            Array1::from_elem(calls, x[0])
+        };
+        assert!(matches!(
+            jacobian(growing, &array![1.0]),
+            Err(DifferentiationError::DimensionMismatchError)
+        ));
+    }
+}
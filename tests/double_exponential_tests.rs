@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod double_exponential_tests {
+    use numix::common::functions::precision_equals;
+    use numix::integrate::double_exponential::DoubleExponential;
+    use numix::integrate::quad::QuadError;
+    use std::f64::consts::PI;
+
+    type Integrand = fn(f64) -> f64;
+
+    #[test]
+    fn test_endpoint_singularities() {
+        // 1/sqrt(x), ln(x) and ln(x) / sqrt(x) on [0, 1]
+        let cases: [(Integrand, f64); 3] = [
+            (|x| 1.0 / x.sqrt(), 2.0),
+            (|x| x.ln(), -1.0),
+            (|x| x.ln() / x.sqrt(), -4.0),
+        ];
+        for (f, expected) in cases {
+            match DoubleExponential::initialize(f, 0.0, 1.0).run() {
+                Ok(result) => {
+                    println!("{}", result);
+                    assert!(precision_equals(result.integral, expected, 1e-12, 0.0));
+                    assert!(result.error_estimate < 1e-9);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_infinite_intervals() {
+        let cases: [(Integrand, f64, f64, f64); 4] = [
+            (|x| (-x).exp() / x.sqrt(), 0.0, f64::INFINITY, PI.sqrt()),
+            (|x| 1.0 / (1.0 + x * x), f64::NEG_INFINITY, 1.0, 0.75 * PI),
+            (
+                |x| (-x * x).exp(),
+                f64::NEG_INFINITY,
+                f64::INFINITY,
+                PI.sqrt(),
+            ),
+            (
+                |x| 1.0 / (1.0 + x * x),
+                f64::INFINITY,
+                f64::NEG_INFINITY,
+                -PI,
+            ),
+        ];
+        for (f, a, b, expected) in cases {
+            match DoubleExponential::initialize(f, a, b).run() {
+                Ok(result) => {
+                    println!("{}", result);
+                    assert!(precision_equals(result.integral, expected, 1e-11, 0.0));
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_smooth_integrand_tolerance() {
+        let result = DoubleExponential::initialize(|x| x.exp() + x.ln(), 2.0, 3.0)
+            .change_tolerance(1e-6)
+            .change_relative_tolerance(1e-6)
+            .run();
+        match result {
+            Ok(result) => {
+                assert!(precision_equals(
+                    result.integral,
+                    13.606_023_329_141_456,
+                    1e-12,
+                    0.0
+                ));
+                assert!(result.number_of_intervals < 100);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_large_finite_endpoint() {
+        // The exp-sinh nodes closest to a round onto a and still have to be counted
+        let a = 1e9;
+        let result =
+            DoubleExponential::initialize(|x| 1.0 / (1.0 + (x - a)).powi(2), a, f64::INFINITY)
+                .run();
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!(precision_equals(result.integral, 1.0, 1e-9, 0.0));
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+
+        let result = DoubleExponential::initialize(|x| 1.0 / (x * x), 1e12, f64::INFINITY)
+            .change_tolerance(1e-15)
+            .run();
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!(precision_equals(result.integral, 1e-12, 0.0, 1e-7));
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_no_node_inside_interval() {
+        // Every offset rounds onto the endpoints, which must not underflow the interval count
+        let cases: [(f64, f64, f64); 2] = [
+            (1e20, f64::INFINITY, 1e-20),
+            (1.0, 1.0 + f64::EPSILON, f64::EPSILON),
+        ];
+        for (a, b, expected) in cases {
+            match DoubleExponential::initialize(|x| 1.0 / (x * x), a, b).run() {
+                Ok(result) => {
+                    println!("{}", result);
+                    assert!(precision_equals(result.integral, expected, 1e-11, 0.0));
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_double_exponential_errors() {
+        assert!(matches!(
+            DoubleExponential::initialize(|x| x, 0.0, 1.0)
+                .change_tolerance(0.0)
+                .run(),
+            Err(QuadError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            DoubleExponential::initialize(|x| x, f64::NAN, 1.0).run(),
+            Err(QuadError::IntervalError)
+        ));
+        // sin(x) / x on [0, inf) converges too slowly for the exp-sinh rule
+        assert!(matches!(
+            DoubleExponential::initialize(|x: f64| x.sin() / x, 0.0, f64::INFINITY)
+                .change_max_level(6)
+                .run(),
+            Err(QuadError::UnacceptableTolearanceError(_)) | Err(QuadError::Divergence)
+        ));
+        assert!(matches!(
+            DoubleExponential::initialize(|x| 1.0 / x, 0.0, 1.0).run(),
+            Err(QuadError::UnacceptableTolearanceError(_)) | Err(QuadError::Divergence)
+        ));
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod dual_tests {
+    use numix::common::dual::{derivative, second_derivative, Dual, Dual2, DualNum};
+    use numix::common::float::Real;
+    use numix::optimize::root_finding::{Halley, Newton};
+
+    fn cubic<D: DualNum>(x: D) -> D {
+        x.powi(3) - x * 2.0 - D::constant(5.0)
+    }
+
+    // exp(sin(x)) / (1 + x^2) + sqrt(x) ln(x)
+    fn composite<D: DualNum>(x: D) -> D {
+        x.sin().exp() / (x * x + 1.0) + x.sqrt() * x.ln()
+    }
+
+    // Written for any underlying type, so that it can be differentiated in f32
+    fn kepler<T: Real, D: DualNum<T>>(x: D) -> D {
+        x - x.sin() * T::constant(0.5) - D::constant(T::constant(1.0))
+    }
+
+    // atan(cos(x)) = tanh(x) has a single root between 0.5 and 1
+    fn balance<D: DualNum>(x: D) -> D {
+        x.cos().atan() - x.tanh()
+    }
+
+    #[test]
+    fn test_derivatives() {
+        let x = 1.3;
+        let f1 = derivative(composite)(x);
+        let f2 = second_derivative(composite)(x);
+
+        // Central differences as an independent check
+        let h = 1e-4;
+        let fd1 = (composite(x + h) - composite(x - h)) / (2.0 * h);
+        let fd2 = (composite(x + h) - 2.0 * composite(x) + composite(x - h)) / (h * h);
+        assert!((f1 - fd1).abs() < 1e-7);
+        assert!((f2 - fd2).abs() < 1e-5);
+
+        let d = composite(Dual::variable(x));
+        let d2 = composite(Dual2::variable(x));
+        assert_eq!(d.re, composite(x));
+        assert_eq!(d2.re, composite(x));
+        assert!((d.eps - d2.d1).abs() < 1e-14);
+    }
+
+    #[test]
+    fn test_elementary_functions() {
+        let x = 0.7;
+        let d = |f: fn(Dual2) -> Dual2| f(Dual2::variable(x));
+
+        let t = d(|x| x.tan());
+        assert!((t.d1 - 1.0 / x.cos().powi(2)).abs() < 1e-14);
+        let t = d(|x| x.tanh());
+        assert!((t.d2 + 2.0 * x.tanh() / x.cosh().powi(2)).abs() < 1e-14);
+        let t = d(|x| x.atan());
+        assert!((t.d1 - 1.0 / (1.0 + x * x)).abs() < 1e-14);
+        let t = d(|x| x.powf(2.5));
+        assert!((t.d2 - 3.75 * x.sqrt()).abs() < 1e-14);
+        let t = d(|x| 1.0 / x);
+        assert!((t.d2 - 2.0 / x.powi(3)).abs() < 1e-13);
+        let t = Dual2::variable(0.0).powi(3);
+        assert_eq!((t.re, t.d1, t.d2), (0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_newton_autodiff() {
+        let result = Newton::initialize(cubic, 2.0)
+            .fp(derivative(cubic))
+            .tol(1e-12)
+            .run();
+
+        match result {
+            Ok(algo_metrics) => {
+                println!("{}", algo_metrics);
+                assert!(cubic(algo_metrics.est_x).abs() < 1e-12);
+                assert!(algo_metrics.iter < 10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_halley_autodiff() {
+        let results = [
+            Halley::initialize(
+                balance,
+                derivative(balance),
+                second_derivative(balance),
+                0.5,
+            )
+            .tol(1e-12)
+            .run(),
+            Newton::initialize(balance, 0.5)
+                .fp(derivative(balance))
+                .fdp(second_derivative(balance))
+                .tol(1e-12)
+                .run(),
+        ];
+
+        for result in results {
+            match result {
+                Ok(algo_metrics) => assert!(balance(algo_metrics.est_x).abs() < 1e-12),
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_autodiff_f32() {
+        let x = 1.2f32;
+        let d = kepler(Dual2::variable(x));
+        assert!((d.d1 - (1.0 - 0.5 * x.cos())).abs() < 1e-6);
+        assert!((d.d2 - 0.5 * x.sin()).abs() < 1e-6);
+        assert!(((2.0f32 / Dual::variable(x)).eps + 2.0 / (x * x)).abs() < 1e-6);
+
+        let result = Newton::new(kepler::<f32, f32>, 1.0f32)
+            .fp(derivative(kepler))
+            .fdp(second_derivative(kepler))
+            .run();
+        match result {
+            Ok(algo_metrics) => {
+                println!("{}", algo_metrics);
+                assert!(kepler::<f32, f32>(algo_metrics.est_x).abs() < 1e-5);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod error_tests {
+    use numix::integrate::integrator::{IntegralError, IntegrationError, Simpson};
+    use numix::integrate::quad::{Quad, QuadError};
+    use numix::integrate::{integrate, IntegrationMethod};
+    use numix::optimize::root_finding::{Bisection, Brent, RootFindingError};
+    use std::error::Error;
+
+    fn solve_and_integrate(k: f64) -> Result<f64, Box<dyn Error>> {
+        let root = Brent::initialize(|x| x * x - k, 0.0, k + 1.0).run()?;
+        let area = integrate(|x| x * x, 0.0, root.est_x, IntegrationMethod::Quad)?;
+        Ok(area.integral)
+    }
+
+    #[test]
+    fn test_errors_flow_through_question_mark() {
+        match solve_and_integrate(9.0) {
+            Ok(area) => assert!((area - 9.0).abs() < 1e-6),
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+
+        let result: Result<(), Box<dyn Error>> = (|| {
+            Bisection::initialize(|x| x * x + 1.0, -1.0, 1.0).run()?;
+            Ok(())
+        })();
+        let error = result.unwrap_err();
+        assert!(error.downcast_ref::<RootFindingError>().is_some());
+        println!("{:?}", error);
+    }
+
+    #[test]
+    fn test_integration_error_source() {
+        let error = match integrate(|x| x, 0.0, 0.0, IntegrationMethod::Simpson) {
+            Ok(results) => panic!("Test failed returning a value: {}", results),
+            Err(error) => error,
+        };
+
+        assert!(matches!(
+            error,
+            IntegrationError::Integral(IntegralError::IntervalError)
+        ));
+        let source = error
+            .source()
+            .expect("the underlying error should be chained");
+        assert!(source.downcast_ref::<IntegralError>().is_some());
+    }
+
+    #[test]
+    fn test_distinct_messages() {
+        let error = Simpson::initialize(|x| x, 0.0, 0.0).run().unwrap_err();
+        let message = error.to_string();
+        assert!(!message.contains("Derivative"));
+        assert!(message.contains("interval"));
+
+        let error = Quad::initialize(|x| x, 0.0, 1.0)
+            .change_tolerance(0.0)
+            .run()
+            .unwrap_err();
+        assert!(matches!(error, QuadError::InvalidInput(_)));
+        assert!(error.to_string().contains("invalid tolerance"));
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod gauss_tests {
+    use numix::common::functions::precision_equals;
+    use numix::integrate::gauss::{gauss_rule, Gauss, GaussError, GaussFamily};
+    use numix::integrate::Integrator;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_gauss_legendre_rule() {
+        let result = gauss_rule(GaussFamily::Legendre, 5);
+
+        match result {
+            Ok(rule) => {
+                let nodes = [
+                    -0.906_179_845_938_664,
+                    -0.538_469_310_105_683,
+                    0.0,
+                    0.538_469_310_105_683,
+                    0.906_179_845_938_664,
+                ];
+                let weights = [
+                    0.236_926_885_056_189,
+                    0.478_628_670_499_366,
+                    0.568_888_888_888_889,
+                    0.478_628_670_499_366,
+                    0.236_926_885_056_189,
+                ];
+                for i in 0..5 {
+                    assert!(precision_equals(rule.nodes[i], nodes[i], 1e-14, 0.0));
+                    assert!(precision_equals(rule.weights[i], weights[i], 1e-14, 0.0));
+                }
+                // Exact up to degree 2n - 1 = 9
+                let integral = rule.apply(|x| x.powi(8) + x.powi(9));
+                assert!(precision_equals(integral, 2.0 / 9.0, 1e-14, 0.0));
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_gauss_weighted_families() {
+        let cases = [
+            // Integral of x^5 x^0.5 e^(-x) over [0, inf) is Gamma(6.5)
+            (GaussFamily::Laguerre(0.5), 5, 287.885_277_815_044_4),
+            // Integral of x^4 e^(-x^2) is 3 sqrt(pi) / 4
+            (GaussFamily::Hermite, 4, 0.75 * PI.sqrt()),
+            (GaussFamily::ChebyshevFirstKind, 4, 3.0 * PI / 8.0),
+            (GaussFamily::ChebyshevSecondKind, 4, PI / 16.0),
+            // Integral of x^4 (1 - x^2) over [-1, 1]
+            (GaussFamily::Jacobi(1.0, 1.0), 4, 4.0 / 35.0),
+        ];
+        for (family, power, expected) in cases {
+            match gauss_rule(family, 6) {
+                Ok(rule) => {
+                    let integral = rule.apply(|x| x.powi(power));
+                    assert!(precision_equals(integral, expected, 1e-12, 1e-13));
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_gauss_jacobi_matches_legendre() {
+        let legendre = gauss_rule(GaussFamily::Legendre, 40);
+        let jacobi = gauss_rule(GaussFamily::Jacobi(0.0, 0.0), 40);
+
+        match (legendre, jacobi) {
+            (Ok(legendre), Ok(jacobi)) => {
+                let sum: f64 = legendre.weights.iter().sum();
+                assert!(precision_equals(sum, 2.0, 1e-13, 0.0));
+                for i in 0..40 {
+                    assert!(precision_equals(
+                        legendre.nodes[i],
+                        jacobi.nodes[i],
+                        1e-13,
+                        0.0
+                    ));
+                    assert!(precision_equals(
+                        legendre.weights[i],
+                        jacobi.weights[i],
+                        1e-13,
+                        0.0
+                    ));
+                }
+            }
+            (Err(e), _) | (_, Err(e)) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_gauss_integrator() {
+        let result = Gauss::initialize(|x| x.exp() + x.ln(), 2.0, 3.0)
+            .order(12)
+            .run();
+        match result {
+            Ok(result) => {
+                assert_eq!(result.nodes, 12);
+                assert!(precision_equals(
+                    result.integral,
+                    13.606_023_329_141_456,
+                    1e-13,
+                    0.0
+                ));
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+
+        let mut integrator = Gauss::weighted(|x: f64| x.cos(), GaussFamily::Hermite).order(15);
+        match integrator.integrate() {
+            Ok(metrics) => {
+                let expected = PI.sqrt() * (-0.25f64).exp();
+                let error = (metrics.integral - expected).abs();
+                assert!(error < 1e-13);
+                assert!(metrics.error_estimate < 1e-10);
+                assert_eq!(metrics.func_evals, 29);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_gauss_errors() {
+        assert!(matches!(
+            gauss_rule(GaussFamily::Legendre, 0),
+            Err(GaussError::ZeroOrderError)
+        ));
+        assert!(matches!(
+            gauss_rule(GaussFamily::Laguerre(-1.0), 4),
+            Err(GaussError::InvalidParameterError)
+        ));
+        assert!(matches!(
+            gauss_rule(GaussFamily::Jacobi(0.5, -2.0), 4),
+            Err(GaussError::InvalidParameterError)
+        ));
+        assert!(matches!(
+            Gauss::initialize(|x| x, 0.0, f64::INFINITY).run(),
+            Err(GaussError::IntervalError)
+        ));
+    }
+}
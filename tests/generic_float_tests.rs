@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod generic_float_tests {
+    use numix::common::float::Real;
+    use numix::integrate::cubature::{Cubature, NestedQuad};
+    use numix::integrate::double_exponential::DoubleExponential;
+    use numix::integrate::gauss::{Gauss, GaussFamily};
+    use numix::integrate::integrator::{CompositeTrapezoid, Romberg, Simpson};
+    use numix::integrate::quad::Quad;
+    use numix::integrate::Integrator;
+    use numix::interpolate::interpolator::{cubic_spline, linear_spline};
+    use numix::optimize::root_finding::{
+        Bisection, Brent, Halley, Newton, NewtonBracketed, Ridders, Secant,
+    };
+
+    #[test]
+    fn test_tolerances_from_epsilon() {
+        assert_eq!(f64::default_tol(), 1e-8);
+        assert_eq!(f64::default_rtol(), 4.0 * f64::EPSILON);
+        assert_eq!(f32::default_tol(), 100.0 * f32::EPSILON);
+        assert_eq!(f32::default_rtol(), 4.0 * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_bracketing_f32() {
+        let f = |x: f32| x.powi(3) - 2.0 * x - 5.0;
+        let root = 2.094_551_5_f32;
+
+        let results = [
+            Bisection::new(f, 2.0f32, 3.0).run(),
+            Ridders::new(f, 2.0f32, 3.0).run(),
+            Brent::new(f, 2.0f32, 3.0).run(),
+            NewtonBracketed::new(f, |x: f32| 3.0 * x * x - 2.0, 2.0f32, 3.0).run(),
+        ];
+
+        for result in results {
+            match result {
+                Ok(algo_metrics) => {
+                    println!("{}", algo_metrics);
+                    assert!((algo_metrics.est_x - root).abs() < 1e-4);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_open_f32() {
+        let results = [
+            Newton::new(|x: f32| x * x - 2.0, 1.0f32)
+                .fp(|x| 2.0 * x)
+                .run(),
+            Secant::new(|x: f32| x * x - 2.0, 1.0f32).run(),
+            Halley::new(|x: f32| x * x - 2.0, |x| 2.0 * x, |_| 2.0, 1.0f32).run(),
+            Secant::new(|x: f32| x * x - 2.0, 1.0f32).x1(2.0).run(),
+        ];
+
+        for result in results {
+            match result {
+                Ok(algo_metrics) => {
+                    assert!((algo_metrics.est_x - 2f32.sqrt()).abs() < 1e-5);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_generic_matches_f64() {
+        let f = |x: f64| x.exp() - 2.0;
+        let generic = Brent::new(f, 0.0, 1.0).tol(1e-12).run();
+        let initialized = Brent::initialize(f, 0.0, 1.0).tol(1e-12).run();
+
+        match (generic, initialized) {
+            (Ok(generic), Ok(initialized)) => {
+                assert_eq!(generic.est_x, initialized.est_x);
+                assert_eq!(generic.func_evals, initialized.func_evals);
+            }
+            _ => panic!("Test failed due to error"),
+        }
+    }
+
+    #[test]
+    fn test_integrators_f32() {
+        let exact = 2.0f32;
+
+        let mut integrators: Vec<Box<dyn Integrator<f32>>> = vec![
+            Box::new(
+                CompositeTrapezoid::new(|x: f32| x.sin(), 0.0f32, std::f32::consts::PI).nodes(1000),
+            ),
+            Box::new(Simpson::new(|x: f32| x.sin(), 0.0f32, std::f32::consts::PI).nodes(100)),
+            Box::new(Romberg::new(|x: f32| x.sin(), 0.0f32, std::f32::consts::PI).extend(6)),
+            Box::new(Quad::new(|x: f32| x.sin(), 0.0f32, std::f32::consts::PI)),
+            Box::new(Gauss::new(|x: f32| x.sin(), 0.0f32, std::f32::consts::PI).order(10)),
+            Box::new(DoubleExponential::new(
+                |x: f32| x.sin(),
+                0.0f32,
+                std::f32::consts::PI,
+            )),
+        ];
+
+        for integrator in integrators.iter_mut() {
+            match integrator.integrate() {
+                Ok(metrics) => {
+                    println!("{}", metrics);
+                    assert!((metrics.integral - exact).abs() < 1e-4);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_quad_f32_infinite_interval() {
+        // Integral of e^(-x^2) over the real line is sqrt(pi)
+        let result = Quad::new(|x: f32| (-x * x).exp(), f32::NEG_INFINITY, f32::INFINITY).run();
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!((result.integral - std::f32::consts::PI.sqrt()).abs() < 1e-5);
+                assert!(result.error_estimate < 1e-4);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_double_exponential_f32_singularity() {
+        // Integral of ln(x) / sqrt(x) over [0, 1] is -4
+        let result = DoubleExponential::new(|x: f32| x.ln() / x.sqrt(), 0.0f32, 1.0).run();
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!((result.integral + 4.0).abs() < 1e-4);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_cubature_f32() {
+        // Integral of x y over the unit square is 1/4 and over the unit triangle 1/24
+        let f = |x: &[f32]| x[0] * x[1];
+        let results = [
+            (Cubature::new(f, &[0.0f32; 2], &[1.0; 2]).run(), 0.25),
+            (
+                Cubature::new_simplex(f, &[vec![0.0f32, 0.0], vec![1.0, 0.0], vec![0.0, 1.0]])
+                    .run(),
+                1.0 / 24.0,
+            ),
+            (NestedQuad::new(f, &[0.0f32; 2], &[1.0; 2]).run(), 0.25),
+        ];
+        for (result, exact) in results {
+            match result {
+                Ok(result) => {
+                    println!("{}", result);
+                    assert!((result.integral - exact).abs() < 1e-5);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_gauss_weighted_f32() {
+        // Integral of x^2 e^(-x^2) over the real line is sqrt(pi) / 2
+        let result = Gauss::new_weighted(|x: f32| x * x, GaussFamily::Hermite)
+            .order(5)
+            .run();
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!((result.integral - 0.5 * std::f32::consts::PI.sqrt()).abs() < 1e-5);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_splines_f32() {
+        let pts: Vec<(f32, f32)> = (0..=10)
+            .map(|i| {
+                let x = i as f32 * 0.5;
+                (x, x.sin())
+            })
+            .collect();
+
+        match (linear_spline(&pts), cubic_spline(&pts)) {
+            (Ok(linear), Ok(cubic)) => {
+                let x = 2.25f32;
+                assert!((linear.eval(x).unwrap() - x.sin()).abs() < 5e-2);
+                assert!((cubic.eval(x).unwrap() - x.sin()).abs() < 5e-3);
+                assert!(cubic.eval(6.0).is_none());
+            }
+            _ => panic!("Test failed due to error"),
+        }
+    }
+}
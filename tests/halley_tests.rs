@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod halley_tests {
+    use numix::optimize::root_finding::{Halley, Newton, RootFindingError};
+
+    #[test]
+    fn test_cube_root() {
+        let result =
+            Halley::initialize(|x| x.powi(3) - 10.0, |x| 3.0 * x.powi(2), |x| 6.0 * x, 2.0)
+                .tol(1e-12)
+                .run();
+
+        match result {
+            Ok(algo_metrics) => {
+                println!("{}", algo_metrics);
+                assert!((algo_metrics.est_x - 10f64.cbrt()).abs() < 1e-10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_fewer_iterations_than_newton() {
+        let f = |x: f64| x.exp() - 5.0;
+        let halley = Halley::initialize(f, |x| x.exp(), |x| x.exp(), 4.0)
+            .tol(1e-12)
+            .run();
+        let newton = Newton::initialize(f, 4.0).fp(|x| x.exp()).tol(1e-12).run();
+
+        match (halley, newton) {
+            (Ok(halley), Ok(newton)) => {
+                assert!((halley.est_x - 5f64.ln()).abs() < 1e-10);
+                assert!(halley.iter < newton.iter);
+            }
+            _ => panic!("Test failed: both solvers should converge"),
+        }
+    }
+
+    #[test]
+    fn test_zero_derivative() {
+        let result = Halley::initialize(|x| x * x + 1.0, |x| 2.0 * x, |_| 2.0, 0.0).run();
+
+        assert!(matches!(
+            result,
+            Err(RootFindingError::ZeroDerivativeError(_))
+        ));
+    }
+}
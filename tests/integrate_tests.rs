@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod integrate_tests {
+    use numix::common::functions::precision_equals;
+    use numix::integrate::integrator::{CompositeTrapezoid, Romberg, Simpson};
+    use numix::integrate::quad::Quad;
+    use numix::integrate::{integrate, IntegrationMethod, Integrator};
+
+    #[test]
+    fn test_integrate_all_methods() {
+        let k = 2.0;
+        let exact = (1.0 - (-k * 2.0_f64).exp()) / k;
+        let methods = [
+            IntegrationMethod::Trapezoid,
+            IntegrationMethod::Simpson,
+            IntegrationMethod::Romberg,
+            IntegrationMethod::Quad,
+            IntegrationMethod::DoubleExponential,
+        ];
+
+        for method in methods {
+            match integrate(|x| (-k * x).exp(), 0.0, 2.0, method) {
+                Ok(results) => {
+                    println!("{:?}: {}", method, results);
+                    assert!(precision_equals(results.integral, exact, 1e-7, 0.0));
+                    assert!(results.error_estimate < 1e-6);
+                    assert!(results.func_evals > 0);
+                }
+                Err(results) => panic!("Test failed for {:?} due to error: {}", method, results),
+            }
+        }
+    }
+
+    #[test]
+    fn test_error_estimates() {
+        // Integral of sin on [0, pi] is 2, the estimate should bound the actual error
+        let pi = std::f64::consts::PI;
+        let mut integrators: Vec<Box<dyn Integrator>> = vec![
+            Box::new(CompositeTrapezoid::initialize(|x: f64| x.sin(), 0.0, pi).nodes(64)),
+            Box::new(CompositeTrapezoid::initialize(|x: f64| x.sin(), 0.0, pi).nodes(63)),
+            Box::new(CompositeTrapezoid::initialize(|x: f64| x.sin(), 0.0, pi).nodes(3)),
+            Box::new(Simpson::initialize(|x: f64| x.sin(), 0.0, pi).nodes(64)),
+            Box::new(Simpson::initialize(|x: f64| x.sin(), 0.0, pi).nodes(66)),
+            Box::new(Romberg::initialize(|x: f64| x.sin(), 0.0, pi).extend(4)),
+            Box::new(Quad::initialize(|x: f64| x.sin(), 0.0, pi)),
+        ];
+
+        for integrator in integrators.iter_mut() {
+            match integrator.integrate() {
+                Ok(results) => {
+                    println!("{}", results);
+                    let actual_error = (results.integral - 2.0).abs();
+                    assert!(results.error_estimate.is_finite());
+                    assert!(actual_error <= 2.0 * results.error_estimate + 1e-12);
+                }
+                Err(results) => panic!("Test failed due to error: {}", results),
+            }
+        }
+    }
+
+    #[test]
+    fn test_function_evaluations() {
+        let mut calls = 0;
+        let results = CompositeTrapezoid::initialize(
+            |x: f64| {
+                calls += 1;
+                x * x
+            },
+            0.0,
+            1.0,
+        )
+        .nodes(100)
+        .integrate();
+
+        match results {
+            Ok(results) => assert_eq!(results.func_evals, 101),
+            Err(results) => panic!("Test failed due to error: {}", results),
+        }
+        assert_eq!(calls, 101);
+    }
+
+    #[test]
+    fn test_integrate_invalid_interval() {
+        let results = integrate(
+            |x| x,
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            IntegrationMethod::Simpson,
+        );
+
+        assert!(results.is_err());
+    }
+}
@@ -184,4 +184,27 @@ mod general_test {
             }
         }
     }
+
+    #[test]
+    fn test_captured_parameter() {
+        // Integrate k * x^2 on [0, 3] for a captured k, exact value is 9k
+        let k = 2.5;
+        let exact = 9.0 * k;
+
+        let simpson = Simpson::initialize(|x| k * x * x, 0.0, 3.0)
+            .nodes(100)
+            .run();
+        let romberg = Romberg::initialize(|x| k * x * x, 0.0, 3.0)
+            .extend(4)
+            .run();
+
+        for result in [simpson, romberg] {
+            match result {
+                Ok(result) => {
+                    assert!(precision_equals(result.integral, exact, 1e-6, 0.0));
+                }
+                Err(result) => panic!("Test failed due to error: {}", result),
+            }
+        }
+    }
 }
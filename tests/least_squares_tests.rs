@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod least_squares_tests {
+    use ndarray::{array, Array1, Array2};
+    use numix::optimize::least_squares::{
+        curve_fit, CurveFit, LeastSquaresError, LevenbergMarquardt,
+    };
+    use std::cell::Cell;
+
+    #[test]
+    fn test_curve_fit_exponential() {
+        // y = 2.5 exp(-1.3 x) + 0.5 with a small deterministic perturbation
+        let data: Vec<(f64, f64)> = (0..50)
+            .map(|i| {
+                let x = i as f64 * 0.1;
+                (x, 2.5 * (-1.3 * x).exp() + 0.5 + 1e-3 * (7.0 * x).sin())
+            })
+            .collect();
+        let model = |x: f64, p: &Array1<f64>| p[0] * (-p[1] * x).exp() + p[2];
+
+        match curve_fit(model, &data, array![1.0, 1.0, 0.0]) {
+            Ok(result) => {
+                println!("{}", result);
+                assert!((result.params[0] - 2.5).abs() < 1e-2);
+                assert!((result.params[1] - 1.3).abs() < 1e-2);
+                assert!((result.params[2] - 0.5).abs() < 1e-2);
+                assert!(result.rmse < 1e-3);
+
+                let errors = result.standard_errors();
+                assert!(errors.iter().all(|e| e.is_finite() && *e > 0.0));
+                assert!((result.covariance[[0, 1]] - result.covariance[[1, 0]]).abs() < 1e-12);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_curve_fit_exact_line() {
+        let data: Vec<(f64, f64)> = (0..10).map(|i| (i as f64, 2.0 * i as f64 + 1.0)).collect();
+
+        match curve_fit(
+            |x, p: &Array1<f64>| p[0] * x + p[1],
+            &data,
+            array![0.0, 0.0],
+        ) {
+            Ok(result) => {
+                assert!((result.params[0] - 2.0).abs() < 1e-8);
+                assert!((result.params[1] - 1.0).abs() < 1e-8);
+                assert!(result.ssr < 1e-14);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_curve_fit_bounds() {
+        let data: Vec<(f64, f64)> = (1..10).map(|i| (i as f64, 3.0 * i as f64)).collect();
+
+        let result = CurveFit::initialize(|x, p: &Array1<f64>| p[0] * x, &data, array![1.0])
+            .bounds(array![0.0], array![2.0])
+            .run();
+
+        match result {
+            Ok(result) => {
+                assert!((result.params[0] - 2.0).abs() < 1e-12);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_curve_fit_narrow_bounds() {
+        // The box is narrower than the finite difference step
+        let data: Vec<(f64, f64)> = (1..10).map(|i| (i as f64, 3.0 * i as f64)).collect();
+        let (lower, upper) = (2.0, 2.0 + 1e-9);
+        let outside = Cell::new(0);
+
+        let result = CurveFit::initialize(
+            |x, p: &Array1<f64>| {
+                if p[0] < lower || p[0] > upper {
+                    outside.set(outside.get() + 1);
+                }
+                p[0] * x
+            },
+            &data,
+            array![1.0],
+        )
+        .bounds(array![lower], array![upper])
+        .run();
+
+        match result {
+            Ok(result) => {
+                assert!((result.params[0] - upper).abs() < 1e-12);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+        assert_eq!(outside.get(), 0);
+    }
+
+    #[test]
+    fn test_levenberg_marquardt_jacobian() {
+        let residuals = |x: &Array1<f64>| array![10.0 * (x[1] - x[0] * x[0]), 1.0 - x[0]];
+        let jacobian =
+            |x: &Array1<f64>| -> Array2<f64> { array![[-20.0 * x[0], 10.0], [-1.0, 0.0]] };
+
+        let result = LevenbergMarquardt::initialize(residuals, array![-1.2, 1.0])
+            .jacobian(jacobian)
+            .run();
+
+        match result {
+            Ok(result) => {
+                assert!((result.params[0] - 1.0).abs() < 1e-8);
+                assert!((result.params[1] - 1.0).abs() < 1e-8);
+                assert!(result.reduced_chi_squared.is_nan());
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_underdetermined() {
+        let data = [(1.0, 2.0)];
+        let result = curve_fit(
+            |x, p: &Array1<f64>| p[0] * x + p[1],
+            &data,
+            array![0.0, 0.0],
+        );
+
+        assert!(matches!(
+            result,
+            Err(LeastSquaresError::UnderdeterminedError)
+        ));
+    }
+}
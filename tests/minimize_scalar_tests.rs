@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod minimize_scalar_tests {
+    use numix::optimize::minimize_scalar::{
+        BracketSearch, BrentMinimizer, GoldenSection, MinimizationError,
+    };
+
+    #[test]
+    fn test_golden_section() {
+        let result = GoldenSection::initialize(|x| (x - 2.0).powi(2) + 1.0, 0.0, 5.0)
+            .tol(1e-10)
+            .run();
+
+        match result {
+            Ok(algo_metrics) => {
+                println!("{}", algo_metrics);
+                assert!((algo_metrics.est_x - 2.0).abs() < 1e-7);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_brent_minimizer() {
+        let k = 0.5;
+        let result = BrentMinimizer::initialize(|x: f64| x.cos() + k * x, 2.0, 5.0)
+            .tol(1e-10)
+            .run();
+
+        match result {
+            Ok(algo_metrics) => {
+                println!("{}", algo_metrics);
+                let expected = std::f64::consts::PI - (0.5f64).asin();
+                assert!((algo_metrics.est_x - expected).abs() < 1e-7);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_brent_faster_than_golden_section() {
+        let f = |x: f64| x.exp() - 3.0 * x;
+        let golden = GoldenSection::initialize(f, 0.0, 3.0).run();
+        let brent = BrentMinimizer::initialize(f, 0.0, 3.0).run();
+
+        match (golden, brent) {
+            (Ok(golden), Ok(brent)) => {
+                assert!((golden.est_x - 3f64.ln()).abs() < 1e-7);
+                assert!((brent.est_x - 3f64.ln()).abs() < 1e-7);
+                assert!(brent.func_evals < golden.func_evals);
+            }
+            _ => panic!("Test failed: both minimizers should converge"),
+        }
+    }
+
+    #[test]
+    fn test_bracket_search() {
+        let f = |x: f64| (x - 10.0).powi(2);
+
+        match BracketSearch::initialize(f, 0.0, 1.0).run() {
+            Ok(bracket) => {
+                println!("{}", bracket);
+                assert!(bracket.a < bracket.b && bracket.b < bracket.c);
+                assert!(bracket.f_b <= bracket.f_a && bracket.f_b <= bracket.f_c);
+                assert!(bracket.a < 10.0 && 10.0 < bracket.c);
+
+                match BrentMinimizer::initialize(f, bracket.a, bracket.c).run() {
+                    Ok(algo_metrics) => assert!((algo_metrics.est_x - 10.0).abs() < 1e-6),
+                    Err(e) => panic!("Test failed due to error: {}", e),
+                }
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_bracket_search_unbounded() {
+        let result = BracketSearch::initialize(|x| -x, 0.0, 1.0).iter(20).run();
+
+        assert!(matches!(
+            result,
+            Err(MinimizationError::BracketNotFoundError(_))
+        ));
+    }
+
+    #[test]
+    fn test_unacceptable_tolerance() {
+        let result = BrentMinimizer::initialize(|x| x * x, -1.0, 1.0)
+            .tol(0.0)
+            .run();
+
+        assert!(matches!(
+            result,
+            Err(MinimizationError::UnacceptableToleranceError(_))
+        ));
+    }
+}
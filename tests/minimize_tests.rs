@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod minimize_tests {
+    use ndarray::{array, Array1};
+    use numix::optimize::minimize::{Bfgs, Lbfgs, MinimizeError, NelderMead};
+
+    fn rosenbrock(x: &Array1<f64>) -> f64 {
+        (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0] * x[0]).powi(2)
+    }
+
+    fn rosenbrock_gradient(x: &Array1<f64>) -> Array1<f64> {
+        array![
+            -2.0 * (1.0 - x[0]) - 400.0 * x[0] * (x[1] - x[0] * x[0]),
+            200.0 * (x[1] - x[0] * x[0])
+        ]
+    }
+
+    #[test]
+    fn test_nelder_mead() {
+        let result = NelderMead::initialize(rosenbrock, array![-1.2, 1.0])
+            .tol(1e-10)
+            .run();
+
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!((result.x[0] - 1.0).abs() < 1e-4);
+                assert!((result.x[1] - 1.0).abs() < 1e-4);
+                assert!(result.grad_norm.is_nan());
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_nelder_mead_noisy() {
+        // A small deterministic ripple on top of a quadratic bowl
+        let f = |x: &Array1<f64>| {
+            (x[0] - 3.0).powi(2) + (x[1] + 1.0).powi(2) + 1e-6 * (1e3 * x[0]).sin()
+        };
+
+        match NelderMead::initialize(f, array![0.0, 0.0]).step(0.5).run() {
+            Ok(result) => {
+                assert!((result.x[0] - 3.0).abs() < 1e-2);
+                assert!((result.x[1] + 1.0).abs() < 1e-2);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_bfgs_gradient() {
+        let result = Bfgs::initialize(rosenbrock, array![-1.2, 1.0])
+            .gradient(rosenbrock_gradient)
+            .tol(1e-8)
+            .run();
+
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!((result.x[0] - 1.0).abs() < 1e-6);
+                assert!((result.x[1] - 1.0).abs() < 1e-6);
+                assert!(result.grad_norm <= 1e-8);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_bfgs_finite_difference() {
+        let center = array![1.0, -2.0, 3.0];
+        let f = |x: &Array1<f64>| {
+            let d = x - &center;
+            d.dot(&d) + 0.5 * d[0] * d[1]
+        };
+
+        match Bfgs::initialize(f, Array1::zeros(3)).run() {
+            Ok(result) => {
+                for k in 0..3 {
+                    assert!((result.x[k] - center[k]).abs() < 1e-6);
+                }
+                // Every central difference gradient costs two evaluations per variable
+                assert!(result.func_evals >= 6 * result.grad_evals);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_lbfgs() {
+        // Extended Rosenbrock function in 20 variables
+        let f = |x: &Array1<f64>| {
+            (0..x.len() / 2)
+                .map(|i| {
+                    let (a, b) = (x[2 * i], x[2 * i + 1]);
+                    (1.0 - a).powi(2) + 100.0 * (b - a * a).powi(2)
+                })
+                .sum::<f64>()
+        };
+        let gradient = |x: &Array1<f64>| {
+            let mut g = Array1::zeros(x.len());
+            for i in 0..x.len() / 2 {
+                let (a, b) = (x[2 * i], x[2 * i + 1]);
+                g[2 * i] = -2.0 * (1.0 - a) - 400.0 * a * (b - a * a);
+                g[2 * i + 1] = 200.0 * (b - a * a);
+            }
+            g
+        };
+        let x0 = Array1::from_shape_fn(20, |i| if i % 2 == 0 { -1.2 } else { 1.0 });
+
+        let result = Lbfgs::initialize(f, x0)
+            .gradient(gradient)
+            .memory(5)
+            .tol(1e-8)
+            .run();
+
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!(result.x.iter().all(|x| (x - 1.0).abs() < 1e-6));
+                assert!(result.f_x < 1e-12);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let result = Bfgs::initialize(rosenbrock, array![-1.2, 1.0])
+            .gradient(|x: &Array1<f64>| array![x[0]])
+            .run();
+
+        assert!(matches!(result, Err(MinimizeError::DimensionMismatchError)));
+    }
+}
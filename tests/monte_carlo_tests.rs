@@ -0,0 +1,177 @@
+#[cfg(test)]
+mod monte_carlo_tests {
+    use numix::integrate::cubature::CubatureError;
+    use numix::integrate::monte_carlo::{
+        ImportanceSampling, MonteCarlo, StratifiedMonteCarlo, Vegas,
+    };
+
+    // Product of narrow normalized Gaussians centered in the unit cube, with integral ~1
+    fn peak(x: &[f64]) -> f64 {
+        let width: f64 = 0.1;
+        x.iter()
+            .map(|x_i| {
+                (-((x_i - 0.5) / width).powi(2)).exp() / (width * std::f64::consts::PI.sqrt())
+            })
+            .product()
+    }
+
+    #[test]
+    fn test_plain_monte_carlo() {
+        let run = |seed| {
+            MonteCarlo::initialize(|x| 8.0 * x[0] * x[1] * x[2], &[0.0; 3], &[1.0; 3])
+                .samples(50_000)
+                .seed(seed)
+                .run()
+        };
+        match (run(1), run(1), run(2)) {
+            (Ok(first), Ok(repeated), Ok(other)) => {
+                println!("{}", first);
+                assert_eq!(first.mean, repeated.mean);
+                assert_ne!(first.mean, other.mean);
+                assert_eq!(first.samples, 50_000);
+                assert!(first.standard_error < 1e-2);
+                assert!((first.mean - 1.0).abs() < 4.0 * first.standard_error);
+            }
+            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
+                panic!("Test failed due to error: {}", e)
+            }
+        }
+    }
+
+    #[test]
+    fn test_stratified_monte_carlo() {
+        let f = |x: &[f64]| (x[0] + 2.0 * x[1]).sin();
+        let exact = (1f64.sin() + 2f64.sin() - 3f64.sin()) / 2.0;
+        let plain = MonteCarlo::initialize(f, &[0.0, 0.0], &[1.0, 1.0])
+            .samples(40_000)
+            .run();
+        let stratified = StratifiedMonteCarlo::initialize(f, &[0.0, 0.0], &[1.0, 1.0])
+            .samples(40_000)
+            .strata(10)
+            .run();
+        match (plain, stratified) {
+            (Ok(plain), Ok(stratified)) => {
+                println!("{}{}", plain, stratified);
+                assert_eq!(stratified.samples, 40_000);
+                assert!(stratified.standard_error < 0.2 * plain.standard_error);
+                assert!((stratified.mean - exact).abs() < 4.0 * stratified.standard_error);
+            }
+            (Err(e), _) | (_, Err(e)) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_importance_sampling() {
+        // Integral of x^2 e^(-x) over [0, inf) is 2, sampled from the exponential distribution
+        let result = ImportanceSampling::initialize(
+            |x| x[0] * x[0] * (-x[0]).exp(),
+            1,
+            |u| vec![-(1.0 - u[0]).ln()],
+            |x| (-x[0]).exp(),
+        )
+        .samples(100_000)
+        .seed(3)
+        .run();
+        match result {
+            Ok(result) => {
+                println!("{}", result);
+                assert!(result.standard_error < 0.02);
+                assert!((result.mean - 2.0).abs() < 4.0 * result.standard_error);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_vegas() {
+        // erf(5)^4, the mass of the peak inside the cube
+        let exact = (1.0 - 1.537_459_794_428_035e-12f64).powi(4);
+        let vegas = Vegas::initialize(peak, &[0.0; 4], &[1.0; 4])
+            .samples(10_000)
+            .iterations(10)
+            .skip(2)
+            .run();
+        let plain = MonteCarlo::initialize(peak, &[0.0; 4], &[1.0; 4])
+            .samples(100_000)
+            .run();
+        match (vegas, plain) {
+            (Ok(vegas), Ok(plain)) => {
+                println!("{}{}", vegas, plain);
+                assert_eq!(vegas.samples, 100_000);
+                assert!(vegas.standard_error < 0.1 * plain.standard_error);
+                assert!((vegas.mean - exact).abs() < 4.0 * vegas.standard_error);
+            }
+            (Err(e), _) | (_, Err(e)) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_vegas_zero_variance_iterations() {
+        // A single bin keeps the sampling uniform, so every iteration is exact
+        let constant = Vegas::initialize(|_| 2.0, &[0.0; 2], &[1.0, 3.0])
+            .samples(1000)
+            .bins(1)
+            .iterations(5)
+            .run();
+        // Vanishes after the first two iterations, which must not be discarded
+        let mut calls = 0;
+        let vanishing = Vegas::initialize(
+            |x| {
+                calls += 1;
+                if calls <= 2000 {
+                    x[0]
+                } else {
+                    0.0
+                }
+            },
+            &[0.0],
+            &[1.0],
+        )
+        .samples(1000)
+        .iterations(5)
+        .run();
+        match (constant, vanishing) {
+            (Ok(constant), Ok(vanishing)) => {
+                println!("{}{}", constant, vanishing);
+                assert_eq!(constant.mean, 6.0);
+                assert_eq!(constant.standard_error, 0.0);
+                assert_eq!(constant.samples, 5000);
+                assert!(vanishing.standard_error > 0.0);
+                assert!((vanishing.mean - 0.5).abs() < 4.0 * vanishing.standard_error);
+            }
+            (Err(e), _) | (_, Err(e)) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_monte_carlo_errors() {
+        assert!(matches!(
+            MonteCarlo::initialize(|x| x[0], &[0.0], &[1.0])
+                .samples(1)
+                .run(),
+            Err(CubatureError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            StratifiedMonteCarlo::initialize(|x| x[0], &[0.0; 3], &[1.0; 3])
+                .samples(100)
+                .strata(5)
+                .run(),
+            Err(CubatureError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            Vegas::initialize(|x| x[0], &[0.0], &[f64::NAN]).run(),
+            Err(CubatureError::RegionError)
+        ));
+        assert!(matches!(
+            Vegas::initialize(|x| x[0], &[0.0], &[1.0])
+                .iterations(2)
+                .skip(2)
+                .run(),
+            Err(CubatureError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            MonteCarlo::initialize(|x| 1.0 / x[0], &[0.0], &[0.0]).run(),
+            Err(CubatureError::Divergence)
+        ));
+    }
+}
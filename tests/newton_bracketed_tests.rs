@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod newton_bracketed_tests {
+    use numix::optimize::root_finding::{Newton, NewtonBracketed, RootFindingError};
+
+    #[test]
+    fn test_cubic() {
+        let result = NewtonBracketed::initialize(
+            |x| x.powi(3) - 2.0 * x - 5.0,
+            |x| 3.0 * x.powi(2) - 2.0,
+            2.0,
+            3.0,
+        )
+        .tol(1e-12)
+        .run();
+
+        match result {
+            Ok(algo_metrics) => {
+                println!("{}", algo_metrics);
+                assert!((algo_metrics.est_x - 2.0945514815423265).abs() < 1e-10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_stiff_equation() {
+        // Plain Newton overshoots on atan when started far from the root, until the
+        // derivative underflows at the diverging iterate
+        let newton = Newton::initialize(|x: f64| x.atan(), 2.0)
+            .fp(|x| 1.0 / (1.0 + x * x))
+            .iter(50)
+            .run();
+        match newton {
+            Err(RootFindingError::ZeroDerivativeError(metrics)) => {
+                assert!(metrics.est_x.abs() > 1e100);
+            }
+            Ok(metrics) => panic!("Test failed returning a value: {}", metrics),
+            Err(e) => panic!("Test failed due to incorrect error: {}", e),
+        }
+
+        let result =
+            NewtonBracketed::initialize(|x: f64| x.atan(), |x| 1.0 / (1.0 + x * x), -1.0, 5.0)
+                .tol(1e-12)
+                .record_history(true)
+                .run();
+
+        match result {
+            Ok(algo_metrics) => {
+                assert!(algo_metrics.est_x.abs() < 1e-10);
+                for state in &algo_metrics.history {
+                    let (low, high) = state.bracket.unwrap();
+                    assert!(low <= state.x && state.x <= high);
+                }
+                // The first Newton step from the midpoint leaves the bracket, so it is bisected
+                let bisections = algo_metrics
+                    .history
+                    .iter()
+                    .filter(|state| {
+                        let (low, high) = state.bracket.unwrap();
+                        let newton = state.x - state.f_x * (1.0 + state.x * state.x);
+                        let next = state.x + state.step;
+                        next == 0.5 * (low + high) && next != newton
+                    })
+                    .count();
+                assert!(bisections >= 1);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_sign_agreement() {
+        let result = NewtonBracketed::initialize(|x| x * x + 1.0, |x| 2.0 * x, -1.0, 2.0).run();
+
+        assert!(matches!(result, Err(RootFindingError::SignAgreementError)));
+    }
+}
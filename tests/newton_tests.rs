@@ -134,4 +134,22 @@ pub mod newton_tests {
             Err(e) => panic!("Test failed due to error: {}", e),
         }
     }
+
+    #[test]
+    fn test_newton_captured_parameter() {
+        // Solve cos(x) = k * x with the parameter k captured by f, fp and fdp
+        let k = 0.5;
+        let root = Newton::initialize(|x| x.cos() - k * x, 1.0)
+            .fp(|x| -x.sin() - k)
+            .fdp(|x| -x.cos())
+            .tol(1e-10)
+            .run();
+
+        match root {
+            Ok(root) => {
+                assert!((root.est_x.cos() - k * root.est_x).abs() < 1e-10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
 }
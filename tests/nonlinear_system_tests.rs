@@ -0,0 +1,152 @@
+#[cfg(test)]
+mod nonlinear_system_tests {
+    use ndarray::{array, Array1, Array2};
+    use numix::optimize::nonlinear_system::{
+        Broyden, NewtonSystem, NonlinearSystemError, PowellHybrid,
+    };
+
+    fn circle_line(x: &Array1<f64>) -> Array1<f64> {
+        array![x[0] * x[0] + x[1] * x[1] - 4.0, x[0] - x[1]]
+    }
+
+    fn rosenbrock(x: &Array1<f64>) -> Array1<f64> {
+        array![10.0 * (x[1] - x[0] * x[0]), 1.0 - x[0]]
+    }
+
+    fn rosenbrock_jacobian(x: &Array1<f64>) -> Array2<f64> {
+        array![[-20.0 * x[0], 10.0], [-1.0, 0.0]]
+    }
+
+    #[test]
+    fn test_newton_finite_difference() {
+        match NewtonSystem::initialize(circle_line, array![1.0, 0.5]).run() {
+            Ok(result) => {
+                println!("{}", result);
+                let s = 2f64.sqrt();
+                assert!((result.x[0] - s).abs() < 1e-10);
+                assert!((result.x[1] - s).abs() < 1e-10);
+                assert!(result.f_norm <= 1e-10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_newton_jacobian() {
+        let result = NewtonSystem::initialize(rosenbrock, array![-1.2, 1.0])
+            .jacobian(rosenbrock_jacobian)
+            .tol(1e-12)
+            .run();
+
+        match result {
+            Ok(result) => {
+                assert!((result.x[0] - 1.0).abs() < 1e-10);
+                assert!((result.x[1] - 1.0).abs() < 1e-10);
+                assert_eq!(result.jac_evals as usize, result.iter);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_broyden() {
+        let k = 3.0;
+        let f = |x: &Array1<f64>| {
+            array![
+                x[0] + x[1] + x[2] - k,
+                x[0] * x[1] + x[1] * x[2] + x[0] * x[2] - k,
+                x[0] * x[1] * x[2] - 1.0 + 0.1 * (x[0] - 1.0)
+            ]
+        };
+
+        match Broyden::initialize(f, array![1.2, 0.9, 0.8]).run() {
+            Ok(result) => {
+                println!("{}", result);
+                assert!(result.f_norm <= 1e-10);
+                assert_eq!(result.jac_evals, 1);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_powell_hybrid() {
+        // Powell's badly scaled function
+        let f = |x: &Array1<f64>| {
+            array![
+                1e4 * x[0] * x[1] - 1.0,
+                (-x[0]).exp() + (-x[1]).exp() - 1.0001
+            ]
+        };
+
+        match PowellHybrid::initialize(f, array![0.0, 1.0])
+            .iter(1000)
+            .run()
+        {
+            Ok(result) => {
+                println!("{}", result);
+                assert!(result.f_norm <= 1e-10);
+                assert!((result.x[0] - 1.098159e-5).abs() < 1e-9);
+                assert!((result.x[1] - 9.106146).abs() < 1e-5);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_powell_hybrid_jacobian() {
+        let result = PowellHybrid::initialize(rosenbrock, array![-1.2, 1.0])
+            .jacobian(rosenbrock_jacobian)
+            .run();
+
+        match result {
+            Ok(result) => {
+                assert!((result.x[0] - 1.0).abs() < 1e-10);
+                assert!((result.x[1] - 1.0).abs() < 1e-10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_small_step_with_large_residual() {
+        // The first step from 2.1 is below rtol, but |F| is still far above tol
+        let cube = |x: &Array1<f64>| array![x[0].powi(3) - 8.0];
+        let newton = NewtonSystem::initialize(cube, array![2.1]).rtol(0.1).run();
+        let broyden = Broyden::initialize(cube, array![2.1]).rtol(0.1).run();
+        for result in [newton, broyden] {
+            match result {
+                Err(NonlinearSystemError::IterationLimitExceededError(metrics)) => {
+                    assert_eq!(metrics.iter, 1);
+                    assert!(metrics.f_norm > 1e-10);
+                }
+                other => panic!("Expected a large residual, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_singular_jacobian() {
+        let result = NewtonSystem::initialize(
+            |x: &Array1<f64>| array![x[0] + x[1], 2.0 * x[0] + 2.0 * x[1] - 1.0],
+            array![1.0, 1.0],
+        )
+        .jacobian(|_: &Array1<f64>| array![[1.0, 1.0], [2.0, 2.0]])
+        .run();
+
+        assert!(matches!(
+            result,
+            Err(NonlinearSystemError::SingularJacobianError(_))
+        ));
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let result = Broyden::initialize(|x: &Array1<f64>| array![x[0]], array![1.0, 1.0]).run();
+
+        assert!(matches!(
+            result,
+            Err(NonlinearSystemError::DimensionMismatchError)
+        ));
+    }
+}
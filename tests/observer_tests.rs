@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod observer_tests {
+    use numix::optimize::root_finding::{
+        Bisection, Brent, IterationState, Newton, ObserverAction, Ridders, RootFindingError, Secant,
+    };
+
+    #[test]
+    fn test_observer_sees_every_iteration() {
+        let mut states: Vec<IterationState> = Vec::new();
+        let result = Bisection::initialize(|x| x * x - 2.0, 0.0, 2.0)
+            .tol(1e-10)
+            .observer(|state| {
+                states.push(*state);
+                ObserverAction::Continue
+            })
+            .run();
+
+        match result {
+            Ok(result) => {
+                assert_eq!(states.len(), result.iter + 1);
+                for state in &states {
+                    let (a, b) = state.bracket.unwrap();
+                    assert!(a <= 2.0_f64.sqrt() && 2.0_f64.sqrt() <= b);
+                }
+                assert!(result.history.is_empty());
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_observer_stops_diverging_newton() {
+        // Newton on arctan diverges when started far enough from the root
+        let result = Newton::initialize(|x| x.atan(), 2.0)
+            .fp(|x| 1.0 / (1.0 + x * x))
+            .observer(|state| {
+                if state.x.abs() > 100.0 {
+                    ObserverAction::Stop
+                } else {
+                    ObserverAction::Continue
+                }
+            })
+            .record_history(true)
+            .run();
+
+        match result {
+            Ok(result) => panic!("Test failed returning a value: {}", result),
+            Err(RootFindingError::EarlyTerminationError(metrics)) => {
+                println!("{}", metrics);
+                assert!(metrics.est_x.abs() > 100.0);
+                assert_eq!(metrics.history.len(), metrics.iter + 1);
+                // The iterates move away from the root with alternating signs
+                for pair in metrics.history.windows(2) {
+                    assert!(pair[1].x.abs() > pair[0].x.abs());
+                    assert!(pair[1].x.signum() != pair[0].x.signum());
+                }
+            }
+            Err(e) => panic!("Test failed due to incorrect error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_recorded_history() {
+        let ridders = Ridders::initialize(|x| x.exp() - 2.0, 0.0, 2.0)
+            .tol(1e-12)
+            .record_history(true)
+            .run();
+        let brent = Brent::initialize(|x| x.exp() - 2.0, 0.0, 2.0)
+            .tol(1e-12)
+            .record_history(true)
+            .run();
+        let secant = Secant::initialize(|x| x.exp() - 2.0, 1.0)
+            .tol(1e-12)
+            .record_history(true)
+            .run();
+
+        for result in [ridders, brent, secant] {
+            match result {
+                Ok(result) => {
+                    assert!(!result.history.is_empty());
+                    let last = result.history.last().unwrap();
+                    assert!((last.x - 2.0_f64.ln()).abs() < 1e-6);
+                    assert!(last.f_x.abs() < 1e-6);
+                    for (i, state) in result.history.iter().enumerate() {
+                        assert_eq!(state.iter, i);
+                    }
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_stop_on_first_iteration() {
+        let result = Brent::initialize(|x| x.sin(), 2.0, 4.0)
+            .observer(|_| ObserverAction::Stop)
+            .run();
+
+        match result {
+            Err(RootFindingError::EarlyTerminationError(metrics)) => {
+                assert_eq!(metrics.iter, 0);
+                assert!(metrics.est_x >= 2.0 && metrics.est_x <= 4.0);
+            }
+            _ => panic!("Test failed because the observer did not stop the solver"),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod polynomial_roots_tests {
+    use numix::optimize::polynomial_roots::{polynomial_roots, Aberth, PolynomialRootsError};
+    use numix::special::polynomials::{legendre_polynomials, poly_evaluate};
+
+    #[test]
+    fn test_real_roots() {
+        // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+        let coefficients = vec![-6.0, 11.0, -6.0, 1.0];
+
+        match polynomial_roots(&coefficients) {
+            Ok(result) => {
+                println!("{}", result);
+                let roots = result.real_roots(1e-10);
+                assert_eq!(roots.len(), 3);
+                for (root, expected) in roots.iter().zip([1.0, 2.0, 3.0]) {
+                    assert!((root - expected).abs() < 1e-12);
+                }
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_complex_roots() {
+        // x^4 + 1 has the roots exp(i(2k + 1)pi/4)
+        let coefficients = vec![1.0, 0.0, 0.0, 0.0, 1.0];
+
+        match Aberth::initialize(&coefficients).run() {
+            Ok(result) => {
+                assert_eq!(result.roots.len(), 4);
+                assert!(result.real_roots(1e-10).is_empty());
+                let s = std::f64::consts::FRAC_1_SQRT_2;
+                for root in &result.roots {
+                    assert!((root.re.abs() - s).abs() < 1e-12);
+                    assert!((root.im.abs() - s).abs() < 1e-12);
+                }
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_zero_and_leading_coefficients() {
+        // x^2 (x + 2) with two vanishing higher order coefficients
+        let coefficients = vec![0.0, 0.0, 2.0, 1.0, 0.0, 0.0];
+
+        match polynomial_roots(&coefficients) {
+            Ok(result) => {
+                let roots = result.real_roots(0.0);
+                assert_eq!(roots.len(), 3);
+                assert!((roots[0] + 2.0).abs() < 1e-12);
+                assert_eq!(roots[1], 0.0);
+                assert_eq!(roots[2], 0.0);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_legendre_roots() {
+        let coefficients = legendre_polynomials(10);
+
+        match polynomial_roots(&coefficients) {
+            Ok(result) => {
+                let roots = result.real_roots(1e-8);
+                assert_eq!(roots.len(), 10);
+                for root in roots {
+                    assert!(root.abs() < 1.0);
+                    assert!(poly_evaluate(&coefficients, root).abs() < 1e-10);
+                }
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_zero_polynomial() {
+        assert!(matches!(
+            polynomial_roots(&[0.0, 0.0]),
+            Err(PolynomialRootsError::ZeroPolynomialError)
+        ));
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod general_test {
     use numix::common::functions::precision_equals;
-    use numix::integrate::quad::{Quad, QuadError};
+    use numix::integrate::quad::{Quad, QuadError, QuadRule};
     use std::time::Instant;
 
     #[test]
@@ -227,4 +227,110 @@ mod general_test {
             }
         }
     }
+
+    #[test]
+    fn test_quad_captured_parameter() {
+        // Integrate 1/x^k on [1, inf) for a captured k, exact value is 1/(k-1)
+        let k = 3.0;
+        let mut calls = 0;
+        let results = Quad::initialize(
+            |x| {
+                calls += 1;
+                x.powf(-k)
+            },
+            1.0,
+            f64::INFINITY,
+        )
+        .run();
+
+        match results {
+            Ok(results) => {
+                println!("{}", results);
+                assert!(precision_equals(
+                    results.integral,
+                    1.0 / (k - 1.0),
+                    1e-7,
+                    0.0
+                ));
+            }
+            Err(results) => {
+                println!("{}", results);
+                panic!("Test failed due to error: {}", results)
+            }
+        }
+        assert!(calls > 0);
+    }
+
+    #[test]
+    fn test_quad_kronrod_rules() {
+        // Integrable singularity at 0, exact value is -4
+        for rule in [QuadRule::G7K15, QuadRule::G10K21] {
+            let results = Quad::initialize(|x| x.ln() / x.sqrt(), 0.0, 1.0)
+                .rule(rule)
+                .run();
+
+            match results {
+                Ok(results) => {
+                    println!("{}", results);
+                    let actual_error = (results.integral + 4.0).abs();
+                    assert!(actual_error <= results.error_estimate);
+                    assert!(results.error_estimate <= 1e-9);
+                    assert!(results.number_of_intervals > 1);
+                }
+                Err(results) => panic!("Test failed due to error: {}", results),
+            }
+        }
+    }
+
+    #[test]
+    fn test_quad_relative_tolerance() {
+        // The integral is about 2.2e10, far above the absolute tolerance
+        let exact = 1e10 * (3f64.exp() - 1.0);
+        let results = Quad::initialize(|x| 1e10 * x.exp(), 0.0, 3.0)
+            .change_relative_tolerance(1e-12)
+            .run();
+
+        match results {
+            Ok(results) => {
+                assert!(results.error_estimate <= 1e-12 * results.integral.abs());
+                assert!(precision_equals(results.integral, exact, 0.0, 1e-12));
+            }
+            Err(results) => panic!("Test failed due to error: {}", results),
+        }
+    }
+
+    #[test]
+    fn test_quad_oscillatory_small_integral() {
+        // Twenty periods of sin(x) + cos(3x), whose integral cancels, plus a small constant
+        let (a, b): (f64, f64) = (0.3, 0.3 + 40.0 * std::f64::consts::PI);
+        let oscillating = a.cos() - b.cos() + ((3.0 * b).sin() - (3.0 * a).sin()) / 3.0;
+        for c in [0.0, 0.01, 0.1, 1.0] {
+            let exact = oscillating + c * (b - a);
+            let results = Quad::initialize(|x| x.sin() + (3.0 * x).cos() + c, a, b).run();
+
+            match results {
+                Ok(results) => {
+                    println!("{}", results);
+                    assert!(precision_equals(results.integral, exact, 1e-10, 0.0));
+                }
+                Err(results) => panic!("Test failed due to error: {}", results),
+            }
+        }
+    }
+
+    #[test]
+    fn test_quad_subinterval_limit() {
+        let results = Quad::initialize(|x| (1.0 / x).sin(), 0.0, 1.0)
+            .change_subinterval_limit(5)
+            .run();
+
+        match results {
+            Ok(results) => panic!("Test failed returning {}", results),
+            Err(QuadError::UnacceptableTolearanceError(quadchar)) => {
+                assert_eq!(quadchar.number_of_intervals, 5);
+                assert!(quadchar.error_estimate > 1e-11);
+            }
+            Err(results) => panic!("Test failed due to incorrect returning error: {}", results),
+        }
+    }
 }
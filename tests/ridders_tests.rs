@@ -180,4 +180,21 @@ mod bisection_tests {
             Err(e) => panic!("Test failed due to error: {}", e),
         }
     }
+
+    #[test]
+    fn test_captured_parameter() {
+        // Solve exp(x) - k = 0 for a sweep of k, with k captured by the closure
+        for k in [0.5_f64, 2.0, 7.5] {
+            let root = Ridders::initialize(|x| x.exp() - k, -3.0, 3.0)
+                .tol(1e-10)
+                .run();
+
+            match root {
+                Ok(root) => {
+                    assert!((root.est_x - k.ln()).abs() < 1e-10);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
 }
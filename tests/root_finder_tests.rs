@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod root_finder_tests {
+    use std::cell::Cell;
+
+    use numix::optimize::root_finding::{
+        root_finder, root_finder_with_derivative, AlgoMetrics, Bisection, BracketingRootFinder,
+        Brent, OpenRootFinder, Ridders, RootFinder, RootFindingError, RootFindingMethod, Secant,
+    };
+
+    fn solve_bracketed<S>(
+        f: fn(f64) -> f64,
+        a: f64,
+        b: f64,
+    ) -> Result<AlgoMetrics, RootFindingError>
+    where
+        S: BracketingRootFinder<fn(f64) -> f64>,
+    {
+        let mut solver = S::from_bracket(f, a, b);
+        solver.set_tol(1e-10);
+        solver.set_iter(1000);
+        solver.solve()
+    }
+
+    #[test]
+    fn test_swap_bracketing_solvers() {
+        let f: fn(f64) -> f64 = |x| x.powi(3) - 2.0 * x - 5.0;
+        let expected = 2.0945514815423265;
+
+        let results = [
+            solve_bracketed::<Bisection<fn(f64) -> f64>>(f, 2.0, 3.0),
+            solve_bracketed::<Ridders<fn(f64) -> f64>>(f, 2.0, 3.0),
+            solve_bracketed::<Brent<fn(f64) -> f64>>(f, 2.0, 3.0),
+        ];
+
+        for result in results {
+            match result {
+                Ok(result) => {
+                    println!("{}", result);
+                    assert!((result.est_x - expected).abs() < 1e-8);
+                }
+                Err(e) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_open_solver() {
+        let mut solver = Secant::from_guess(|x: f64| x.cos() - x, 1.0);
+        solver.set_tol(1e-12);
+
+        match solver.solve() {
+            Ok(result) => {
+                assert!((result.est_x - 0.7390851332151607).abs() < 1e-10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_runtime_selection() {
+        let k = 4.0;
+        let methods = [
+            RootFindingMethod::Bisection,
+            RootFindingMethod::Ridders,
+            RootFindingMethod::Brent,
+            RootFindingMethod::Secant,
+        ];
+
+        for method in methods {
+            let result = root_finder(method, |x| x * x - k, 1.0, 3.0).and_then(|mut solver| {
+                solver.set_tol(1e-10);
+                solver.solve()
+            });
+
+            match result {
+                Ok(result) => {
+                    assert!((result.est_x - 2.0).abs() < 1e-8, "{:?}", method);
+                }
+                Err(e) => panic!("Test failed for {:?} due to error: {}", method, e),
+            }
+        }
+        assert!(RootFindingMethod::Brent.is_bracketing());
+        assert!(!RootFindingMethod::Newton.is_bracketing());
+        assert!(!RootFindingMethod::Secant.is_bracketing());
+
+        // Without a derivative Newton's Method cannot be built
+        assert!(matches!(
+            root_finder(RootFindingMethod::Newton, |x| x * x - k, 1.0, 3.0),
+            Err(RootFindingError::MissingDerivativeError)
+        ));
+    }
+
+    #[test]
+    fn test_runtime_selection_with_derivative() {
+        let k = 4.0;
+        let fp_calls = Cell::new(0);
+        let fp = |x: f64| {
+            fp_calls.set(fp_calls.get() + 1);
+            2.0 * x
+        };
+
+        for method in [RootFindingMethod::Brent, RootFindingMethod::Newton] {
+            fp_calls.set(0);
+            let mut solver = root_finder_with_derivative(method, |x| x * x - k, fp, 1.0, 3.0);
+            solver.set_tol(1e-10);
+
+            match solver.solve() {
+                Ok(result) => {
+                    assert!((result.est_x - 2.0).abs() < 1e-8, "{:?}", method);
+                }
+                Err(e) => panic!("Test failed for {:?} due to error: {}", method, e),
+            }
+            // Only Newton's Method evaluates the derivative
+            assert_eq!(fp_calls.get() > 0, method == RootFindingMethod::Newton);
+        }
+    }
+
+    #[test]
+    fn test_tolerances_checked_alike() {
+        let methods = [
+            RootFindingMethod::Bisection,
+            RootFindingMethod::Ridders,
+            RootFindingMethod::Brent,
+            RootFindingMethod::Secant,
+        ];
+
+        for method in methods {
+            for (tol, rtol, msg) in [
+                (0.0, 1e-12, "Value of tol is either negative or zero."),
+                (
+                    1e-10,
+                    1e-20,
+                    "Value of rtol is either negative or extremely small.",
+                ),
+            ] {
+                let result =
+                    root_finder(method, |x| x * x - 4.0, 1.0, 3.0).and_then(|mut solver| {
+                        solver.set_tol(tol);
+                        solver.set_rtol(rtol);
+                        solver.solve()
+                    });
+
+                match result {
+                    Err(RootFindingError::UnacceptableToleranceError(metrics)) => {
+                        assert_eq!(metrics.msg, msg, "{:?}", method);
+                    }
+                    result => panic!("Test failed for {:?} returning {:?}", method, result),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_boxed_error_is_reported() {
+        let result = root_finder(RootFindingMethod::Bisection, |x| x * x + 1.0, -1.0, 2.0)
+            .and_then(|mut solver| {
+                solver.set_iter(50);
+                solver.solve()
+            });
+
+        assert!(matches!(result, Err(RootFindingError::SignAgreementError)));
+    }
+}
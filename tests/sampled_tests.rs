@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod sampled_tests {
+    use ndarray::Array1;
+    use numix::common::functions::precision_equals;
+    use numix::integrate::sampled::{
+        cumulative_simpson, cumulative_trapezoid, simpson, trapezoid, SampledDataError,
+    };
+
+    #[test]
+    fn test_trapezoid() {
+        let x = [0.0, 0.5, 2.0, 3.0];
+        let y: Vec<f64> = x.iter().map(|x| 2.0 * x + 1.0).collect();
+        match (trapezoid(&y, &x), cumulative_trapezoid(&y, &x)) {
+            (Ok(integral), Ok(cumulative)) => {
+                // Exact for linear functions: x^2 + x
+                assert!(precision_equals(integral, 12.0, 1e-14, 0.0));
+                let expected = Array1::from(vec![0.0, 0.75, 6.0, 12.0]);
+                for (c, e) in cumulative.iter().zip(expected.iter()) {
+                    assert!(precision_equals(*c, *e, 1e-14, 0.0));
+                }
+            }
+            (Err(e), _) | (_, Err(e)) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_simpson_non_uniform() {
+        // Exact for quadratics on any monotonic grid, with odd and even numbers of samples
+        let f = |x: f64| 3.0 * x * x - 2.0 * x + 1.0;
+        let antiderivative = |x: f64| x * x * x - x * x + x;
+        for x in [
+            vec![0.0, 0.3, 1.0, 1.2, 2.0],
+            vec![0.0, 0.3, 1.0, 1.2, 2.0, 2.1],
+            vec![2.0, 1.5, 0.1],
+            vec![-1.0, 0.0, 0.5, 2.0],
+        ] {
+            let y: Array1<f64> = x.iter().map(|x| f(*x)).collect();
+            let x = Array1::from(x);
+            let exact = antiderivative(x[x.len() - 1]) - antiderivative(x[0]);
+            match (simpson(&y, &x), cumulative_simpson(&y, &x)) {
+                (Ok(integral), Ok(cumulative)) => {
+                    assert!(precision_equals(integral, exact, 1e-13, 0.0));
+                    assert!(precision_equals(
+                        cumulative[x.len() - 1],
+                        integral,
+                        1e-13,
+                        0.0
+                    ));
+                    for k in 0..x.len() {
+                        let partial = antiderivative(x[k]) - antiderivative(x[0]);
+                        assert!(precision_equals(cumulative[k], partial, 1e-13, 0.0));
+                    }
+                }
+                (Err(e), _) | (_, Err(e)) => panic!("Test failed due to error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn test_simpson_convergence() {
+        // Non-uniform grid x = t^2 on [0, 1] for sin, with an even number of samples
+        let x: Vec<f64> = (0..100).map(|k| (k as f64 / 99.0).powi(2)).collect();
+        let y: Vec<f64> = x.iter().map(|x| x.sin()).collect();
+        let exact = 1.0 - 1f64.cos();
+        match (simpson(y.as_slice(), x.as_slice()), trapezoid(&y, &x)) {
+            (Ok(simpson), Ok(trapezoid)) => {
+                assert!((simpson - exact).abs() < 1e-8);
+                assert!((trapezoid - exact).abs() > 1e-6);
+            }
+            (Err(e), _) | (_, Err(e)) => panic!("Test failed due to error: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_sampled_errors() {
+        assert!(matches!(
+            trapezoid(&[1.0, 2.0], &[0.0, 1.0, 2.0]),
+            Err(SampledDataError::DimensionMismatchError)
+        ));
+        assert!(matches!(
+            cumulative_trapezoid(&[1.0], &[0.0]),
+            Err(SampledDataError::TooFewSamplesError)
+        ));
+        assert!(matches!(
+            simpson(&[1.0, 2.0, 3.0], &[0.0, 1.0, 1.0]),
+            Err(SampledDataError::SpacingError)
+        ));
+        assert!(matches!(
+            cumulative_simpson(&[1.0, 2.0, 3.0], &[0.0, 1.0, 0.5]),
+            Err(SampledDataError::SpacingError)
+        ));
+    }
+}
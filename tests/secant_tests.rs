@@ -1,14 +1,14 @@
 #[cfg(test)]
 pub mod newton_tests {
-    use numix::optimize::root_finding::Newton;
+    use numix::optimize::root_finding::{Newton, RootFindingError, Secant};
 
     #[test]
     fn test1() {
         // Test case 1: Root near 0 for sin(x)
-        let root1 = Newton::initialize(|x| x.sin(), 1.0).tol(1e-10).run();
+        let root1 = Secant::initialize(|x| x.sin(), 1.0).tol(1e-10).run();
 
         // Test case 2: Root near PI for sin(x)
-        let root2 = Newton::initialize(|x| x.sin(), 4.0).tol(1e-10).run();
+        let root2 = Secant::initialize(|x| x.sin(), 4.0).tol(1e-10).run();
 
         // Validate root1
         match root1 {
@@ -31,12 +31,12 @@ pub mod newton_tests {
     #[test]
     fn test2() {
         // Test case 1: Root at x = 1 for the cubic equation
-        let root1 = Newton::initialize(|x| x.powi(3) - 6.0 * x.powi(2) + 11.0 * x - 6.0, 0.5)
+        let root1 = Secant::initialize(|x| x.powi(3) - 6.0 * x.powi(2) + 11.0 * x - 6.0, 0.5)
             .tol(1e-10)
             .run();
 
         // Test case 2: Root at x = 3 for the cubic equation
-        let root2 = Newton::initialize(|x| x.powi(3) - 6.0 * x.powi(2) + 11.0 * x - 6.0, 3.5)
+        let root2 = Secant::initialize(|x| x.powi(3) - 6.0 * x.powi(2) + 11.0 * x - 6.0, 3.5)
             .tol(1e-10)
             .run();
 
@@ -63,7 +63,7 @@ pub mod newton_tests {
         let ln_2: f64 = std::f64::consts::LN_2; // Natural log of 2
 
         // Test case 1: Root at x = ln(2) for the exponential equation
-        let root = Newton::initialize(|x| x.exp() - 2.0, 0.5).tol(1e-10).run();
+        let root = Secant::initialize(|x| x.exp() - 2.0, 0.5).tol(1e-10).run();
 
         // Validate root
         match root {
@@ -76,12 +76,12 @@ pub mod newton_tests {
     #[test]
     fn test4() {
         // Test case 1: Root at x = 2 for f(x) = x^2 - 4
-        let root1 = Newton::initialize(|x| x.powi(2) - 4.0, 1.0)
+        let root1 = Secant::initialize(|x| x.powi(2) - 4.0, 1.0)
             .tol(1e-10)
             .run();
 
         // Test case 2: Root at x = -2 for f(x) = x^2 - 4
-        let root2 = Newton::initialize(|x| x.powi(2) - 4.0, -1.0)
+        let root2 = Secant::initialize(|x| x.powi(2) - 4.0, -1.0)
             .tol(1e-10)
             .run();
 
@@ -104,7 +104,7 @@ pub mod newton_tests {
     #[test]
     fn test5() {
         // Test case: Root at x = 4 for f(x) = x^3 - 4x^2 + 6x - 24
-        let root = Newton::initialize(|x| x.powi(3) - 4.0 * x.powi(2) + 6.0 * x - 24.0, 1.0)
+        let root = Secant::initialize(|x| x.powi(3) - 4.0 * x.powi(2) + 6.0 * x - 24.0, 1.0)
             .tol(1e-10)
             .run();
 
@@ -119,7 +119,7 @@ pub mod newton_tests {
     #[test]
     fn test6() {
         // Test case: Root near 0.739 for f(x) = cos(x) - x
-        let root = Newton::initialize(|x| x.cos() - x, 1.0).tol(1e-10).run();
+        let root = Secant::initialize(|x| x.cos() - x, 1.0).tol(1e-10).run();
 
         // Validate root
         match root {
@@ -133,7 +133,7 @@ pub mod newton_tests {
     #[test]
     fn test7() {
         // Test case: Root at x = e for f(x) = ln(x) - 1
-        let root = Newton::initialize(|x| x.ln() - 1.0, 2.0).tol(1e-10).run();
+        let root = Secant::initialize(|x| x.ln() - 1.0, 2.0).tol(1e-10).run();
 
         // Validate root
         match root {
@@ -146,7 +146,7 @@ pub mod newton_tests {
     #[test]
     fn test8() {
         // Test case: Root near 0.567 for f(x) = e^{-x} - x
-        let root = Newton::initialize(|x| (-x).exp() - x, 1.0).tol(1e-10).run();
+        let root = Secant::initialize(|x| (-x).exp() - x, 1.0).tol(1e-10).run();
 
         // Validate root
         match root {
@@ -158,7 +158,7 @@ pub mod newton_tests {
     }
     #[test]
     fn test9() {
-        let result = Newton::initialize(|x| x.sin() * x.sin() / x, 8.0)
+        let result = Secant::initialize(|x| x.sin() * x.sin() / x, 8.0)
             .tol(1e-10)
             .run();
 
@@ -171,4 +171,33 @@ pub mod newton_tests {
             Err(e) => panic!("Test failed due to error: {}", e),
         }
     }
+    #[test]
+    fn test_secant_solver() {
+        let result = Secant::initialize(|x| x.powi(3) - 2.0 * x - 5.0, 2.0)
+            .x1(3.0)
+            .tol(1e-12)
+            .run();
+
+        match result {
+            Ok(algo_metrics) => {
+                println!("{}", algo_metrics);
+                assert!((algo_metrics.est_x - 2.0945514815423265).abs() < 1e-10);
+            }
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
+    #[test]
+    fn test_newton_requires_derivative() {
+        let f = |x: f64| (-x).exp() - x;
+        let newton = Newton::initialize(f, 1.0).tol(1e-10).run();
+        assert!(matches!(
+            newton,
+            Err(RootFindingError::MissingDerivativeError)
+        ));
+
+        match Secant::initialize(f, 1.0).tol(1e-10).run() {
+            Ok(secant) => assert!((secant.est_x - 0.5671432904).abs() < 1e-10),
+            Err(e) => panic!("Test failed due to error: {}", e),
+        }
+    }
 }